@@ -0,0 +1,478 @@
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use log::{debug, error, info};
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+
+use crate::app::{ApiConfig, App, AppResult, FeedItem};
+use crate::event::Event;
+
+/// Largest request (headers plus body) the API server will accept.
+const MAX_REQUEST_BYTES: usize = 64 * 1024;
+
+/// Time a client gets to send its whole request before the connection is dropped.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A parsed HTTP request received by the API server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    /// Request method, upper-case (e.g. "GET")
+    pub method: String,
+    /// Path without the query string (e.g. "/api/feeds")
+    pub path: String,
+    /// Decoded query string parameters
+    pub query: HashMap<String, String>,
+    /// Header values keyed by lower-case header name
+    pub headers: HashMap<String, String>,
+    /// Raw request body
+    pub body: String,
+    /// Title of the feed a `POST /api/feeds` subscribes to. The server fetches it before
+    /// passing the request on, so the main loop never waits for the network.
+    pub feed_title: Option<String>,
+}
+
+/// A JSON response produced by [`handle_request`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl ApiResponse {
+    fn ok(body: Value) -> Self {
+        Self { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+}
+
+/// An API request forwarded to the main loop, with a channel for the reply.
+#[derive(Debug)]
+pub struct ApiCall {
+    pub request: HttpRequest,
+    pub reply: oneshot::Sender<ApiResponse>,
+}
+
+/// Parses a raw HTTP/1.1 request. Returns None if the request line is malformed.
+pub fn parse_request(raw: &str) -> Option<HttpRequest> {
+    let (head, body) = raw.split_once("\r\n\r\n").unwrap_or((raw, ""));
+    let mut lines = head.lines();
+    let mut request_line = lines.next()?.split_whitespace();
+    let method = request_line.next()?.to_uppercase();
+    let target = request_line.next()?;
+
+    let (path, query_string) = target.split_once('?').unwrap_or((target, ""));
+    let query = query_string
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect();
+
+    let headers = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+        .collect();
+
+    Some(HttpRequest {
+        method,
+        path: path.to_string(),
+        query,
+        headers,
+        body: body.to_string(),
+        feed_title: None,
+    })
+}
+
+/// Decodes %XX escapes and '+' in a URL component
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+                match u8::from_str_radix(hex, 16) {
+                    Ok(byte) => {
+                        decoded.push(byte);
+                        i += 3;
+                        continue;
+                    }
+                    Err(_) => decoded.push(b'%'),
+                }
+            }
+            b'+' => decoded.push(b' '),
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+/// Checks the request's token against the configured one.
+/// Accepts "Authorization: Bearer <token>" or an "X-Reedy-Token" header.
+pub fn is_authorized(request: &HttpRequest, token: &str) -> bool {
+    if token.is_empty() {
+        return false;
+    }
+    let provided = request
+        .headers
+        .get("authorization")
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .or_else(|| request.headers.get("x-reedy-token").map(String::as_str));
+
+    match provided {
+        // Compare every byte so timing does not reveal the matching prefix
+        Some(provided) => {
+            provided.len() == token.len()
+                && provided
+                    .bytes()
+                    .zip(token.bytes())
+                    .fold(0, |acc, (a, b)| acc | (a ^ b))
+                    == 0
+        }
+        None => false,
+    }
+}
+
+/// Serializes a feed item for API responses
+fn item_json(app: &App, item: &FeedItem) -> Value {
+    json!({
+        "id": item.id,
+        "title": item.title,
+        "link": item.link,
+        "description": item.description,
+        "published": item
+            .published
+            .map(|p| chrono::DateTime::<chrono::Utc>::from(p).to_rfc3339()),
+        "feed_url": item.feed_url,
+        "read": app.is_item_read(item),
+        "favorite": app.is_item_favorite(item),
+    })
+}
+
+/// Routes an API request to the matching [`App`] operation.
+///
+/// Endpoints:
+/// - `GET /api/health` - feed health overview
+/// - `GET /api/feeds` - subscriptions with unread/total counts
+/// - `POST /api/feeds` - subscribe, body `{"url": "...", "category": "..."}`
+/// - `GET /api/items` - cached items, filters `feed`, `unread`, `favorites`, `limit`
+/// - `GET /api/unread` - unread counts per feed
+/// - `POST /api/items/{id}/read`, `/unread`, `/favorite`, `/unfavorite` - update item state
+pub async fn handle_request(app: &mut App, request: &HttpRequest) -> ApiResponse {
+    debug!("API request: {} {}", request.method, request.path);
+    let segments: Vec<String> = request
+        .path
+        .trim_matches('/')
+        .split('/')
+        .map(percent_decode)
+        .collect();
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["api", "health"]) => {
+            let feeds: Vec<Value> = app
+                .rss_feeds
+                .iter()
+                .map(|feed| {
+                    let health = app.get_feed_health(&feed.url);
+                    json!({
+                        "url": feed.url,
+                        "title": feed.title,
+                        "status": health.status_description(),
+                        "consecutive_failures": health.consecutive_failures,
+                        "last_error": health.last_error,
+                    })
+                })
+                .collect();
+            let last_refresh = app
+                .last_refresh
+                .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+                .map(|d| d.as_secs());
+            ApiResponse::ok(json!({
+                "status": "ok",
+                "version": env!("CARGO_PKG_VERSION"),
+                "last_refresh": last_refresh,
+                "feeds": feeds,
+            }))
+        }
+        ("GET", ["api", "feeds"]) => {
+            let feeds: Vec<Value> = app
+                .get_feeds_by_category()
+                .into_iter()
+                .flat_map(|(_, feeds)| feeds)
+                .map(|feed| {
+                    json!({
+                        "url": feed.url,
+                        "title": feed.title,
+                        "category": feed.category,
+                        "tags": feed.tags,
+                        "paused": feed.paused,
+                        "unread": app.count_unread_for_feed(&feed.url),
+                        "total": app.count_total_for_feed(&feed.url),
+                    })
+                })
+                .collect();
+            ApiResponse::ok(json!(feeds))
+        }
+        ("POST", ["api", "feeds"]) => {
+            let (url, category) = match subscription(request) {
+                Ok(subscription) => subscription,
+                Err(response) => return response,
+            };
+            let Some(title) = &request.feed_title else {
+                return ApiResponse::error(422, "Invalid RSS feed URL");
+            };
+            match app.add_subscription(&url, title, category) {
+                Ok(()) => ApiResponse {
+                    status: 201,
+                    body: json!({ "url": url, "title": title }),
+                },
+                Err(e) => ApiResponse::error(422, e.to_string()),
+            }
+        }
+        ("GET", ["api", "items"]) => {
+            let flag = |name: &str| {
+                request
+                    .query
+                    .get(name)
+                    .is_some_and(|v| v == "true" || v == "1")
+            };
+            let limit = request
+                .query
+                .get("limit")
+                .and_then(|v| v.parse::<usize>().ok())
+                .unwrap_or(usize::MAX);
+            let items: Vec<Value> = app
+                .all_cached_items()
+                .iter()
+                .filter(|item| {
+                    request
+                        .query
+                        .get("feed")
+                        .is_none_or(|feed| &item.feed_url == feed)
+                })
+                .filter(|item| !flag("unread") || !app.is_item_read(item))
+                .filter(|item| !flag("favorites") || app.is_item_favorite(item))
+                .take(limit)
+                .map(|item| item_json(app, item))
+                .collect();
+            ApiResponse::ok(json!(items))
+        }
+        ("GET", ["api", "unread"]) => {
+            let per_feed: serde_json::Map<String, Value> = app
+                .rss_feeds
+                .iter()
+                .map(|feed| {
+                    // Paused feeds are not counted as unread, as in the category totals
+                    let unread = if feed.paused {
+                        0
+                    } else {
+                        app.count_unread_for_feed(&feed.url)
+                    };
+                    (feed.url.clone(), json!(unread))
+                })
+                .collect();
+            let total: u64 = per_feed.values().filter_map(Value::as_u64).sum();
+            ApiResponse::ok(json!({ "total": total, "feeds": per_feed }))
+        }
+        ("POST", ["api", "items", id, action]) => match *action {
+            "read" | "unread" => {
+                let read = *action == "read";
+                app.set_item_read(id, read);
+                ApiResponse::ok(json!({ "id": id, "read": read }))
+            }
+            "favorite" | "unfavorite" => {
+                let favorite = *action == "favorite";
+                app.set_item_favorite(id, favorite);
+                ApiResponse::ok(json!({ "id": id, "favorite": favorite }))
+            }
+            _ => ApiResponse::error(404, format!("Unknown action: {}", action)),
+        },
+        _ => ApiResponse::error(
+            404,
+            format!("No route for {} {}", request.method, request.path),
+        ),
+    }
+}
+
+/// Reads the feed URL and optional category from a `POST /api/feeds` body
+fn subscription(request: &HttpRequest) -> Result<(String, Option<String>), ApiResponse> {
+    let body: Value = serde_json::from_str(&request.body)
+        .map_err(|e| ApiResponse::error(400, format!("Invalid JSON body: {}", e)))?;
+    let Some(url) = body.get("url").and_then(Value::as_str) else {
+        return Err(ApiResponse::error(400, "Missing \"url\""));
+    };
+    let category = body
+        .get("category")
+        .and_then(Value::as_str)
+        .filter(|c| !c.trim().is_empty())
+        .map(|c| c.trim().to_string());
+    Ok((url.trim().to_string(), category))
+}
+
+/// Fetches the title of the feed a `POST /api/feeds` subscribes to. Returns the error
+/// response to send instead if the feed cannot be fetched; a malformed body is left for
+/// [`handle_request`] to reject.
+async fn fetch_feed_title(
+    request: &mut HttpRequest,
+    http_timeout_secs: u64,
+) -> Result<(), ApiResponse> {
+    let Ok((url, _)) = subscription(request) else {
+        return Ok(());
+    };
+    match App::validate_and_get_feed_title(&url, http_timeout_secs).await {
+        Ok(Some(title)) => {
+            request.feed_title = Some(title);
+            Ok(())
+        }
+        Ok(None) => Err(ApiResponse::error(422, "Invalid RSS feed URL")),
+        Err(e) => Err(ApiResponse::error(422, e.to_string())),
+    }
+}
+
+/// Starts the API server on 127.0.0.1 and forwards authorized requests to the main loop.
+/// Fails if no token is configured or the port cannot be bound. Aborting the returned
+/// task stops the server.
+pub async fn start_server(
+    config: &ApiConfig,
+    http_timeout_secs: u64,
+    sender: mpsc::UnboundedSender<Event>,
+) -> AppResult<JoinHandle<()>> {
+    if config.token.is_empty() {
+        return Err("API is enabled but api.token is empty".into());
+    }
+    let listener = TcpListener::bind(("127.0.0.1", config.port)).await?;
+    info!("API server listening on 127.0.0.1:{}", config.port);
+
+    let token = config.token.clone();
+    let task = tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let sender = sender.clone();
+                    let token = token.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) =
+                            serve_connection(stream, &token, http_timeout_secs, sender).await
+                        {
+                            debug!("API connection error: {}", e);
+                        }
+                    });
+                }
+                Err(e) => {
+                    error!("API server accept failed: {}", e);
+                    break;
+                }
+            }
+        }
+    });
+    Ok(task)
+}
+
+/// Reads one request from the connection, answers it and closes the connection
+async fn serve_connection(
+    mut stream: TcpStream,
+    token: &str,
+    http_timeout_secs: u64,
+    sender: mpsc::UnboundedSender<Event>,
+) -> AppResult<()> {
+    let raw = tokio::time::timeout(REQUEST_TIMEOUT, read_request(&mut stream))
+        .await
+        .map_err(|_| "Timed out reading the request")??;
+    let response = match parse_request(&raw) {
+        None => ApiResponse::error(400, "Malformed request"),
+        Some(request) if !is_authorized(&request, token) => {
+            ApiResponse::error(401, "Missing or invalid token")
+        }
+        Some(mut request) => {
+            let fetched =
+                if request.method == "POST" && request.path.trim_matches('/') == "api/feeds" {
+                    fetch_feed_title(&mut request, http_timeout_secs).await
+                } else {
+                    Ok(())
+                };
+            match fetched {
+                Ok(()) => {
+                    let (reply, receiver) = oneshot::channel();
+                    sender.send(Event::Api(ApiCall { request, reply }))?;
+                    receiver
+                        .await
+                        .unwrap_or_else(|_| ApiResponse::error(503, "Reedy is shutting down"))
+                }
+                Err(response) => response,
+            }
+        }
+    };
+
+    let body = if response.body.is_null() {
+        String::new()
+    } else {
+        response.body.to_string()
+    };
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        status_reason(response.status),
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Reads the request head and, if present, a Content-Length body
+async fn read_request(stream: &mut TcpStream) -> AppResult<String> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    loop {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        buffer.extend_from_slice(&chunk[..n]);
+        if buffer.len() > MAX_REQUEST_BYTES {
+            return Err("Request too large".into());
+        }
+        if let Some(head_end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            let head = String::from_utf8_lossy(&buffer[..head_end]).to_lowercase();
+            let content_length = head
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .and_then(|v| v.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            if buffer.len() >= head_end + 4 + content_length {
+                break;
+            }
+        }
+    }
+    Ok(String::from_utf8_lossy(&buffer).to_string())
+}
+
+fn status_reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        422 => "Unprocessable Entity",
+        503 => "Service Unavailable",
+        _ => "Unknown",
+    }
+}
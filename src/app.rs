@@ -1,6 +1,11 @@
+use crate::config::{default_toml, parse_config, serialize_config, ConfigFormat};
+use crate::index::SearchIndex;
+use crate::palette::{fuzzy_match, PaletteEntry, PaletteTarget, PALETTE_COMMANDS};
+use crate::paths;
+use crate::query::Query;
 use atom_syndication::Feed as AtomFeed;
 use base64;
-use chrono::DateTime;
+use chrono::{DateTime, Local};
 use crossterm::terminal;
 use html2text;
 use log::{debug, error, info, warn};
@@ -11,13 +16,16 @@ use rss::Channel;
 use serde::{Deserialize, Serialize};
 use std::io::{Cursor, Write};
 use std::{
-    collections::HashMap, collections::HashSet, error, fs, path::PathBuf, time::Duration,
-    time::SystemTime,
+    collections::BTreeSet, collections::HashMap, collections::HashSet, error, fs, path::Path,
+    path::PathBuf, sync::LazyLock, sync::OnceLock, time::Duration, time::SystemTime,
 };
 
 /// Default HTTP request timeout in seconds
 const DEFAULT_HTTP_TIMEOUT_SECS: u64 = 30;
 
+/// Longest accepted HTTP request timeout in seconds
+const MAX_HTTP_TIMEOUT_SECS: u64 = 600;
+
 /// Default auto-refresh interval in minutes (0 = disabled)
 const DEFAULT_AUTO_REFRESH_MINS: u64 = 0;
 
@@ -30,6 +38,12 @@ const DEFAULT_NOTIFICATIONS_ENABLED: bool = false;
 /// Default mark read on scroll setting (false = disabled)
 const DEFAULT_MARK_READ_ON_SCROLL: bool = false;
 
+/// Default maximum number of entries written to a published Atom feed
+const DEFAULT_PUBLISH_MAX_ITEMS: usize = 50;
+
+/// Refresh interval used by daemon mode when auto-refresh is disabled
+const DEFAULT_DAEMON_REFRESH_MINS: u64 = 30;
+
 /// Copies text to clipboard using OSC 52 escape sequence.
 /// This works over SSH and through tmux, unlike native clipboard APIs.
 /// Returns Ok(()) on success, Err with message on failure.
@@ -82,6 +96,9 @@ pub struct Theme {
     /// Category header color
     #[serde(default = "default_category")]
     pub category: String,
+    /// Color for articles matching a highlight rule without its own color
+    #[serde(default = "default_alert")]
+    pub alert: String,
 }
 
 fn default_primary() -> String {
@@ -116,6 +133,10 @@ fn default_category() -> String {
     "magenta".to_string()
 }
 
+fn default_alert() -> String {
+    "light_red".to_string()
+}
+
 impl Default for Theme {
     fn default() -> Self {
         Self {
@@ -127,6 +148,7 @@ impl Default for Theme {
             highlight: default_highlight(),
             description: default_description(),
             category: default_category(),
+            alert: default_alert(),
         }
     }
 }
@@ -143,6 +165,7 @@ impl Theme {
             highlight: "blue".to_string(),
             description: "dark_gray".to_string(),
             category: "magenta".to_string(),
+            alert: "red".to_string(),
         }
     }
 }
@@ -182,6 +205,8 @@ pub struct Keybindings {
     pub toggle_favorite: String,
     #[serde(default = "default_toggle_favorites_view")]
     pub toggle_favorites_view: String,
+    #[serde(default = "default_tag_article")]
+    pub tag_article: String,
     #[serde(default = "default_refresh")]
     pub refresh: String,
 
@@ -190,6 +215,12 @@ pub struct Keybindings {
     pub start_search: String,
     #[serde(default = "default_toggle_unread_only")]
     pub toggle_unread_only: String,
+    #[serde(default = "default_next_category")]
+    pub next_category: String,
+    #[serde(default = "default_prev_category")]
+    pub prev_category: String,
+    #[serde(default = "default_toggle_show_muted")]
+    pub toggle_show_muted: String,
 
     // Preview
     #[serde(default = "default_open_preview")]
@@ -212,6 +243,30 @@ pub struct Keybindings {
     pub import_clipboard: String,
     #[serde(default = "default_import_opml")]
     pub import_opml: String,
+    #[serde(default = "default_open_mute_rules")]
+    pub open_mute_rules: String,
+    #[serde(default = "default_set_feed_tags")]
+    pub set_feed_tags: String,
+    #[serde(default = "default_rename_feed")]
+    pub rename_feed: String,
+    #[serde(default = "default_edit_feed_url")]
+    pub edit_feed_url: String,
+    #[serde(default = "default_move_feed_up")]
+    pub move_feed_up: String,
+    #[serde(default = "default_move_feed_down")]
+    pub move_feed_down: String,
+    #[serde(default = "default_toggle_pin_feed")]
+    pub toggle_pin_feed: String,
+    #[serde(default = "default_toggle_pause_feed")]
+    pub toggle_pause_feed: String,
+    #[serde(default = "default_toggle_mark")]
+    pub toggle_mark: String,
+    #[serde(default = "default_visual_mode")]
+    pub visual_mode: String,
+    #[serde(default = "default_cycle_sort")]
+    pub cycle_sort: String,
+    #[serde(default = "default_cycle_group")]
+    pub cycle_group: String,
 
     // UI
     #[serde(default = "default_help")]
@@ -222,6 +277,16 @@ pub struct Keybindings {
     // Export
     #[serde(default = "default_export_article")]
     pub export_article: String,
+
+    // External programs
+    #[serde(default = "default_view_in_pager")]
+    pub view_in_pager: String,
+    #[serde(default = "default_open_in_text_browser")]
+    pub open_in_text_browser: String,
+    #[serde(default = "default_edit_config")]
+    pub edit_config: String,
+    #[serde(default = "default_open_settings")]
+    pub open_settings: String,
 }
 
 // Default keybinding functions
@@ -264,6 +329,9 @@ fn default_toggle_favorite() -> String {
 fn default_toggle_favorites_view() -> String {
     "F".to_string()
 }
+fn default_tag_article() -> String {
+    "t".to_string()
+}
 fn default_refresh() -> String {
     "c".to_string()
 }
@@ -273,6 +341,15 @@ fn default_start_search() -> String {
 fn default_toggle_unread_only() -> String {
     "u".to_string()
 }
+fn default_next_category() -> String {
+    "]".to_string()
+}
+fn default_prev_category() -> String {
+    "[".to_string()
+}
+fn default_toggle_show_muted() -> String {
+    "M".to_string()
+}
 fn default_open_preview() -> String {
     "p".to_string()
 }
@@ -300,6 +377,42 @@ fn default_import_clipboard() -> String {
 fn default_import_opml() -> String {
     "I".to_string()
 }
+fn default_open_mute_rules() -> String {
+    "x".to_string()
+}
+fn default_set_feed_tags() -> String {
+    "T".to_string()
+}
+fn default_rename_feed() -> String {
+    "n".to_string()
+}
+fn default_edit_feed_url() -> String {
+    "U".to_string()
+}
+fn default_move_feed_up() -> String {
+    "K".to_string()
+}
+fn default_move_feed_down() -> String {
+    "J".to_string()
+}
+fn default_toggle_pin_feed() -> String {
+    "p".to_string()
+}
+fn default_toggle_pause_feed() -> String {
+    "z".to_string()
+}
+fn default_toggle_mark() -> String {
+    "Space".to_string()
+}
+fn default_visual_mode() -> String {
+    "v".to_string()
+}
+fn default_cycle_sort() -> String {
+    ".".to_string()
+}
+fn default_cycle_group() -> String {
+    ">".to_string()
+}
 fn default_help() -> String {
     "?".to_string()
 }
@@ -309,6 +422,18 @@ fn default_quit() -> String {
 fn default_export_article() -> String {
     "s".to_string()
 }
+fn default_view_in_pager() -> String {
+    "P".to_string()
+}
+fn default_open_in_text_browser() -> String {
+    "w".to_string()
+}
+fn default_edit_config() -> String {
+    "C".to_string()
+}
+fn default_open_settings() -> String {
+    "=".to_string()
+}
 
 impl Default for Keybindings {
     fn default() -> Self {
@@ -326,9 +451,13 @@ impl Default for Keybindings {
             mark_all_read: default_mark_all_read(),
             toggle_favorite: default_toggle_favorite(),
             toggle_favorites_view: default_toggle_favorites_view(),
+            tag_article: default_tag_article(),
             refresh: default_refresh(),
             start_search: default_start_search(),
             toggle_unread_only: default_toggle_unread_only(),
+            next_category: default_next_category(),
+            prev_category: default_prev_category(),
+            toggle_show_muted: default_toggle_show_muted(),
             open_preview: default_open_preview(),
             open_feed_manager: default_open_feed_manager(),
             add_feed: default_add_feed(),
@@ -338,10 +467,293 @@ impl Default for Keybindings {
             export_opml: default_export_opml(),
             import_clipboard: default_import_clipboard(),
             import_opml: default_import_opml(),
+            open_mute_rules: default_open_mute_rules(),
+            set_feed_tags: default_set_feed_tags(),
+            rename_feed: default_rename_feed(),
+            edit_feed_url: default_edit_feed_url(),
+            move_feed_up: default_move_feed_up(),
+            move_feed_down: default_move_feed_down(),
+            toggle_pin_feed: default_toggle_pin_feed(),
+            toggle_pause_feed: default_toggle_pause_feed(),
+            toggle_mark: default_toggle_mark(),
+            visual_mode: default_visual_mode(),
+            cycle_sort: default_cycle_sort(),
+            cycle_group: default_cycle_group(),
             help: default_help(),
             quit: default_quit(),
             export_article: default_export_article(),
+            view_in_pager: default_view_in_pager(),
+            open_in_text_browser: default_open_in_text_browser(),
+            edit_config: default_edit_config(),
+            open_settings: default_open_settings(),
+        }
+    }
+}
+
+/// Selects which articles are included in a published feed
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PublishSource {
+    /// All favorited articles
+    Favorites,
+    /// Articles from feeds in the given category
+    Category(String),
+    /// Articles matching the given search query (same syntax as the search bar)
+    Search(String),
+    /// Articles matching the saved search with the given name
+    SavedSearch(String),
+    /// Articles carrying the given user tag, including ones no longer in their feed
+    Tag(String),
+}
+
+/// An aggregated Atom feed that is regenerated after every refresh
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PublishedFeed {
+    /// Title of the generated feed
+    pub title: String,
+    /// File the Atom XML is written to (a leading "~/" expands to the home directory)
+    pub path: String,
+    /// Which articles to include
+    pub source: PublishSource,
+    /// Maximum number of entries, newest first (default: 50)
+    #[serde(default = "default_publish_max_items")]
+    pub max_items: usize,
+}
+
+fn default_publish_max_items() -> usize {
+    DEFAULT_PUBLISH_MAX_ITEMS
+}
+
+/// Default terminal browser used by the "open in text browser" action
+const DEFAULT_TEXT_BROWSER: &str = "w3m";
+
+/// Default port for the local JSON API
+const DEFAULT_API_PORT: u16 = 7878;
+
+/// Settings for the optional local JSON API server
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApiConfig {
+    /// Start the API server on launch (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+    /// Port to listen on; the server always binds to 127.0.0.1 (default: 7878)
+    #[serde(default = "default_api_port")]
+    pub port: u16,
+    /// Token clients must send as "Authorization: Bearer <token>" (required when enabled)
+    #[serde(default)]
+    pub token: String,
+}
+
+fn default_api_port() -> u16 {
+    DEFAULT_API_PORT
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_API_PORT,
+            token: String::new(),
+        }
+    }
+}
+
+/// What happens to articles matched by a mute rule
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MuteAction {
+    /// Hide matching articles unless "show muted" is on
+    #[default]
+    Hide,
+    /// Mark matching articles as read when feeds are refreshed
+    MarkRead,
+}
+
+/// A kill-file rule, e.g. `feed:"Weekly" title:/sponsored/ domain:ads.example.com`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MuteRule {
+    /// Search query selecting the articles to mute (same syntax as the search bar)
+    pub query: String,
+    #[serde(default)]
+    pub action: MuteAction,
+    /// The query, parsed the first time the rule is used (None if it is empty or invalid)
+    #[serde(skip)]
+    parsed: OnceLock<Option<Query>>,
+}
+
+impl PartialEq for MuteRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.query == other.query && self.action == other.action
+    }
+}
+
+impl MuteRule {
+    /// Creates a rule applying `action` to the articles matching `query`
+    pub fn new(query: &str, action: MuteAction) -> Self {
+        MuteRule {
+            query: query.to_string(),
+            action,
+            parsed: OnceLock::new(),
+        }
+    }
+
+    /// The rule's query; None if it is empty or does not parse
+    pub fn parsed_query(&self) -> Option<&Query> {
+        self.parsed
+            .get_or_init(|| Query::parse(&self.query).ok().flatten())
+            .as_ref()
+    }
+}
+
+/// Watch-word rule that highlights matching articles, e.g. `"Reedy" OR title:/CVE-\d{4}-\d+/`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HighlightRule {
+    /// Search query selecting the articles to highlight (same syntax as the search bar)
+    pub query: String,
+    /// Name shown in alerts (default: the query)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Color name or hex value (default: the theme's `alert` color)
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Keep unread matches at the top of the feed list (default: false)
+    #[serde(default)]
+    pub pin: bool,
+    /// Send a desktop notification for new matches, even with notifications disabled (default: false)
+    #[serde(default)]
+    pub notify: bool,
+}
+
+impl HighlightRule {
+    /// Returns the name used in alerts
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.query)
+    }
+}
+
+/// A named search shown as a virtual feed in the Feed Manager
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSearch {
+    pub name: String,
+    /// Search query, in the same syntax as the search bar
+    pub query: String,
+    /// Only include unread articles (default: false)
+    #[serde(default)]
+    pub unread_only: bool,
+    /// The query, parsed the first time the search is counted
+    #[serde(skip)]
+    parsed: OnceLock<Result<Option<Query>, String>>,
+}
+
+impl PartialEq for SavedSearch {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.query == other.query
+            && self.unread_only == other.unread_only
+    }
+}
+
+impl SavedSearch {
+    /// Creates a saved search named `name` for `query`
+    pub fn new(name: &str, query: &str, unread_only: bool) -> Self {
+        SavedSearch {
+            name: name.to_string(),
+            query: query.to_string(),
+            unread_only,
+            parsed: OnceLock::new(),
+        }
+    }
+
+    /// The search's query; `Ok(None)` for an empty query, which matches everything
+    pub fn parsed_query(&self) -> Result<Option<&Query>, &str> {
+        match self.parsed.get_or_init(|| Query::parse(&self.query)) {
+            Ok(query) => Ok(query.as_ref()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Routes links matching a pattern to a specific program instead of the system browser
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Opener {
+    /// Regular expression matched against the item link and its enclosure URL
+    pub pattern: String,
+    /// Command line to run; `{url}` is replaced with the URL (appended if absent)
+    /// and `$VAR` / `${VAR}` expand to environment variables, e.g. "mpv {url}" or "$BROWSER"
+    pub command: String,
+    /// Suspend the TUI and run the command in the terminal, e.g. for w3m or lynx (default: false)
+    #[serde(default)]
+    pub foreground: bool,
+    /// The pattern, compiled the first time it is matched (None if it is invalid)
+    #[serde(skip)]
+    regex: OnceLock<Option<regex::Regex>>,
+}
+
+impl PartialEq for Opener {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+            && self.command == other.command
+            && self.foreground == other.foreground
+    }
+}
+
+/// A fully expanded external program invocation
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ExternalCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    /// Text piped to the program's standard input (e.g. an article for the pager)
+    pub input: Option<String>,
+    /// Reload the config file after the program exits (used for the editor)
+    pub reload_config: bool,
+}
+
+impl Opener {
+    /// Creates an opener running `command` for URLs matching `pattern`
+    pub fn new(pattern: &str, command: &str, foreground: bool) -> Self {
+        Opener {
+            pattern: pattern.to_string(),
+            command: command.to_string(),
+            foreground,
+            regex: OnceLock::new(),
+        }
+    }
+
+    /// Returns true if the pattern matches the URL. Invalid patterns never match.
+    pub fn matches(&self, url: &str) -> bool {
+        let regex = self.regex.get_or_init(|| {
+            regex::Regex::new(&self.pattern)
+                .inspect_err(|e| warn!("Invalid opener pattern '{}': {}", self.pattern, e))
+                .ok()
+        });
+        regex.as_ref().is_some_and(|re| re.is_match(url))
+    }
+
+    /// Expands the command line for the given URL. Returns None if the command is empty
+    /// after expansion (e.g. "$BROWSER" when the variable is unset) or cannot be parsed.
+    pub fn build_command(&self, url: &str) -> Option<ExternalCommand> {
+        let words = shell_words::split(&self.command).ok()?;
+        let mut has_placeholder = false;
+        let mut words: Vec<String> = words
+            .iter()
+            .map(|word| {
+                has_placeholder |= word.contains("{url}");
+                expand_env_vars(word).replace("{url}", url)
+            })
+            .filter(|word| !word.is_empty())
+            .collect();
+        if words.is_empty() {
+            return None;
+        }
+        if !has_placeholder {
+            words.push(url.to_string());
         }
+        let program = words.remove(0);
+        Some(ExternalCommand {
+            program,
+            args: words,
+            ..Default::default()
+        })
     }
 }
 
@@ -369,6 +781,31 @@ pub struct Config {
     /// Custom keyboard shortcuts (default: vim-style bindings)
     #[serde(default)]
     pub keybindings: Keybindings,
+    /// Atom feeds to publish from favorites, categories or searches (default: none)
+    #[serde(default)]
+    pub publish: Vec<PublishedFeed>,
+    /// Local JSON API server (default: disabled)
+    #[serde(default)]
+    pub api: ApiConfig,
+    /// Programs used to open links, first match wins (default: none, use the system browser)
+    #[serde(default)]
+    pub openers: Vec<Opener>,
+    /// Terminal browser command for the "open in text browser" action (default: "w3m")
+    #[serde(default = "default_text_browser")]
+    pub text_browser: String,
+    /// Named searches listed as virtual feeds (default: none)
+    #[serde(default)]
+    pub saved_searches: Vec<SavedSearch>,
+    /// Rules that hide or auto-read matching articles (default: none)
+    #[serde(default)]
+    pub mute_rules: Vec<MuteRule>,
+    /// Watch-word rules that highlight, pin and alert on matching articles (default: none)
+    #[serde(default)]
+    pub highlight_rules: Vec<HighlightRule>,
+}
+
+fn default_text_browser() -> String {
+    DEFAULT_TEXT_BROWSER.to_string()
 }
 
 fn default_http_timeout() -> u64 {
@@ -401,10 +838,204 @@ impl Default for Config {
             mark_read_on_scroll: DEFAULT_MARK_READ_ON_SCROLL,
             theme: Theme::default(),
             keybindings: Keybindings::default(),
+            publish: Vec::new(),
+            api: ApiConfig::default(),
+            openers: Vec::new(),
+            text_browser: DEFAULT_TEXT_BROWSER.to_string(),
+            saved_searches: Vec::new(),
+            mute_rules: Vec::new(),
+            highlight_rules: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Checks settings that deserialize fine but cannot work, returning one message per problem
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+
+        if self.http_timeout_secs == 0 {
+            problems.push("http_timeout_secs must be greater than 0".to_string());
+        } else if self.http_timeout_secs > MAX_HTTP_TIMEOUT_SECS {
+            problems.push(format!(
+                "http_timeout_secs must be at most {}",
+                MAX_HTTP_TIMEOUT_SECS
+            ));
+        }
+        if self.text_browser.trim().is_empty() {
+            problems.push("text_browser cannot be empty".to_string());
+        }
+        if let Ok(serde_json::Value::Object(colors)) = serde_json::to_value(&self.theme) {
+            for (name, color) in colors {
+                let color = color.as_str().unwrap_or_default();
+                if crate::ui::try_parse_color(color).is_none() {
+                    problems.push(format!("theme.{}: unknown color \"{}\"", name, color));
+                }
+            }
+        }
+        if let Ok(serde_json::Value::Object(bindings)) = serde_json::to_value(&self.keybindings) {
+            for (action, keys) in bindings {
+                let keys = keys.as_str().unwrap_or_default();
+                if keys
+                    .split(',')
+                    .any(|key| crate::handler::parse_key(key).is_none())
+                {
+                    problems.push(format!(
+                        "keybindings.{}: invalid key in \"{}\"",
+                        action, keys
+                    ));
+                }
+            }
+        }
+        for opener in &self.openers {
+            if let Err(e) = regex::Regex::new(&opener.pattern) {
+                problems.push(format!(
+                    "openers: invalid pattern \"{}\": {}",
+                    opener.pattern, e
+                ));
+            }
+            if opener.command.trim().is_empty() {
+                problems.push(format!("openers: empty command for \"{}\"", opener.pattern));
+            }
+        }
+        for feed in &self.publish {
+            if feed.path.trim().is_empty() {
+                problems.push(format!("publish: \"{}\" has no path", feed.title));
+            }
+            if feed.max_items == 0 {
+                problems.push(format!(
+                    "publish: \"{}\" max_items must be greater than 0",
+                    feed.title
+                ));
+            }
+        }
+        for rule in &self.mute_rules {
+            if let Err(e) = Query::parse(&rule.query) {
+                problems.push(format!("mute_rules: \"{}\": {}", rule.query, e));
+            }
+        }
+        for rule in &self.highlight_rules {
+            if let Err(e) = Query::parse(&rule.query) {
+                problems.push(format!("highlight_rules: \"{}\": {}", rule.query, e));
+            }
+            if let Some(color) = &rule.color {
+                if crate::ui::try_parse_color(color).is_none() {
+                    problems.push(format!(
+                        "highlight_rules: \"{}\": unknown color \"{}\"",
+                        rule.query, color
+                    ));
+                }
+            }
+        }
+        for search in &self.saved_searches {
+            if let Err(e) = Query::parse(&search.query) {
+                problems.push(format!("saved_searches: \"{}\": {}", search.name, e));
+            }
+        }
+        if self.api.port == 0 {
+            problems.push("api.port must be greater than 0".to_string());
+        }
+        if self.api.enabled && self.api.token.is_empty() {
+            problems.push("api.token is required when the API is enabled".to_string());
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
         }
     }
 }
 
+/// One line of the Settings page: a config value addressed by its dotted key
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setting {
+    /// Dotted path in the config, e.g. "theme.primary"
+    pub key: String,
+    /// Current value as text
+    pub value: String,
+    pub description: String,
+    /// Lists (rules, openers, ...) are only summarized and cannot be edited here
+    pub editable: bool,
+}
+
+/// Config settings in Settings page order with their descriptions. Objects expand to one
+/// setting per field, prefixed with the description.
+pub(crate) const SETTINGS: &[(&str, &str)] = &[
+    ("http_timeout_secs", "HTTP request timeout in seconds"),
+    (
+        "auto_refresh_mins",
+        "Auto-refresh interval in minutes (0 disables it)",
+    ),
+    (
+        "cache_duration_mins",
+        "How long fetched feeds are cached, in minutes",
+    ),
+    (
+        "notifications_enabled",
+        "Desktop notifications for new articles",
+    ),
+    (
+        "mark_read_on_scroll",
+        "Mark articles read when scrolling past them",
+    ),
+    (
+        "text_browser",
+        "Terminal browser for the text browser action",
+    ),
+    ("theme.primary", "Color of titles and headers"),
+    (
+        "theme.secondary",
+        "Color of selected items and section headers",
+    ),
+    ("theme.text", "Default text color"),
+    ("theme.muted", "Color of read items and inactive elements"),
+    ("theme.error", "Color of error messages"),
+    ("theme.highlight", "Color of unread counts and links"),
+    ("theme.description", "Color of article descriptions"),
+    ("theme.category", "Color of category headers and tags"),
+    (
+        "theme.alert",
+        "Color of highlighted articles without their own color",
+    ),
+    ("keybindings", "Keys for"),
+    (
+        "api.enabled",
+        "Start the local JSON API (applies on restart)",
+    ),
+    (
+        "api.port",
+        "Port of the local JSON API (applies on restart)",
+    ),
+    ("api.token", "Bearer token required by the local JSON API"),
+    ("publish", "Published Atom feeds (edit the config file)"),
+    (
+        "openers",
+        "Programs that open matching links (edit the config file)",
+    ),
+    (
+        "saved_searches",
+        "Saved searches (managed in the Feed Manager)",
+    ),
+    ("mute_rules", "Mute rules (managed on the Mute Rules page)"),
+    ("highlight_rules", "Highlight rules (edit the config file)"),
+];
+
+/// Looks up a dotted key in a JSON value
+fn value_at<'a>(value: &'a serde_json::Value, key: &str) -> Option<&'a serde_json::Value> {
+    key.split('.')
+        .try_fold(value, |value, part| value.get(part))
+}
+
+/// Text shown for a JSON value on the Settings page
+fn setting_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(entries) => format!("{} entries", entries.len()),
+        other => other.to_string(),
+    }
+}
+
 /// Creates a reqwest client with a configured timeout to prevent hanging on slow/unresponsive feeds
 fn create_http_client(timeout_secs: u64) -> reqwest::Client {
     reqwest::Client::builder()
@@ -427,6 +1058,67 @@ pub enum InputMode {
     SettingCategory,
     Preview,
     Command,
+    Tagging,
+    SettingFeedTags,
+    RenamingFeed,
+    EditingFeedUrl,
+    Palette,
+    /// Typing a new value on the Settings page
+    EditingSetting,
+    /// Confirming that a setting goes back to its default
+    ResettingSetting,
+}
+
+/// A row of the Feed Manager tree
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeedManagerRow {
+    /// A category node; `path` is the full path such as "Tech/Rust"
+    Category {
+        path: String,
+        depth: usize,
+        collapsed: bool,
+    },
+    /// A feed, by index into `rss_feeds`
+    Feed { index: usize, depth: usize },
+    /// A saved search, by index into `config.saved_searches`
+    SavedSearch(usize),
+}
+
+/// Which feeds the feed list shows, cycled with the next/previous category keys
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum CategoryScope {
+    /// Items from every feed
+    #[default]
+    All,
+    /// Items from feeds without a category
+    Uncategorized,
+    /// Items from feeds in the given category
+    Category(String),
+}
+
+impl CategoryScope {
+    /// Name shown in the UI
+    pub fn label(&self) -> &str {
+        match self {
+            CategoryScope::All => "All",
+            CategoryScope::Uncategorized => "Uncategorized",
+            CategoryScope::Category(name) => name,
+        }
+    }
+
+    /// Returns true if the feed is part of this scope: its category is the scope's category
+    /// or nested below it, or the feed carries the scope's name as a tag
+    pub fn includes(&self, feed: Option<&FeedInfo>) -> bool {
+        let category = feed.and_then(|f| f.category.as_deref());
+        match self {
+            CategoryScope::All => true,
+            CategoryScope::Uncategorized => category.is_none(),
+            CategoryScope::Category(name) => {
+                category.is_some_and(|c| category_in_path(c, name))
+                    || feed.is_some_and(|f| f.tags.iter().any(|t| t.eq_ignore_ascii_case(name)))
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -434,6 +1126,8 @@ pub enum PageMode {
     FeedList,
     FeedManager,
     Favorites,
+    MuteRules,
+    Settings,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -445,15 +1139,28 @@ pub struct FeedItem {
     pub id: String,
     #[serde(default)]
     pub feed_url: String,
+    /// URL of an attached media file (RSS enclosure or Atom rel="enclosure" link)
+    #[serde(default)]
+    pub enclosure: Option<String>,
 }
 
 /// Represents a feed subscription with its URL, title, and optional category
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FeedInfo {
     pub url: String,
     pub title: String,
+    /// Category path, with "/" separating nested categories (e.g. "Tech/Rust/Blogs")
     #[serde(default)]
     pub category: Option<String>,
+    /// Additional tags; the feed also shows up under each tag's category view
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Listed at the top of the Feed Manager
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pinned: bool,
+    /// Skipped when refreshing and left out of unread totals until resumed
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub paused: bool,
 }
 
 /// Feed health status
@@ -484,6 +1191,17 @@ pub struct FeedHealth {
     pub consecutive_failures: u32,
 }
 
+/// Article counts of a feed's cache, kept up to date so the Feed Manager needn't read it
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FeedStats {
+    /// Number of cached articles
+    pub total: usize,
+    /// Number of cached articles not yet read
+    pub unread: usize,
+    /// Publication date of the newest cached article
+    pub newest: Option<SystemTime>,
+}
+
 impl Default for FeedHealth {
     fn default() -> Self {
         Self {
@@ -541,8 +1259,201 @@ struct SavedState {
     feeds: Vec<FeedInfo>,
     read_items: HashSet<String>,
     favorites: HashSet<String>,
+    #[serde(default)]
+    tagged_items: HashMap<String, TaggedItem>,
+    #[serde(default)]
+    view_orders: HashMap<String, ViewOrder>,
+    #[serde(default)]
+    command_history: Vec<String>,
+    #[serde(default)]
+    category_order: Vec<String>,
+    #[serde(default)]
+    feed_sort: FeedSort,
 }
 
+/// An article with user tags. A copy of the article is kept so the tags
+/// survive after it drops out of its feed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaggedItem {
+    pub item: FeedItem,
+    pub tags: BTreeSet<String>,
+}
+
+/// Order of the articles in the feed list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortMode {
+    #[default]
+    NewestFirst,
+    OldestFirst,
+    Feed,
+    Title,
+    UnreadFirst,
+    FavoritesFirst,
+}
+
+impl SortMode {
+    pub const ALL: [SortMode; 6] = [
+        SortMode::NewestFirst,
+        SortMode::OldestFirst,
+        SortMode::Feed,
+        SortMode::Title,
+        SortMode::UnreadFirst,
+        SortMode::FavoritesFirst,
+    ];
+
+    /// Name used by `:sort` and shown in the status bar
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortMode::NewestFirst => "newest",
+            SortMode::OldestFirst => "oldest",
+            SortMode::Feed => "feed",
+            SortMode::Title => "title",
+            SortMode::UnreadFirst => "unread",
+            SortMode::FavoritesFirst => "favorites",
+        }
+    }
+
+    pub fn next(&self) -> SortMode {
+        let index = Self::ALL.iter().position(|m| m == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Order of the feeds within each category of the Feed Manager
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedSort {
+    /// The order set by moving feeds up and down
+    #[default]
+    Manual,
+    /// Most unread articles first
+    Unread,
+    /// Most recently published article first
+    Updated,
+    /// Broken feeds first, then slow, unchecked and healthy ones
+    Health,
+    /// Alphabetically by title
+    Name,
+}
+
+impl FeedSort {
+    pub const ALL: [FeedSort; 5] = [
+        FeedSort::Manual,
+        FeedSort::Unread,
+        FeedSort::Updated,
+        FeedSort::Health,
+        FeedSort::Name,
+    ];
+
+    /// Name used by `:sort` in the Feed Manager and shown in its title
+    pub fn label(&self) -> &'static str {
+        match self {
+            FeedSort::Manual => "manual",
+            FeedSort::Unread => "unread",
+            FeedSort::Updated => "updated",
+            FeedSort::Health => "health",
+            FeedSort::Name => "name",
+        }
+    }
+
+    pub fn next(&self) -> FeedSort {
+        let index = Self::ALL.iter().position(|s| s == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Group headers shown in the feed list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupBy {
+    #[default]
+    None,
+    Day,
+    Feed,
+    Category,
+}
+
+impl GroupBy {
+    pub const ALL: [GroupBy; 4] = [
+        GroupBy::None,
+        GroupBy::Day,
+        GroupBy::Feed,
+        GroupBy::Category,
+    ];
+
+    /// Name used by `:group` and shown in the status bar
+    pub fn label(&self) -> &'static str {
+        match self {
+            GroupBy::None => "none",
+            GroupBy::Day => "day",
+            GroupBy::Feed => "feed",
+            GroupBy::Category => "category",
+        }
+    }
+
+    pub fn next(&self) -> GroupBy {
+        let index = Self::ALL.iter().position(|g| g == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Sort and grouping of one view of the feed list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViewOrder {
+    #[serde(default)]
+    pub sort: SortMode,
+    #[serde(default)]
+    pub group: GroupBy,
+}
+
+/// Number of recent articles offered by the jump palette
+const PALETTE_RECENT_ARTICLES: usize = 200;
+/// Maximum number of matches listed in the jump palette
+const PALETTE_MAX_MATCHES: usize = 50;
+
+/// Command names offered by Tab completion in command mode
+const COMMAND_NAMES: &[&str] = &[
+    "add",
+    "bottom",
+    "cat",
+    "categories",
+    "delete",
+    "export",
+    "favorites",
+    "feeds",
+    "filter",
+    "find",
+    "group",
+    "help",
+    "mute",
+    "open",
+    "profile",
+    "quit",
+    "read",
+    "refresh",
+    "rename",
+    "rules",
+    "savesearch",
+    "set",
+    "settings",
+    "sort",
+    "tag",
+    "tags",
+    "top",
+    "write",
+    "wq",
+];
+
+/// Number of command lines kept in the command history
+const COMMAND_HISTORY_LIMIT: usize = 100;
+
+/// Maximum number of results shown when searching everything
+const EVERYTHING_RESULTS: usize = 1000;
+
+/// Day group labels, newest first
+const DAY_GROUPS: [&str; 5] = ["Today", "Yesterday", "This week", "Last week", "Older"];
+
 #[derive(Debug, Serialize, Deserialize)]
 struct CachedFeed {
     url: String,
@@ -564,7 +1475,41 @@ pub struct App {
     pub status_message: Option<String>,
     save_path: PathBuf,
     read_items: HashSet<String>,
+    /// Articles of each feed's cache file, however old, keyed by feed URL
+    cached_items: HashMap<String, Vec<FeedItem>>,
+    /// Counts of each feed's cached articles, updated on refresh and read-state changes
+    feed_stats: HashMap<String, FeedStats>,
+    /// (unread, total) articles matching each saved search, keyed by its name
+    saved_search_counts: HashMap<String, (usize, usize)>,
+    /// Articles were read, starred or tagged since the saved searches were last counted
+    saved_search_counts_stale: bool,
     pub favorites: HashSet<String>,
+    /// User tags on articles, keyed by item id
+    pub tagged_items: HashMap<String, TaggedItem>,
+    /// Sort and grouping chosen for each view, keyed by `view_key`
+    pub view_orders: HashMap<String, ViewOrder>,
+    /// Category paths in the order they were moved into; others follow alphabetically
+    pub category_order: Vec<String>,
+    /// Order of the feeds within each category of the Feed Manager
+    pub feed_sort: FeedSort,
+    /// Feed URLs marked in the Feed Manager for bulk actions
+    pub marked_feeds: HashSet<String>,
+    /// Feed Manager row where a visual range started; the range ends at the selection
+    pub feed_visual_start: Option<usize>,
+    /// Article IDs marked in the feed list for bulk actions
+    pub marked_items: HashSet<String>,
+    /// ID of the article where a visual range started; the range ends at the selection
+    pub item_visual_start: Option<String>,
+    /// Full-text index over every article seen so far
+    pub search_index: SearchIndex,
+    /// The search covers the whole index instead of the loaded articles
+    pub search_everything: bool,
+    /// Text typed in the jump palette
+    pub palette_query: String,
+    /// Selected row among the palette matches
+    pub palette_selected: usize,
+    /// Candidates collected when the palette opened
+    palette_entries: Vec<PaletteEntry>,
     pub scroll: u16,
     pub terminal_width: u16,
     pub terminal_height: u16,
@@ -572,20 +1517,64 @@ pub struct App {
     pub filtered_indices: Option<Vec<usize>>,
     pub import_result: Option<String>,
     pub config: Config,
+    /// Reload the config file when it changes on disk (off for the default, unsaved app)
+    pub watch_config: bool,
+    /// Modification time of the config file when it was last loaded
+    config_modified: Option<SystemTime>,
     /// Timestamp of the last feed refresh
     pub last_refresh: Option<SystemTime>,
     /// Flag indicating an auto-refresh is pending (set by tick, consumed by main loop)
     pub auto_refresh_pending: bool,
+    /// Forced refresh of all feeds asked for by `reedy ctl refresh` (consumed by main loop)
+    pub refresh_requested: bool,
     /// Scroll position for the article preview pane
     pub preview_scroll: u16,
     /// Buffer for vi-style command mode (e.g., :q, :w, :wq)
     pub command_buffer: String,
+    /// Previously entered commands, oldest first
+    pub command_history: Vec<String>,
+    /// Entry shown while browsing the command history with Up/Down
+    command_history_pos: Option<usize>,
+    /// Set when the command history or read state changed without being saved yet
+    unsaved_state: bool,
+    /// Feed to subscribe to from `:add` (async, consumed by the event handler)
+    pub pending_subscription: Option<(String, Option<String>)>,
+    /// Profile to switch to from `:profile` (async, consumed by the event handler)
+    pub pending_profile: Option<String>,
     /// Health status for each feed (keyed by URL)
     pub feed_health: HashMap<String, FeedHealth>,
     /// Item IDs that have already been seen (for notification tracking)
     seen_items: HashSet<String>,
     /// Filter to show only unread items
     pub show_unread_only: bool,
+    /// Parse error for the current search query, shown in the search bar
+    pub search_error: Option<String>,
+    /// Name of the saved search currently shown in the feed list
+    pub active_saved_search: Option<String>,
+    /// Tag whose articles are currently shown in the feed list
+    pub active_tag: Option<String>,
+    /// Category the feed list is limited to
+    pub category_scope: CategoryScope,
+    /// Category paths collapsed in the Feed Manager tree
+    pub collapsed_categories: HashSet<String>,
+    /// URL of the feed shown when the feed list holds a single feed picked in the Feed Manager
+    loaded_feed: Option<String>,
+    /// Number of pinned articles at the top of the visible list
+    pinned_count: usize,
+    /// Return to the preview pane when the tag picker closes
+    pub tagging_from_preview: bool,
+    /// Reveal articles hidden by mute rules
+    pub show_muted: bool,
+    /// Number of articles currently hidden by mute rules
+    pub muted_count: usize,
+    /// Index of the mute rule being edited (None while adding a new rule)
+    pub editing_rule: Option<usize>,
+    /// Terminal program waiting to run in the foreground (set by openers, consumed by main loop)
+    pub pending_external: Option<ExternalCommand>,
+    /// Flag indicating the user pressed Ctrl-Z (set by handler, consumed by main loop)
+    pub suspend_pending: bool,
+    /// Set after switching profiles, so the control socket and API server are bound again
+    pub profile_switched: bool,
 }
 
 impl Default for App {
@@ -602,7 +1591,24 @@ impl Default for App {
             status_message: None,
             save_path: Self::get_save_path(),
             read_items: HashSet::new(),
+            cached_items: HashMap::new(),
+            feed_stats: HashMap::new(),
+            saved_search_counts: HashMap::new(),
+            saved_search_counts_stale: false,
             favorites: HashSet::new(),
+            tagged_items: HashMap::new(),
+            view_orders: HashMap::new(),
+            category_order: Vec::new(),
+            marked_feeds: HashSet::new(),
+            feed_visual_start: None,
+            marked_items: HashSet::new(),
+            item_visual_start: None,
+            feed_sort: FeedSort::Manual,
+            search_index: SearchIndex::default(),
+            search_everything: false,
+            palette_query: String::new(),
+            palette_selected: 0,
+            palette_entries: Vec::new(),
             scroll: 0,
             terminal_width: 80,
             terminal_height: 24,
@@ -610,52 +1616,44 @@ impl Default for App {
             filtered_indices: None,
             import_result: None,
             config: Config::default(),
+            watch_config: false,
+            config_modified: None,
             last_refresh: None,
             auto_refresh_pending: false,
+            refresh_requested: false,
             preview_scroll: 0,
             command_buffer: String::new(),
+            command_history: Vec::new(),
+            command_history_pos: None,
+            unsaved_state: false,
+            pending_subscription: None,
+            pending_profile: None,
             feed_health: HashMap::new(),
             seen_items: HashSet::new(),
             show_unread_only: false,
+            search_error: None,
+            active_saved_search: None,
+            active_tag: None,
+            category_scope: CategoryScope::All,
+            collapsed_categories: HashSet::new(),
+            loaded_feed: None,
+            pinned_count: 0,
+            tagging_from_preview: false,
+            show_muted: false,
+            muted_count: 0,
+            editing_rule: None,
+            pending_external: None,
+            suspend_pending: false,
+            profile_switched: false,
         }
     }
 }
 
 impl App {
     pub async fn new() -> Self {
-        let mut app = Self {
-            config: Self::load_config(),
-            ..Default::default()
-        };
-
-        // Get initial terminal size
-        if let Ok((width, height)) = terminal::size() {
-            app.terminal_width = width;
-            app.terminal_height = height;
-        }
-
-        app.load_feeds().unwrap_or_else(|e| {
-            error!("Failed to load feeds: {}", e);
-            app.error_message = Some(format!("Failed to load feeds: {}", e));
-        });
+        let mut app = Self::load();
 
-        // Cache all feeds and load all cached content
         if !app.rss_feeds.is_empty() {
-            app.selected_index = Some(0);
-
-            // Load cached content first to populate seen_items (prevents notifications on startup)
-            let mut all_items = Vec::new();
-            for feed in &app.rss_feeds {
-                if let Some(cached_items) = app.load_feed_cache(&feed.url) {
-                    all_items.extend(cached_items);
-                }
-            }
-            // Populate seen_items with cached item IDs to avoid startup notifications
-            for item in &all_items {
-                app.seen_items.insert(item.id.clone());
-            }
-            app.current_feed_content = all_items;
-
             // Now refresh feeds (notifications will only fire for truly new items)
             // This will use cache if valid, only fetching expired feeds
             let _ = app.refresh_all_feeds().await;
@@ -669,8 +1667,11 @@ impl App {
             }
 
             // Sort all items by date, newest first
-            all_items.sort_by(|a, b| b.published.cmp(&a.published));
+            all_items.sort_by_key(|item| std::cmp::Reverse(item.published));
+            app.index_items(&all_items);
+            app.save_search_index();
             app.current_feed_content = all_items;
+            app.refresh_filters();
         }
 
         // Record the initial refresh time
@@ -679,10 +1680,66 @@ impl App {
         app
     }
 
-    /// Handles the tick event of the terminal.
-    /// Checks if auto-refresh is due and sets the auto_refresh_pending flag.
-    pub fn tick(&mut self) {
-        // Skip auto-refresh check if disabled or no feeds
+    /// Loads the config, feeds and cached articles of the current profile without fetching
+    /// anything
+    pub fn load() -> Self {
+        let mut app = Self {
+            config: Self::load_config(),
+            config_modified: Self::config_modified(),
+            watch_config: true,
+            ..Default::default()
+        };
+        if Self::get_config_path().exists() {
+            if let Err(e) = Self::read_config(&Self::get_config_path()) {
+                let name = Self::get_config_path()
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                app.error_message = Some(format!("Check {}: {}", name, e));
+            }
+        }
+
+        // Get initial terminal size
+        if let Ok((width, height)) = terminal::size() {
+            app.terminal_width = width;
+            app.terminal_height = height;
+        }
+
+        app.load_feeds().unwrap_or_else(|e| {
+            error!("Failed to load feeds: {}", e);
+            app.error_message = Some(format!("Failed to load feeds: {}", e));
+        });
+        app.search_index = SearchIndex::load(SearchIndex::default_dir());
+        app.load_cached_items();
+
+        // Show the cached content, however old
+        if !app.rss_feeds.is_empty() {
+            app.selected_index = Some(0);
+
+            let all_items = app.all_cached_items();
+            // Populate seen_items with cached item IDs to avoid startup notifications
+            for item in &all_items {
+                app.seen_items.insert(item.id.clone());
+            }
+            app.current_feed_content = all_items;
+            app.refresh_filters();
+        }
+
+        app
+    }
+
+    /// Handles the tick event of the terminal.
+    /// Reloads a changed config file, then checks if auto-refresh is due and sets the
+    /// auto_refresh_pending flag.
+    pub fn tick(&mut self) {
+        // Pick up edits made to the config file outside reedy
+        self.check_config_file();
+
+        if self.saved_search_counts_stale {
+            self.update_saved_search_counts();
+        }
+
+        // Skip auto-refresh check if disabled or no feeds
         if self.config.auto_refresh_mins == 0 || self.rss_feeds.is_empty() {
             return;
         }
@@ -707,6 +1764,23 @@ impl App {
         }
     }
 
+    /// Force-refreshes all feeds if a refresh was requested. Called from the main loop.
+    pub async fn perform_requested_refresh(&mut self) {
+        if !std::mem::take(&mut self.refresh_requested) {
+            return;
+        }
+        match self.force_refresh_all_feeds().await {
+            Ok(()) => {
+                self.last_refresh = Some(SystemTime::now());
+                self.status_message = Some(format!("Refreshed {} feeds", self.rss_feeds.len()));
+            }
+            Err(e) => {
+                error!("Requested refresh failed: {}", e);
+                self.error_message = Some(format!("Refresh failed: {}", e));
+            }
+        }
+    }
+
     /// Performs the auto-refresh if pending. Called from the main loop.
     pub async fn perform_auto_refresh(&mut self) {
         if !self.auto_refresh_pending {
@@ -731,27 +1805,17 @@ impl App {
                 if let Err(e) = self.refresh_all_feeds().await {
                     error!("Auto-refresh failed: {}", e);
                 } else {
-                    // Re-filter to show only favorites
-                    let favorites: Vec<FeedItem> = self
-                        .current_feed_content
-                        .iter()
-                        .filter(|item| self.favorites.contains(&item.id))
-                        .cloned()
-                        .collect();
-                    self.current_feed_content = favorites;
+                    // Re-list the favorites, including saved copies that left their feeds
+                    self.current_feed_content = self.favorite_items();
                     // Reset selection state after content change
-                    self.selected_index = if self.current_feed_content.is_empty() {
-                        None
-                    } else {
-                        Some(0)
-                    };
-                    self.filtered_indices = None;
+                    self.selected_index = None;
                     self.scroll = 0;
+                    self.refresh_filters();
                     self.last_refresh = Some(SystemTime::now());
                 }
             }
-            PageMode::FeedManager => {
-                // Don't auto-refresh in feed manager mode
+            PageMode::FeedManager | PageMode::MuteRules | PageMode::Settings => {
+                // Don't auto-refresh in feed manager, mute rules or settings mode
                 self.last_refresh = Some(SystemTime::now());
             }
         }
@@ -775,24 +1839,30 @@ impl App {
         Some(Duration::from_secs(0))
     }
 
+    /// Returns the refresh interval used by daemon mode.
+    /// Follows auto_refresh_mins, falling back to 30 minutes when auto-refresh is disabled.
+    pub fn daemon_refresh_interval(&self) -> Duration {
+        let mins = if self.config.auto_refresh_mins == 0 {
+            DEFAULT_DAEMON_REFRESH_MINS
+        } else {
+            self.config.auto_refresh_mins
+        };
+        Duration::from_secs(mins * 60)
+    }
+
     /// Set running to false to quit the application.
     pub fn quit(&mut self) {
         self.running = false;
     }
 
     pub fn get_save_path() -> PathBuf {
-        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
-        path.push("reedy");
-        if let Err(e) = fs::create_dir_all(&path) {
-            error!("Failed to create config directory {:?}: {}", path, e);
-        }
+        let mut path = paths::config_dir();
         path.push("feeds.json");
         path
     }
 
     pub fn get_log_path() -> PathBuf {
-        let mut path = dirs::data_dir().unwrap_or_else(|| PathBuf::from("."));
-        path.push("reedy");
+        let mut path = paths::data_dir();
         path.push("logs");
         if let Err(e) = fs::create_dir_all(&path) {
             eprintln!("Failed to create log directory {:?}: {}", path, e);
@@ -801,11 +1871,29 @@ impl App {
         path
     }
 
-    pub fn get_config_path() -> PathBuf {
-        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    /// Returns the Unix socket path used by `reedy ctl` to reach a running instance
+    pub fn get_socket_path() -> PathBuf {
+        let mut path = dirs::runtime_dir()
+            .or_else(dirs::cache_dir)
+            .unwrap_or_else(|| PathBuf::from("."));
         path.push("reedy");
         if let Err(e) = fs::create_dir_all(&path) {
-            error!("Failed to create config directory {:?}: {}", path, e);
+            error!("Failed to create socket directory {:?}: {}", path, e);
+        }
+        // Each profile has its own socket so instances of different profiles can run together
+        match paths::profile().as_str() {
+            paths::DEFAULT_PROFILE => path.push("reedy.sock"),
+            profile => path.push(format!("reedy-{}.sock", profile)),
+        }
+        path
+    }
+
+    /// The config file: config.toml if there is one, config.json otherwise
+    pub fn get_config_path() -> PathBuf {
+        let mut path = paths::config_dir();
+        let toml = path.join("config.toml");
+        if toml.exists() {
+            return toml;
         }
         path.push("config.json");
         path
@@ -815,7 +1903,7 @@ impl App {
         let config_path = Self::get_config_path();
         if config_path.exists() {
             match fs::read_to_string(&config_path) {
-                Ok(contents) => match serde_json::from_str::<Config>(&contents) {
+                Ok(contents) => match parse_config(&contents, ConfigFormat::of(&config_path)) {
                     Ok(config) => return config,
                     Err(e) => {
                         warn!(
@@ -840,19 +1928,83 @@ impl App {
 
     pub fn save_config(&self) -> AppResult<()> {
         let config_path = Self::get_config_path();
-        let json = serde_json::to_string_pretty(&self.config)?;
-        fs::write(config_path, json)?;
+        let existing = fs::read_to_string(&config_path).ok();
+        let contents = serialize_config(
+            &self.config,
+            ConfigFormat::of(&config_path),
+            existing.as_deref(),
+        )?;
+        fs::write(config_path, contents)?;
         Ok(())
     }
 
-    fn create_item_id(title: &str, published: Option<SystemTime>, feed_url: &str) -> String {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
+    /// Reads and validates a config file, describing the first parse error or every
+    /// validation problem
+    pub fn read_config(path: &Path) -> Result<Config, String> {
+        let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let config = parse_config(&contents, ConfigFormat::of(path)).map_err(|e| e.to_string())?;
+        config.validate().map_err(|problems| problems.join("; "))?;
+        Ok(config)
+    }
+
+    /// Modification time of the config file, if it exists
+    fn config_modified() -> Option<SystemTime> {
+        fs::metadata(Self::get_config_path())
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    /// Reloads the config when the config file was changed by another program. Changes
+    /// written by reedy itself are recognized and ignored.
+    pub fn check_config_file(&mut self) {
+        if !self.watch_config {
+            return;
+        }
+        let modified = Self::config_modified();
+        if modified == self.config_modified {
+            return;
+        }
+        self.config_modified = modified;
+        if modified.is_none() {
+            return;
+        }
+        let config_path = Self::get_config_path();
+        let unchanged = fs::read_to_string(&config_path)
+            .ok()
+            .is_some_and(|on_disk| {
+                serialize_config(&self.config, ConfigFormat::of(&config_path), Some(&on_disk))
+                    .is_ok_and(|current| on_disk == current)
+            });
+        if !unchanged {
+            debug!("Config file changed on disk");
+            self.reload_config();
+        }
+    }
 
-        let mut hasher = DefaultHasher::new();
-        feed_url.hash(&mut hasher);
-        let url_hash = hasher.finish();
+    /// Re-reads the config file, keeping the current config if the file is invalid
+    pub fn reload_config(&mut self) {
+        let config_path = Self::get_config_path();
+        self.config_modified = Self::config_modified();
+        let parsed = Self::read_config(&config_path);
+
+        match parsed {
+            Ok(config) => {
+                info!("Reloaded config from {}", config_path.display());
+                self.config = config;
+                self.update_saved_search_counts();
+                // Mute rules may have changed, but the reader keeps their place
+                self.refresh_filters();
+                self.status_message = Some("Config reloaded".to_string());
+            }
+            Err(e) => {
+                warn!("Config not reloaded: {}", e);
+                self.error_message = Some(format!("Config not reloaded: {}", e));
+            }
+        }
+    }
 
+    fn create_item_id(title: &str, published: Option<SystemTime>, feed_url: &str) -> String {
+        let url_hash = url_hash(feed_url);
         let title_slug = title
             .to_lowercase()
             .replace(|c: char| !c.is_alphanumeric(), "_");
@@ -875,1517 +2027,4594 @@ impl App {
     /// Returns the count of unread items for a given feed URL.
     /// Uses cached feed content to determine the count.
     pub fn count_unread_for_feed(&self, url: &str) -> usize {
-        if let Some(items) = self.load_feed_cache(url) {
-            items.iter().filter(|item| !self.is_item_read(item)).count()
-        } else {
-            0
-        }
+        self.feed_stats(url).unread
     }
 
     /// Returns the total count of items for a given feed URL.
     /// Uses cached feed content to determine the count.
     pub fn count_total_for_feed(&self, url: &str) -> usize {
-        if let Some(items) = self.load_feed_cache(url) {
-            items.len()
-        } else {
-            0
+        self.feed_stats(url).total
+    }
+
+    /// Returns the article counts of a feed's cache
+    pub fn feed_stats(&self, url: &str) -> FeedStats {
+        self.feed_stats.get(url).copied().unwrap_or_default()
+    }
+
+    /// Recounts the cached articles of every feed
+    fn update_feed_stats(&mut self) {
+        self.feed_stats = self
+            .cached_items
+            .iter()
+            .map(|(url, items)| (url.clone(), self.count_items(items)))
+            .collect();
+        self.update_saved_search_counts();
+    }
+
+    /// Recounts the cached articles of the feeds with these URLs after some of their articles
+    /// were read or unread; saved searches are recounted on the next tick
+    fn recount_feeds(&mut self, urls: &HashSet<String>) {
+        for url in urls {
+            if let Some(items) = self.cached_items.get(url) {
+                let stats = self.count_items(items);
+                self.feed_stats.insert(url.clone(), stats);
+            }
         }
+        self.saved_search_counts_stale = true;
     }
 
-    /// Returns the health status for a given feed URL.
-    /// Returns a default Unknown status if the feed hasn't been checked yet.
-    pub fn get_feed_health(&self, url: &str) -> FeedHealth {
-        self.feed_health.get(url).cloned().unwrap_or_default()
+    /// Recounts the feed of a single article, see `recount_feeds`
+    fn recount_feed_of(&mut self, item: &FeedItem) {
+        self.recount_feeds(&HashSet::from([item.feed_url.clone()]));
     }
 
-    pub fn toggle_read_status(&mut self) {
-        if let Some(visible_index) = self.selected_index {
-            if let Some(actual_index) = self.get_actual_index(visible_index) {
-                if let Some(item) = self.current_feed_content.get(actual_index) {
-                    if self.read_items.contains(&item.id) {
-                        self.read_items.remove(&item.id);
-                        debug!("Marked item as unread: {}", item.title);
-                    } else {
-                        self.read_items.insert(item.id.clone());
-                        debug!("Marked item as read: {}", item.title);
-                    }
-                    self.save_state().unwrap_or_else(|e| {
-                        error!("Failed to save read status: {}", e);
-                    });
-                }
+    /// URL of the feed an article belongs to, looked up in the loaded and cached articles
+    fn feed_url_of(&self, id: &str) -> Option<String> {
+        self.current_feed_content
+            .iter()
+            .chain(self.cached_items.values().flatten())
+            .find(|item| item.id == id)
+            .map(|item| item.feed_url.clone())
+    }
+
+    /// Recounts the cached articles matching each saved search
+    fn update_saved_search_counts(&mut self) {
+        self.saved_search_counts_stale = false;
+        let items: Vec<&FeedItem> = self
+            .rss_feeds
+            .iter()
+            .filter(|feed| !feed.paused)
+            .filter_map(|feed| self.cached_items.get(&feed.url))
+            .flatten()
+            .collect();
+        let counts = self
+            .config
+            .saved_searches
+            .iter()
+            .map(|search| {
+                let counts = self.count_for_saved_search(search, items.iter().copied());
+                (search.name.clone(), counts)
+            })
+            .collect();
+        self.saved_search_counts = counts;
+    }
+
+    /// Returns the (unread, total) number of cached articles matching a saved search
+    pub fn saved_search_count(&self, search: &SavedSearch) -> (usize, usize) {
+        self.saved_search_counts
+            .get(&search.name)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn count_items(&self, items: &[FeedItem]) -> FeedStats {
+        FeedStats {
+            total: items.len(),
+            unread: items.iter().filter(|item| !self.is_item_read(item)).count(),
+            newest: items.iter().filter_map(|item| item.published).max(),
+        }
+    }
+
+    /// Reads the cache file of every feed into memory, expired or not, and recounts them
+    pub fn load_cached_items(&mut self) {
+        self.cached_items = self
+            .rss_feeds
+            .iter()
+            .filter_map(|feed| {
+                let cache = Self::read_feed_cache(&feed.url)?;
+                Some((feed.url.clone(), cache.content))
+            })
+            .collect();
+        self.update_feed_stats();
+    }
+
+    /// Returns the cached items of every subscribed feed, newest first.
+    pub fn all_cached_items(&self) -> Vec<FeedItem> {
+        let mut items: Vec<FeedItem> = self
+            .rss_feeds
+            .iter()
+            .filter_map(|feed| self.cached_items.get(&feed.url))
+            .flatten()
+            .cloned()
+            .collect();
+        items.sort_by_key(|item| std::cmp::Reverse(item.published));
+        items
+    }
+
+    /// Returns the (unread, total) number of items matching a saved search
+    pub fn count_for_saved_search<'a>(
+        &self,
+        search: &SavedSearch,
+        items: impl IntoIterator<Item = &'a FeedItem>,
+    ) -> (usize, usize) {
+        let Ok(query) = search.parsed_query() else {
+            return (0, 0);
+        };
+        let matching: Vec<&FeedItem> = items
+            .into_iter()
+            .filter(|item| query.as_ref().is_none_or(|q| q.matches(item, self)))
+            .filter(|item| !search.unread_only || !self.is_item_read(item))
+            .collect();
+        let unread = matching
+            .iter()
+            .filter(|item| !self.is_item_read(item))
+            .count();
+        (unread, matching.len())
+    }
+
+    /// Rows of the Feed Manager: uncategorized feeds, the category tree (collapsed
+    /// categories hide everything below them), then saved searches
+    pub fn feed_manager_rows(&self) -> Vec<FeedManagerRow> {
+        let mut rows = Vec::new();
+        self.push_category_rows(None, 0, &mut rows);
+        rows.extend((0..self.config.saved_searches.len()).map(FeedManagerRow::SavedSearch));
+        rows
+    }
+
+    /// Adds the feeds directly in `parent` (None for the root), then its sub-categories.
+    /// Pinned feeds come first at the root instead of in their category.
+    fn push_category_rows(
+        &self,
+        parent: Option<&str>,
+        depth: usize,
+        rows: &mut Vec<FeedManagerRow>,
+    ) {
+        if parent.is_none() {
+            for index in self.sorted_feeds(|feed| feed.pinned) {
+                rows.push(FeedManagerRow::Feed { index, depth });
+            }
+        }
+        for index in self.sorted_feeds(|feed| !feed.pinned && feed.category.as_deref() == parent) {
+            rows.push(FeedManagerRow::Feed { index, depth });
+        }
+        for path in self.child_categories(parent) {
+            let collapsed = self.collapsed_categories.contains(&path);
+            rows.push(FeedManagerRow::Category {
+                path: path.clone(),
+                depth,
+                collapsed,
+            });
+            if !collapsed {
+                self.push_category_rows(Some(&path), depth + 1, rows);
             }
         }
     }
 
-    /// Marks the currently selected item as read if mark_read_on_scroll is enabled.
-    /// This is called when navigating away from an item (scrolling to the next one).
-    /// Does not save state immediately to avoid excessive disk writes during rapid scrolling;
-    /// state will be saved on quit or next explicit save action.
-    fn mark_current_as_read_on_scroll(&mut self) {
-        if !self.config.mark_read_on_scroll {
-            return;
+    /// Indices of the feeds passing `filter`, in the Feed Manager's order
+    pub fn sorted_feeds(&self, filter: impl Fn(&FeedInfo) -> bool) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.rss_feeds.len())
+            .filter(|&index| filter(&self.rss_feeds[index]))
+            .collect();
+        // Sorting is stable, so ties keep the manual order
+        let feed = |index: usize| &self.rss_feeds[index];
+        match self.feed_sort {
+            FeedSort::Manual => {}
+            FeedSort::Unread => indices
+                .sort_by_key(|&index| std::cmp::Reverse(self.feed_stats(&feed(index).url).unread)),
+            FeedSort::Updated => indices
+                .sort_by_key(|&index| std::cmp::Reverse(self.feed_stats(&feed(index).url).newest)),
+            FeedSort::Health => {
+                indices.sort_by_key(
+                    |&index| match self.get_feed_health(&feed(index).url).status {
+                        FeedStatus::Broken => 0,
+                        FeedStatus::Slow => 1,
+                        FeedStatus::Unknown => 2,
+                        FeedStatus::Healthy => 3,
+                    },
+                )
+            }
+            FeedSort::Name => indices.sort_by_cached_key(|&index| feed(index).title.to_lowercase()),
         }
+        indices
+    }
 
-        // Only mark read in FeedList or Favorites mode
-        if self.page_mode != PageMode::FeedList && self.page_mode != PageMode::Favorites {
-            return;
+    /// The categories directly inside `parent` (None for the top level), in the order
+    /// set by moving them; categories never moved follow alphabetically
+    pub fn child_categories(&self, parent: Option<&str>) -> Vec<String> {
+        let mut children: Vec<String> = self
+            .category_paths()
+            .into_iter()
+            .filter(|path| match parent {
+                None => !path.contains('/'),
+                Some(parent) => path
+                    .strip_prefix(parent)
+                    .and_then(|rest| rest.strip_prefix('/'))
+                    .is_some_and(|rest| !rest.contains('/')),
+            })
+            .collect();
+        children.sort_by_key(|path| {
+            self.category_order
+                .iter()
+                .position(|ordered| ordered == path)
+                .unwrap_or(usize::MAX)
+        });
+        children
+    }
+
+    /// Every category path in Feed Manager order: each category followed by its sub-categories
+    fn ordered_category_paths(&self, parent: Option<&str>, paths: &mut Vec<String>) {
+        for path in self.child_categories(parent) {
+            paths.push(path.clone());
+            self.ordered_category_paths(Some(&path), paths);
         }
+    }
 
-        if let Some(visible_index) = self.selected_index {
-            if let Some(actual_index) = self.get_actual_index(visible_index) {
-                if let Some(item) = self.current_feed_content.get(actual_index) {
-                    if !self.read_items.contains(&item.id) {
-                        self.read_items.insert(item.id.clone());
-                        debug!("Auto-marked item as read on scroll: {}", item.title);
-                    }
+    /// Returns every category path in use, including parents of nested categories, sorted
+    pub fn category_paths(&self) -> Vec<String> {
+        let mut paths = BTreeSet::new();
+        for category in self.rss_feeds.iter().filter_map(|f| f.category.as_deref()) {
+            let mut path = String::new();
+            for part in category.split('/') {
+                if !path.is_empty() {
+                    path.push('/');
                 }
+                path.push_str(part);
+                paths.insert(path.clone());
             }
         }
+        paths.into_iter().collect()
     }
 
-    fn save_state(&self) -> AppResult<()> {
-        let saved = SavedState {
-            feeds: self.rss_feeds.clone(),
-            read_items: self.read_items.clone(),
-            favorites: self.favorites.clone(),
-        };
-        let content = serde_json::to_string_pretty(&saved)?;
-        fs::write(&self.save_path, content)?;
-        debug!(
-            "Saved {} feeds, {} read items, and {} favorites to {}",
-            self.rss_feeds.len(),
-            self.read_items.len(),
-            self.favorites.len(),
-            self.save_path.display()
-        );
-        Ok(())
+    /// Number of rows in the Feed Manager
+    pub fn feed_manager_len(&self) -> usize {
+        self.feed_manager_rows().len()
     }
 
-    fn load_feeds(&mut self) -> AppResult<()> {
-        if self.save_path.exists() {
-            let content = match fs::read_to_string(&self.save_path) {
-                Ok(c) => c,
-                Err(e) => {
-                    error!("Failed to read feeds file: {}. Clearing corrupted data.", e);
-                    if let Err(e) = fs::remove_file(&self.save_path) {
-                        error!("Failed to remove corrupted file: {}", e);
-                    }
-                    self.error_message = Some(
-                        "Feeds data was corrupted and has been cleared. Starting fresh."
-                            .to_string(),
-                    );
-                    return Ok(());
-                }
-            };
+    /// Returns the index into `rss_feeds` of the feed at a Feed Manager row, if that row is one
+    pub fn feed_index_at(&self, row: usize) -> Option<usize> {
+        match self.feed_manager_rows().get(row) {
+            Some(FeedManagerRow::Feed { index, .. }) => Some(*index),
+            _ => None,
+        }
+    }
 
-            // Try to parse with new format first (Vec<FeedInfo>)
-            match serde_json::from_str::<SavedState>(&content) {
-                Ok(saved) => {
-                    self.rss_feeds = saved.feeds;
-                    self.read_items = saved.read_items;
-                    self.favorites = saved.favorites;
-                    debug!(
-                        "Loaded {} feeds and {} favorites from {}",
-                        self.rss_feeds.len(),
-                        self.favorites.len(),
-                        self.save_path.display()
-                    );
-                }
-                Err(_) => {
-                    // Try parsing middle format (with favorites, but Vec<String> for feeds)
-                    #[derive(Debug, Serialize, Deserialize)]
-                    struct MiddleSavedState {
-                        feeds: Vec<String>,
-                        read_items: HashSet<String>,
-                        favorites: HashSet<String>,
-                    }
+    /// Returns the category path at a Feed Manager row, if that row is a category
+    pub fn category_at(&self, row: usize) -> Option<String> {
+        match self.feed_manager_rows().get(row) {
+            Some(FeedManagerRow::Category { path, .. }) => Some(path.clone()),
+            _ => None,
+        }
+    }
 
-                    if let Ok(middle_saved) = serde_json::from_str::<MiddleSavedState>(&content) {
-                        // Convert Vec<String> to Vec<FeedInfo> using URL as title
-                        self.rss_feeds = middle_saved
-                            .feeds
-                            .into_iter()
-                            .map(|url| FeedInfo {
-                                title: url.clone(),
-                                url,
-                                category: None,
-                            })
-                            .collect();
-                        self.read_items = middle_saved.read_items;
-                        self.favorites = middle_saved.favorites;
-                        debug!(
-                            "Loaded {} feeds from middle format state file {}",
-                            self.rss_feeds.len(),
-                            self.save_path.display()
-                        );
-                    } else {
-                        // Try parsing oldest format (without favorites)
-                        #[derive(Debug, Serialize, Deserialize)]
-                        struct OldSavedState {
-                            feeds: Vec<String>,
-                            read_items: HashSet<String>,
-                        }
+    /// Returns the saved search at a Feed Manager row, if that row is one
+    pub fn saved_search_at(&self, row: usize) -> Option<&SavedSearch> {
+        match self.feed_manager_rows().get(row) {
+            Some(FeedManagerRow::SavedSearch(i)) => self.config.saved_searches.get(*i),
+            _ => None,
+        }
+    }
 
-                        if let Ok(old_saved) = serde_json::from_str::<OldSavedState>(&content) {
-                            // Convert Vec<String> to Vec<FeedInfo> using URL as title
-                            self.rss_feeds = old_saved
-                                .feeds
-                                .into_iter()
-                                .map(|url| FeedInfo {
-                                    title: url.clone(),
-                                    url,
-                                    category: None,
-                                })
-                                .collect();
-                            self.read_items = old_saved.read_items;
-                            self.favorites = HashSet::new(); // Initialize empty favorites
-                            debug!(
-                                "Loaded {} feeds from old format state file {}",
-                                self.rss_feeds.len(),
-                                self.save_path.display()
-                            );
-                        } else {
-                            // All parsing attempts failed - clear the corrupted file and start fresh
-                            error!(
-                                "Failed to parse feeds file: {}. Clearing corrupted data.",
-                                self.save_path.display()
-                            );
-                            if let Err(e) = fs::remove_file(&self.save_path) {
-                                error!("Failed to remove corrupted file: {}", e);
-                            }
-                            self.error_message = Some(
-                                "Feeds data was corrupted and has been cleared. Starting fresh."
-                                    .to_string(),
-                            );
-                        }
-                    }
-                }
+    /// Collapses or expands the category at a Feed Manager row
+    pub fn toggle_category_collapse(&mut self, row: usize) {
+        if let Some(path) = self.category_at(row) {
+            if !self.collapsed_categories.remove(&path) {
+                self.collapsed_categories.insert(path);
             }
         }
-        Ok(())
     }
 
-    pub fn toggle_feed_manager(&mut self) {
-        match self.page_mode {
-            PageMode::FeedList => {
-                self.page_mode = PageMode::FeedManager;
-                self.selected_index = Some(0);
-                self.scroll = 0; // Reset scroll position
-            }
-            PageMode::FeedManager => {
-                self.page_mode = PageMode::FeedList;
-                // Reset selection and trigger refresh
-                self.selected_index = Some(0);
-                self.scroll = 0; // Reset scroll position
-            }
-            PageMode::Favorites => {
-                self.page_mode = PageMode::FeedManager;
-                self.selected_index = Some(0);
-                self.scroll = 0; // Reset scroll position
-            }
+    /// Indices into `rss_feeds` of the feeds a Feed Manager row stands for: the feed itself,
+    /// or every feed filed under a category (collapsed or not)
+    fn feeds_in_row(&self, row: &FeedManagerRow) -> Vec<usize> {
+        match row {
+            FeedManagerRow::Feed { index, .. } => vec![*index],
+            FeedManagerRow::Category { path, .. } => (0..self.rss_feeds.len())
+                .filter(|&i| {
+                    self.rss_feeds[i]
+                        .category
+                        .as_deref()
+                        .is_some_and(|c| category_in_path(c, path))
+                })
+                .collect(),
+            FeedManagerRow::SavedSearch(_) => Vec::new(),
         }
     }
 
-    pub fn select_previous(&mut self) {
-        self.status_message = None; // Clear status on navigation
-        if let Some(current) = self.selected_index {
-            let len = match self.page_mode {
-                PageMode::FeedList | PageMode::Favorites => self.visible_item_count(),
-                PageMode::FeedManager => self.rss_feeds.len(),
-            };
-            if len == 0 {
-                return;
+    /// Marks the selected feed for bulk actions, or unmarks it, and moves down a row.
+    /// On a category row every feed filed under it is marked (or unmarked if all are).
+    pub fn toggle_mark_selected_feed(&mut self) {
+        let Some(row) = self.selected_index else {
+            return;
+        };
+        let urls: Vec<String> = self
+            .feed_manager_rows()
+            .get(row)
+            .map(|row| self.feeds_in_row(row))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|i| self.rss_feeds[i].url.clone())
+            .collect();
+        if urls.iter().all(|url| self.marked_feeds.contains(url)) {
+            for url in &urls {
+                self.marked_feeds.remove(url);
             }
-            self.selected_index = Some(if current > 0 { current - 1 } else { len - 1 });
-            self.ensure_selection_visible();
+        } else {
+            self.marked_feeds.extend(urls);
         }
+        self.select_next();
     }
 
-    pub fn select_next(&mut self) {
-        self.status_message = None; // Clear status on navigation
-        if let Some(current) = self.selected_index {
-            let len = match self.page_mode {
-                PageMode::FeedList | PageMode::Favorites => self.visible_item_count(),
-                PageMode::FeedManager => self.rss_feeds.len(),
-            };
-            if len == 0 {
-                return;
-            }
-            // Mark current item as read before moving to the next (if enabled)
-            self.mark_current_as_read_on_scroll();
-            self.selected_index = Some((current + 1) % len);
-            self.ensure_selection_visible();
+    /// Starts a visual range at the selected Feed Manager row, or ends it and marks the
+    /// feeds it covered
+    pub fn toggle_feed_visual(&mut self) {
+        if self.feed_visual_start.is_some() {
+            self.commit_feed_visual();
+        } else {
+            self.feed_visual_start = self.selected_index;
         }
     }
 
-    /// Calculates the number of visible items based on terminal height and page mode.
-    /// This accounts for UI chrome (title bar, command bar, borders, etc.)
-    pub fn items_per_page(&self) -> usize {
-        // Terminal layout: 3 lines title + 3 lines command bar = 6 lines of chrome
-        // Content area has 2 lines for borders
-        let content_height = self.terminal_height.saturating_sub(8) as usize;
+    /// Indices into `rss_feeds` of the feeds covered by the visual range
+    pub fn visual_feed_indices(&self) -> HashSet<usize> {
+        self.visual_feed_indices_in(&self.feed_manager_rows())
+    }
 
-        match self.page_mode {
-            // FeedList/Favorites: each item takes 3 lines (title, description snippet, metadata)
-            PageMode::FeedList | PageMode::Favorites => (content_height / 3).max(1),
-            // FeedManager: each item takes 1 line, minus 1 for status line
-            PageMode::FeedManager => content_height.saturating_sub(1).max(1),
-        }
+    /// Like `visual_feed_indices`, for Feed Manager rows that were already built
+    pub fn visual_feed_indices_in(&self, rows: &[FeedManagerRow]) -> HashSet<usize> {
+        let (Some(start), Some(end)) = (self.feed_visual_start, self.selected_index) else {
+            return HashSet::new();
+        };
+        rows.iter()
+            .take(start.max(end) + 1)
+            .skip(start.min(end))
+            .flat_map(|row| self.feeds_in_row(row))
+            .collect()
     }
 
-    /// Ensures that the currently selected item is visible in the view
-    pub fn ensure_selection_visible(&mut self) {
-        if let Some(index) = self.selected_index {
-            // Make sure selection is not above the current scroll position
-            if (index as u16) < self.scroll {
-                self.scroll = index as u16;
-            }
+    /// Ends the visual range, adding the feeds it covered to the marked ones
+    fn commit_feed_visual(&mut self) {
+        let urls: Vec<String> = self
+            .visual_feed_indices()
+            .into_iter()
+            .map(|i| self.rss_feeds[i].url.clone())
+            .collect();
+        self.marked_feeds.extend(urls);
+        self.feed_visual_start = None;
+    }
 
-            // Calculate the number of visible items in the current view
-            let items_per_page = self.items_per_page();
+    /// True if bulk actions in the Feed Manager apply to marked feeds instead of the selection
+    pub fn has_marked_feeds(&self) -> bool {
+        !self.marked_feeds.is_empty() || self.feed_visual_start.is_some()
+    }
 
-            // Make sure selection is not below the visible area
-            if index >= (self.scroll as usize + items_per_page) {
-                self.scroll = (index - items_per_page + 1) as u16;
-            }
-        }
+    /// Unmarks every feed and leaves visual mode
+    pub fn clear_feed_marks(&mut self) {
+        self.marked_feeds.clear();
+        self.feed_visual_start = None;
     }
 
-    pub fn start_adding(&mut self) {
-        self.input_mode = InputMode::Adding;
-        self.input_buffer.clear();
+    /// Ends any visual range and returns the marked feeds as indices into `rss_feeds`
+    fn take_marked_feeds(&mut self) -> Vec<usize> {
+        self.commit_feed_visual();
+        (0..self.rss_feeds.len())
+            .filter(|&i| self.marked_feeds.contains(&self.rss_feeds[i].url))
+            .collect()
     }
 
-    pub fn cancel_adding(&mut self) {
-        self.input_mode = InputMode::Normal;
-        self.input_buffer.clear();
-        self.clear_error();
+    /// Unsubscribes from every marked feed at once
+    pub fn delete_marked_feeds(&mut self) {
+        let marked = self.take_marked_feeds();
+        self.marked_feeds.clear();
+        self.remove_feeds(&marked);
+        info!("Deleted {} marked feeds", marked.len());
+        self.status_message = Some(format!("Deleted {} feeds", marked.len()));
     }
 
-    pub fn toggle_help(&mut self) {
-        match self.input_mode {
-            InputMode::Help => self.input_mode = InputMode::Normal,
-            _ => self.input_mode = InputMode::Help,
+    /// Marks every cached article of the marked feeds as read
+    pub fn mark_marked_feeds_read(&mut self) {
+        let marked = self.take_marked_feeds();
+        let mut count = 0;
+        for index in &marked {
+            for item in self
+                .cached_items
+                .get(&self.rss_feeds[*index].url)
+                .into_iter()
+                .flatten()
+            {
+                if self.read_items.insert(item.id.clone()) {
+                    count += 1;
+                }
+            }
         }
+        let urls = marked
+            .iter()
+            .map(|&i| self.rss_feeds[i].url.clone())
+            .collect();
+        self.recount_feeds(&urls);
+        debug!("Marked {} articles read in {} feeds", count, marked.len());
+        self.status_message = Some(format!(
+            "Marked {} articles read in {} feeds",
+            count,
+            marked.len()
+        ));
+        self.save_state().unwrap_or_else(|e| {
+            error!("Failed to save read status: {}", e);
+        });
     }
 
-    pub fn start_deleting(&mut self) {
-        if !self.rss_feeds.is_empty() {
-            self.input_mode = InputMode::Deleting;
-            self.selected_index = Some(0);
-        }
+    /// Fetches the marked feeds again; the other feeds are taken from the cache
+    pub async fn refresh_marked_feeds(&mut self) -> AppResult<()> {
+        let urls: HashSet<String> = self
+            .take_marked_feeds()
+            .into_iter()
+            .map(|i| self.rss_feeds[i].url.clone())
+            .collect();
+        self.refresh_all_feeds_impl(true, Some(&urls)).await?;
+        self.status_message = Some(format!("Refreshed {} feeds", urls.len()));
+        Ok(())
     }
 
-    pub fn cancel_deleting(&mut self) {
-        self.input_mode = InputMode::Normal;
+    /// True if the feed with this URL is paused
+    pub fn is_feed_paused(&self, url: &str) -> bool {
+        self.rss_feeds
+            .iter()
+            .any(|feed| feed.url == url && feed.paused)
     }
 
-    pub fn delete_feed(&mut self, index: usize) {
-        if index < self.rss_feeds.len() {
-            self.rss_feeds.remove(index);
-            self.selected_index = None;
-            self.current_feed_content.clear();
+    /// Pauses the selected feed, or resumes it if it is paused
+    pub fn toggle_pause_selected_feed(&mut self) {
+        let marked = self.take_marked_feeds();
+        if !marked.is_empty() {
+            // Marked feeds are all paused, unless every one of them is paused already
+            let pause = marked.iter().any(|&i| !self.rss_feeds[i].paused);
+            for &i in &marked {
+                self.rss_feeds[i].paused = pause;
+            }
+            info!(
+                "{} {} marked feeds",
+                if pause { "Paused" } else { "Resumed" },
+                marked.len()
+            );
+            self.status_message = Some(format!(
+                "{} {} feeds",
+                if pause { "Paused" } else { "Resumed" },
+                marked.len()
+            ));
             if let Err(e) = self.save_feeds() {
-                error!("Failed to save feeds after deletion: {}", e);
+                error!("Failed to save paused feeds: {}", e);
                 self.error_message = Some("Failed to save feeds".to_string());
             }
+            self.refresh_after_pause();
+            return;
+        }
+        let Some(index) = self.selected_index.and_then(|row| self.feed_index_at(row)) else {
+            return;
+        };
+        let key = self
+            .config
+            .keybindings
+            .toggle_pause_feed
+            .split(',')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let feed = &mut self.rss_feeds[index];
+        feed.paused = !feed.paused;
+        info!(
+            "{} feed: {}",
+            if feed.paused { "Paused" } else { "Resumed" },
+            feed.title
+        );
+        self.status_message = Some(if feed.paused {
+            format!("Paused {} ({} resumes)", feed.title, key)
+        } else {
+            format!("Resumed {}", feed.title)
+        });
+        if let Err(e) = self.save_feeds() {
+            error!("Failed to save paused feed: {}", e);
+            self.error_message = Some("Failed to save feeds".to_string());
         }
+        self.refresh_after_pause();
     }
 
-    /// Exports all feed URLs to the clipboard using OSC 52, one URL per line
-    pub fn export_feeds_to_clipboard(&mut self) {
-        if self.rss_feeds.is_empty() {
-            self.error_message = Some("No feeds to export".to_string());
-            return;
+    /// Reloads the combined feed list and recounts saved searches after feeds were paused
+    /// or resumed; category counts read the paused state directly
+    fn refresh_after_pause(&mut self) {
+        if self.loaded_feed.is_none() && self.active_tag.is_none() && !self.search_everything {
+            self.current_feed_content = self.all_cached_items();
+            // The Feed Manager keeps its own selection, so only the filters are re-applied
+            self.update_filtered_indices();
         }
+        self.update_saved_search_counts();
+    }
 
-        let feed_list: String = self
-            .rss_feeds
-            .iter()
-            .map(|f| f.url.as_str())
-            .collect::<Vec<_>>()
-            .join("\n");
-        match copy_to_clipboard_osc52(&feed_list) {
-            Ok(()) => {
-                info!("Exported {} feeds to clipboard", self.rss_feeds.len());
-                self.status_message = Some(format!(
-                    "Exported {} feeds to clipboard",
-                    self.rss_feeds.len()
-                ));
-            }
-            Err(e) => {
-                error!("Failed to copy to clipboard: {}", e);
-                self.error_message = Some(format!("Failed to copy to clipboard: {}", e));
+    /// Moves the selected feed or category one place up or down among its siblings.
+    /// Only possible in manual order; the selection follows the moved row.
+    pub fn move_selected_feed(&mut self, down: bool) {
+        let Some(row) = self.selected_index else {
+            return;
+        };
+        if self.feed_sort != FeedSort::Manual {
+            self.error_message = Some(format!(
+                "Feeds are sorted by {}; switch to manual order to move them",
+                self.feed_sort.label()
+            ));
+            return;
+        }
+        if let Some(index) = self.feed_index_at(row) {
+            let feed = &self.rss_feeds[index];
+            let (pinned, category) = (feed.pinned, feed.category.clone());
+            let siblings =
+                self.sorted_feeds(|f| f.pinned == pinned && (pinned || f.category == category));
+            let Some(target) = sibling_target(&siblings, index, down) else {
+                return;
+            };
+            self.rss_feeds.swap(index, target);
+            self.select_feed_row(target);
+        } else if let Some(path) = self.category_at(row) {
+            let parent = path.rsplit_once('/').map(|(parent, _)| parent.to_string());
+            let mut siblings = self.child_categories(parent.as_deref());
+            let Some(position) = siblings.iter().position(|p| *p == path) else {
+                return;
+            };
+            let target = if down {
+                position + 1
+            } else {
+                position.wrapping_sub(1)
+            };
+            if target >= siblings.len() {
+                return;
             }
+            siblings.swap(position, target);
+            // Relative positions are all that matter, so the siblings go to the end in order
+            self.category_order.retain(|p| !siblings.contains(p));
+            self.category_order.extend(siblings);
+            self.select_category_row(&path);
+        } else {
+            return;
+        }
+        if let Err(e) = self.save_feeds() {
+            error!("Failed to save feed order: {}", e);
+            self.error_message = Some("Failed to save feed order".to_string());
         }
     }
 
-    /// Starts the import mode
-    pub fn start_importing(&mut self) {
-        self.input_mode = InputMode::Importing;
-        self.input_buffer.clear();
-        self.import_result = None;
+    /// Pins the selected feed to the top of the Feed Manager, or unpins it
+    pub fn toggle_pin_selected_feed(&mut self) {
+        let Some(index) = self.selected_index.and_then(|row| self.feed_index_at(row)) else {
+            return;
+        };
+        let feed = &mut self.rss_feeds[index];
+        feed.pinned = !feed.pinned;
+        self.status_message = Some(format!(
+            "{} {}",
+            if feed.pinned { "Pinned" } else { "Unpinned" },
+            feed.title
+        ));
+        self.select_feed_row(index);
+        if let Err(e) = self.save_feeds() {
+            error!("Failed to save pinned feed: {}", e);
+            self.error_message = Some("Failed to save feeds".to_string());
+        }
+    }
 
-        // Try to pre-fill from clipboard
-        if let Ok(mut clipboard) = arboard::Clipboard::new() {
-            if let Ok(text) = clipboard.get_text() {
-                self.input_buffer = text;
-            }
+    /// Switches the Feed Manager to the next feed order and saves it
+    pub fn cycle_feed_sort(&mut self) {
+        self.set_feed_sort(self.feed_sort.next());
+    }
+
+    /// Changes the order of the feeds in the Feed Manager, keeping the selected row in view
+    pub fn set_feed_sort(&mut self, sort: FeedSort) {
+        let selected = self.selected_index.and_then(|row| self.feed_index_at(row));
+        self.feed_sort = sort;
+        if let Some(index) = selected {
+            self.select_feed_row(index);
+        }
+        self.status_message = Some(format!("Feeds sorted by {}", sort.label()));
+        if let Err(e) = self.save_state() {
+            error!("Failed to save feed order: {}", e);
         }
     }
 
-    /// Cancels the import mode
-    pub fn cancel_importing(&mut self) {
-        self.input_mode = InputMode::Normal;
-        self.input_buffer.clear();
-        self.import_result = None;
-        self.clear_error();
+    /// Selects the Feed Manager row of a feed, if it is shown
+    fn select_feed_row(&mut self, index: usize) {
+        let row = self
+            .feed_manager_rows()
+            .iter()
+            .position(|r| matches!(r, FeedManagerRow::Feed { index: i, .. } if *i == index));
+        if row.is_some() {
+            self.selected_index = row;
+            self.ensure_selection_visible();
+        }
     }
 
-    /// Exports feeds to OPML format and saves to a file
-    /// Returns the path where the file was saved
-    pub fn export_opml(&mut self) -> AppResult<PathBuf> {
-        if self.rss_feeds.is_empty() {
-            self.error_message = Some("No feeds to export".to_string());
-            return Err("No feeds to export".into());
+    /// Selects the Feed Manager row of a category, if it is shown
+    fn select_category_row(&mut self, path: &str) {
+        let row = self
+            .feed_manager_rows()
+            .iter()
+            .position(|r| matches!(r, FeedManagerRow::Category { path: p, .. } if p == path));
+        if row.is_some() {
+            self.selected_index = row;
+            self.ensure_selection_visible();
         }
+    }
 
-        let opml_content = self.generate_opml()?;
+    /// Returns the (feeds, unread) totals for a category and everything nested below it
+    pub fn count_for_category(&self, path: &str) -> (usize, usize) {
+        self.rss_feeds
+            .iter()
+            .filter(|f| {
+                f.category
+                    .as_deref()
+                    .is_some_and(|c| category_in_path(c, path))
+            })
+            .fold((0, 0), |(feeds, unread), f| {
+                let feed_unread = if f.paused {
+                    0
+                } else {
+                    self.count_unread_for_feed(&f.url)
+                };
+                (feeds + 1, unread + feed_unread)
+            })
+    }
 
-        // Save to the config directory
-        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
-        path.push("reedy");
-        fs::create_dir_all(&path)?;
-        path.push("feeds.opml");
+    /// Saves the current search and unread filter under a name, replacing any saved search
+    /// with the same name. Returns false if there is nothing to save.
+    pub fn save_current_search(&mut self, name: &str) -> bool {
+        let name = name.trim();
+        if name.is_empty() {
+            self.error_message = Some("Usage: :savesearch <name>".to_string());
+            return false;
+        }
+        if self.search_query.trim().is_empty() && !self.show_unread_only {
+            self.error_message = Some("No active search to save".to_string());
+            return false;
+        }
+        if let Some(e) = &self.search_error {
+            self.error_message = Some(format!("Invalid search: {}", e));
+            return false;
+        }
 
-        fs::write(&path, opml_content)?;
+        let search = SavedSearch::new(name, self.search_query.trim(), self.show_unread_only);
+        match self
+            .config
+            .saved_searches
+            .iter_mut()
+            .find(|s| s.name.eq_ignore_ascii_case(name))
+        {
+            Some(existing) => *existing = search,
+            None => self.config.saved_searches.push(search),
+        }
+        self.update_saved_search_counts();
+        if let Err(e) = self.save_config() {
+            error!("Failed to save config: {}", e);
+            self.error_message = Some(format!("Failed to save search: {}", e));
+            return false;
+        }
+        info!("Saved search '{}'", name);
+        self.active_saved_search = Some(name.to_string());
+        self.status_message = Some(format!("Saved search '{}'", name));
+        true
+    }
 
-        info!(
-            "Exported {} feeds to OPML: {}",
-            self.rss_feeds.len(),
-            path.display()
-        );
-        self.error_message = Some(format!(
-            "Exported {} feeds to {}",
-            self.rss_feeds.len(),
-            path.display()
-        ));
-
-        Ok(path)
+    /// Shows the items of every feed filtered by the saved search at `index`
+    pub fn open_saved_search(&mut self, index: usize) {
+        let Some(search) = self.config.saved_searches.get(index).cloned() else {
+            return;
+        };
+        debug!("Opening saved search '{}'", search.name);
+        self.current_feed_content = self.all_cached_items();
+        self.search_query = search.query;
+        self.show_unread_only = search.unread_only;
+        self.apply_filters();
+        self.active_saved_search = Some(search.name);
     }
 
-    /// Generates OPML XML content from current feeds
-    fn generate_opml(&self) -> AppResult<String> {
-        let mut writer = Writer::new(Cursor::new(Vec::new()));
-
-        // XML declaration
-        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
-
-        // OPML root element
-        let mut opml = BytesStart::new("opml");
-        opml.push_attribute(("version", "2.0"));
-        writer.write_event(Event::Start(opml))?;
-
-        // Head section
-        writer.write_event(Event::Start(BytesStart::new("head")))?;
-        writer.write_event(Event::Start(BytesStart::new("title")))?;
-        writer.write_event(Event::Text(BytesText::new("Reedy RSS Feeds")))?;
-        writer.write_event(Event::End(BytesEnd::new("title")))?;
-        writer.write_event(Event::End(BytesEnd::new("head")))?;
-
-        // Body section
-        writer.write_event(Event::Start(BytesStart::new("body")))?;
-
-        // Group feeds by category
-        let feeds_by_category = self.get_feeds_by_category();
-
-        for (category, feeds) in feeds_by_category {
-            match category {
-                Some(cat_name) => {
-                    // Create a category outline
-                    let mut cat_outline = BytesStart::new("outline");
-                    cat_outline.push_attribute(("text", cat_name.as_str()));
-                    cat_outline.push_attribute(("title", cat_name.as_str()));
-                    writer.write_event(Event::Start(cat_outline))?;
-
-                    // Write feeds in this category
-                    for feed in feeds {
-                        let mut outline = BytesStart::new("outline");
-                        outline.push_attribute(("type", "rss"));
-                        outline.push_attribute(("text", feed.title.as_str()));
-                        outline.push_attribute(("title", feed.title.as_str()));
-                        outline.push_attribute(("xmlUrl", feed.url.as_str()));
-                        writer.write_event(Event::Empty(outline))?;
-                    }
+    /// Returns the health status for a given feed URL.
+    /// Returns a default Unknown status if the feed hasn't been checked yet.
+    pub fn get_feed_health(&self, url: &str) -> FeedHealth {
+        self.feed_health.get(url).cloned().unwrap_or_default()
+    }
 
-                    writer.write_event(Event::End(BytesEnd::new("outline")))?;
-                }
-                None => {
-                    // Write uncategorized feeds at the top level
-                    for feed in feeds {
-                        let mut outline = BytesStart::new("outline");
-                        outline.push_attribute(("type", "rss"));
-                        outline.push_attribute(("text", feed.title.as_str()));
-                        outline.push_attribute(("title", feed.title.as_str()));
-                        outline.push_attribute(("xmlUrl", feed.url.as_str()));
-                        writer.write_event(Event::Empty(outline))?;
+    pub fn toggle_read_status(&mut self) {
+        if let Some(visible_index) = self.selected_index {
+            if let Some(actual_index) = self.get_actual_index(visible_index) {
+                if let Some(item) = self.current_feed_content.get(actual_index) {
+                    if self.read_items.contains(&item.id) {
+                        self.read_items.remove(&item.id);
+                        debug!("Marked item as unread: {}", item.title);
+                    } else {
+                        self.read_items.insert(item.id.clone());
+                        debug!("Marked item as read: {}", item.title);
                     }
+                    let item = item.clone();
+                    self.recount_feed_of(&item);
+                    self.save_state().unwrap_or_else(|e| {
+                        error!("Failed to save read status: {}", e);
+                    });
                 }
             }
         }
+    }
 
-        writer.write_event(Event::End(BytesEnd::new("body")))?;
-        writer.write_event(Event::End(BytesEnd::new("opml")))?;
+    /// Marks the currently selected item as read if mark_read_on_scroll is enabled.
+    /// This is called when navigating away from an item (scrolling to the next one).
+    /// Does not save state immediately to avoid excessive disk writes during rapid scrolling;
+    /// state will be saved on quit or next explicit save action.
+    fn mark_current_as_read_on_scroll(&mut self) {
+        if !self.config.mark_read_on_scroll {
+            return;
+        }
 
-        let result = writer.into_inner().into_inner();
-        Ok(String::from_utf8(result)?)
-    }
+        // Only mark read in FeedList or Favorites mode
+        if self.page_mode != PageMode::FeedList && self.page_mode != PageMode::Favorites {
+            return;
+        }
 
-    /// Imports feeds from an OPML file
-    pub async fn import_opml(&mut self, path: &PathBuf) -> AppResult<()> {
-        let content = fs::read_to_string(path)?;
-        self.parse_and_import_opml(&content).await
+        if let Some(visible_index) = self.selected_index {
+            if let Some(actual_index) = self.get_actual_index(visible_index) {
+                if let Some(item) = self.current_feed_content.get(actual_index) {
+                    if !self.read_items.contains(&item.id) {
+                        self.read_items.insert(item.id.clone());
+                        debug!("Auto-marked item as read on scroll: {}", item.title);
+                        self.unsaved_state = true;
+                        let item = item.clone();
+                        self.recount_feed_of(&item);
+                    }
+                }
+            }
+        }
     }
 
-    /// Imports feeds from OPML content string
-    pub async fn import_opml_content(&mut self, content: &str) -> AppResult<()> {
-        self.parse_and_import_opml(content).await
+    fn save_state(&mut self) -> AppResult<()> {
+        self.unsaved_state = false;
+        let saved = SavedState {
+            feeds: self.rss_feeds.clone(),
+            read_items: self.read_items.clone(),
+            favorites: self.favorites.clone(),
+            tagged_items: self.tagged_items.clone(),
+            view_orders: self.view_orders.clone(),
+            command_history: self.command_history.clone(),
+            category_order: self.category_order.clone(),
+            feed_sort: self.feed_sort,
+        };
+        let content = serde_json::to_string_pretty(&saved)?;
+        fs::write(&self.save_path, content)?;
+        debug!(
+            "Saved {} feeds, {} read items, and {} favorites to {}",
+            self.rss_feeds.len(),
+            self.read_items.len(),
+            self.favorites.len(),
+            self.save_path.display()
+        );
+        Ok(())
     }
 
-    /// Parses OPML content and imports feeds
-    async fn parse_and_import_opml(&mut self, content: &str) -> AppResult<()> {
-        let mut reader = Reader::from_str(content);
-        reader.config_mut().trim_text(true);
-
-        let mut added = 0;
-        let mut skipped_duplicate = 0;
-        let mut category_stack: Vec<String> = Vec::new();
+    fn load_feeds(&mut self) -> AppResult<()> {
+        if self.save_path.exists() {
+            let content = match fs::read_to_string(&self.save_path) {
+                Ok(c) => c,
+                Err(e) => {
+                    error!("Failed to read feeds file: {}. Clearing corrupted data.", e);
+                    if let Err(e) = fs::remove_file(&self.save_path) {
+                        error!("Failed to remove corrupted file: {}", e);
+                    }
+                    self.error_message = Some(
+                        "Feeds data was corrupted and has been cleared. Starting fresh."
+                            .to_string(),
+                    );
+                    return Ok(());
+                }
+            };
 
-        loop {
-            match reader.read_event() {
-                Ok(Event::Start(ref e)) if e.name().as_ref() == b"outline" => {
-                    // This is a start tag (has children) - could be a category or a feed
-                    let mut xml_url: Option<String> = None;
-                    let mut title: Option<String> = None;
-
-                    for attr in e.attributes().flatten() {
-                        match attr.key.as_ref() {
-                            b"xmlUrl" | b"xmlurl" => {
-                                xml_url = Some(String::from_utf8_lossy(&attr.value).to_string());
-                            }
-                            b"text" | b"title" => {
-                                if title.is_none() {
-                                    title = Some(String::from_utf8_lossy(&attr.value).to_string());
-                                }
-                            }
-                            _ => {}
-                        }
+            // Try to parse with new format first (Vec<FeedInfo>)
+            match serde_json::from_str::<SavedState>(&content) {
+                Ok(saved) => {
+                    self.rss_feeds = saved.feeds;
+                    self.read_items = saved.read_items;
+                    self.favorites = saved.favorites;
+                    self.tagged_items = saved.tagged_items;
+                    self.view_orders = saved.view_orders;
+                    self.command_history = saved.command_history;
+                    self.category_order = saved.category_order;
+                    self.feed_sort = saved.feed_sort;
+                    debug!(
+                        "Loaded {} feeds and {} favorites from {}",
+                        self.rss_feeds.len(),
+                        self.favorites.len(),
+                        self.save_path.display()
+                    );
+                }
+                Err(_) => {
+                    // Try parsing middle format (with favorites, but Vec<String> for feeds)
+                    #[derive(Debug, Serialize, Deserialize)]
+                    struct MiddleSavedState {
+                        feeds: Vec<String>,
+                        read_items: HashSet<String>,
+                        favorites: HashSet<String>,
                     }
 
-                    if xml_url.is_some() {
-                        // It's a feed with a start tag (unusual but valid)
-                        let url = xml_url.unwrap();
-                        if self.rss_feeds.iter().any(|f| f.url == url) {
-                            skipped_duplicate += 1;
-                        } else {
-                            let feed_title = title
-                                .filter(|t| !t.is_empty())
-                                .unwrap_or_else(|| url.clone());
-                            let category = category_stack.last().cloned();
-                            info!("Adding feed from OPML: {} ({})", feed_title, url);
-                            self.rss_feeds.push(FeedInfo {
+                    if let Ok(middle_saved) = serde_json::from_str::<MiddleSavedState>(&content) {
+                        // Convert Vec<String> to Vec<FeedInfo> using URL as title
+                        self.rss_feeds = middle_saved
+                            .feeds
+                            .into_iter()
+                            .map(|url| FeedInfo {
+                                title: url.clone(),
                                 url,
-                                title: feed_title,
-                                category,
-                            });
-                            added += 1;
+                                ..Default::default()
+                            })
+                            .collect();
+                        self.read_items = middle_saved.read_items;
+                        self.favorites = middle_saved.favorites;
+                        debug!(
+                            "Loaded {} feeds from middle format state file {}",
+                            self.rss_feeds.len(),
+                            self.save_path.display()
+                        );
+                    } else {
+                        // Try parsing oldest format (without favorites)
+                        #[derive(Debug, Serialize, Deserialize)]
+                        struct OldSavedState {
+                            feeds: Vec<String>,
+                            read_items: HashSet<String>,
                         }
-                    } else if let Some(cat_name) = title {
-                        // It's a category - push to stack
-                        category_stack.push(cat_name);
-                    }
-                }
-                Ok(Event::Empty(ref e)) if e.name().as_ref() == b"outline" => {
-                    // Self-closing tag - this is a feed
-                    let mut xml_url: Option<String> = None;
-                    let mut title: Option<String> = None;
 
-                    for attr in e.attributes().flatten() {
-                        match attr.key.as_ref() {
-                            b"xmlUrl" | b"xmlurl" => {
-                                xml_url = Some(String::from_utf8_lossy(&attr.value).to_string());
-                            }
-                            b"text" | b"title" => {
-                                if title.is_none() {
-                                    title = Some(String::from_utf8_lossy(&attr.value).to_string());
-                                }
+                        if let Ok(old_saved) = serde_json::from_str::<OldSavedState>(&content) {
+                            // Convert Vec<String> to Vec<FeedInfo> using URL as title
+                            self.rss_feeds = old_saved
+                                .feeds
+                                .into_iter()
+                                .map(|url| FeedInfo {
+                                    title: url.clone(),
+                                    url,
+                                    ..Default::default()
+                                })
+                                .collect();
+                            self.read_items = old_saved.read_items;
+                            self.favorites = HashSet::new(); // Initialize empty favorites
+                            debug!(
+                                "Loaded {} feeds from old format state file {}",
+                                self.rss_feeds.len(),
+                                self.save_path.display()
+                            );
+                        } else {
+                            // All parsing attempts failed - clear the corrupted file and start fresh
+                            error!(
+                                "Failed to parse feeds file: {}. Clearing corrupted data.",
+                                self.save_path.display()
+                            );
+                            if let Err(e) = fs::remove_file(&self.save_path) {
+                                error!("Failed to remove corrupted file: {}", e);
                             }
-                            _ => {}
-                        }
-                    }
-
-                    if let Some(url) = xml_url {
-                        // Skip duplicates
-                        if self.rss_feeds.iter().any(|f| f.url == url) {
-                            skipped_duplicate += 1;
-                            continue;
+                            self.error_message = Some(
+                                "Feeds data was corrupted and has been cleared. Starting fresh."
+                                    .to_string(),
+                            );
                         }
-
-                        // Use the title from OPML or use the URL as fallback
-                        let feed_title = title
-                            .filter(|t| !t.is_empty())
-                            .unwrap_or_else(|| url.clone());
-                        let category = category_stack.last().cloned();
-
-                        info!("Adding feed from OPML: {} ({})", feed_title, url);
-                        self.rss_feeds.push(FeedInfo {
-                            url,
-                            title: feed_title,
-                            category,
-                        });
-                        added += 1;
                     }
                 }
-                Ok(Event::End(ref e)) if e.name().as_ref() == b"outline" => {
-                    // Exiting an outline - pop category if we have one
-                    category_stack.pop();
-                }
-                Ok(Event::Eof) => break,
-                Err(e) => {
-                    error!("Error parsing OPML: {}", e);
-                    return Err(format!("Error parsing OPML: {}", e).into());
-                }
-                _ => {}
             }
         }
-
-        // Save if we added any feeds
-        if added > 0 {
-            self.save_feeds()?;
-        }
-
-        // Build result message
-        let mut result_parts = Vec::new();
-        if added > 0 {
-            result_parts.push(format!("{} added", added));
-        }
-        if skipped_duplicate > 0 {
-            result_parts.push(format!("{} duplicate", skipped_duplicate));
-        }
-
-        let result_msg = if result_parts.is_empty() {
-            "OPML Import: No feeds found".to_string()
-        } else {
-            format!("OPML Import: {}", result_parts.join(", "))
-        };
-        self.import_result = Some(result_msg.clone());
-        self.error_message = Some(result_msg);
-
         Ok(())
     }
 
-    /// Gets the default OPML file path for import
-    pub fn get_opml_path() -> PathBuf {
-        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
-        path.push("reedy");
-        path.push("feeds.opml");
-        path
-    }
-
-    /// Starts category setting mode for the currently selected feed
-    pub fn start_setting_category(&mut self) {
-        if self.selected_index.is_some() && !self.rss_feeds.is_empty() {
-            self.input_mode = InputMode::SettingCategory;
-            // Pre-fill with current category if it exists
-            if let Some(index) = self.selected_index {
-                if let Some(feed) = self.rss_feeds.get(index) {
-                    self.input_buffer = feed.category.clone().unwrap_or_default();
-                }
-            }
+    pub fn toggle_feed_manager(&mut self) {
+        self.clear_feed_marks();
+        self.clear_item_marks();
+        match self.page_mode {
+            PageMode::FeedList => {
+                self.page_mode = PageMode::FeedManager;
+                self.selected_index = Some(0);
+                self.scroll = 0; // Reset scroll position
+            }
+            PageMode::FeedManager => {
+                self.page_mode = PageMode::FeedList;
+                // Reset selection and trigger refresh
+                self.selected_index = Some(0);
+                self.scroll = 0; // Reset scroll position
+            }
+            PageMode::Favorites | PageMode::MuteRules | PageMode::Settings => {
+                self.page_mode = PageMode::FeedManager;
+                self.input_mode = InputMode::Normal;
+                self.selected_index = Some(0);
+                self.scroll = 0; // Reset scroll position
+            }
         }
     }
 
-    /// Cancels category setting mode
-    pub fn cancel_setting_category(&mut self) {
-        self.input_mode = InputMode::Normal;
-        self.input_buffer.clear();
-        self.clear_error();
+    pub fn select_previous(&mut self) {
+        self.status_message = None; // Clear status on navigation
+        if let Some(current) = self.selected_index {
+            let len = match self.page_mode {
+                PageMode::FeedList | PageMode::Favorites => self.visible_item_count(),
+                PageMode::FeedManager => self.feed_manager_len(),
+                PageMode::MuteRules => self.config.mute_rules.len(),
+                PageMode::Settings => self.settings().len(),
+            };
+            if len == 0 {
+                return;
+            }
+            self.selected_index = Some(if current > 0 { current - 1 } else { len - 1 });
+            self.ensure_selection_visible();
+        }
     }
 
-    /// Sets the category for the currently selected feed
-    pub fn set_category(&mut self) {
+    pub fn select_next(&mut self) {
+        self.status_message = None; // Clear status on navigation
+        if let Some(current) = self.selected_index {
+            let len = match self.page_mode {
+                PageMode::FeedList | PageMode::Favorites => self.visible_item_count(),
+                PageMode::FeedManager => self.feed_manager_len(),
+                PageMode::MuteRules => self.config.mute_rules.len(),
+                PageMode::Settings => self.settings().len(),
+            };
+            if len == 0 {
+                return;
+            }
+            // Mark current item as read before moving to the next (if enabled)
+            self.mark_current_as_read_on_scroll();
+            self.selected_index = Some((current + 1) % len);
+            self.ensure_selection_visible();
+        }
+    }
+
+    /// Calculates the number of visible items based on terminal height and page mode.
+    /// This accounts for UI chrome (title bar, command bar, borders, etc.)
+    pub fn items_per_page(&self) -> usize {
+        // Terminal layout: 3 lines title + 3 lines command bar = 6 lines of chrome
+        // Content area has 2 lines for borders
+        let content_height = self.terminal_height.saturating_sub(8) as usize;
+
+        match self.page_mode {
+            // FeedList/Favorites: each item takes 3 lines (title, description snippet, metadata),
+            // plus a line for each group header
+            PageMode::FeedList | PageMode::Favorites => {
+                self.items_fitting(self.scroll as usize, content_height)
+            }
+            // FeedManager/MuteRules/Settings: each item takes 1 line, minus 1 for status line
+            PageMode::FeedManager | PageMode::MuteRules | PageMode::Settings => {
+                content_height.saturating_sub(1).max(1)
+            }
+        }
+    }
+
+    /// Ensures that the currently selected item is visible in the view
+    pub fn ensure_selection_visible(&mut self) {
         if let Some(index) = self.selected_index {
-            if let Some(feed) = self.rss_feeds.get_mut(index) {
-                let category = self.input_buffer.trim().to_string();
-                if category.is_empty() {
-                    feed.category = None;
-                    info!("Cleared category for feed: {}", feed.title);
-                } else {
-                    feed.category = Some(category.clone());
-                    info!("Set category '{}' for feed: {}", category, feed.title);
-                }
-                if let Err(e) = self.save_feeds() {
-                    error!("Failed to save feeds after setting category: {}", e);
-                    self.error_message = Some("Failed to save category".to_string());
+            // Make sure selection is not above the current scroll position
+            if (index as u16) < self.scroll {
+                self.scroll = index as u16;
+            }
+
+            // Calculate the number of visible items in the current view
+            let items_per_page = self.items_per_page();
+
+            // Make sure selection is not below the visible area
+            if index >= (self.scroll as usize + items_per_page) {
+                self.scroll = (index - items_per_page + 1) as u16;
+                // Group headers change how many items fit, so step down until the selection fits
+                while index >= self.scroll as usize + self.items_per_page() {
+                    self.scroll += 1;
                 }
             }
         }
+    }
+
+    pub fn start_adding(&mut self) {
+        self.input_mode = InputMode::Adding;
+        self.input_buffer.clear();
+    }
+
+    pub fn cancel_adding(&mut self) {
         self.input_mode = InputMode::Normal;
         self.input_buffer.clear();
+        self.clear_error();
     }
 
-    /// Returns a sorted list of unique categories used by feeds
-    pub fn get_categories(&self) -> Vec<String> {
-        let mut categories: Vec<String> = self
-            .rss_feeds
+    pub fn toggle_help(&mut self) {
+        match self.input_mode {
+            InputMode::Help => self.input_mode = InputMode::Normal,
+            _ => self.input_mode = InputMode::Help,
+        }
+    }
+
+    pub fn start_deleting(&mut self) {
+        // Marked feeds are deleted together, so the selection stays where it is
+        if self.has_marked_feeds() {
+            self.commit_feed_visual();
+            self.input_mode = InputMode::Deleting;
+        } else if self.feed_manager_len() > 0 {
+            self.input_mode = InputMode::Deleting;
+            self.selected_index = Some(0);
+        }
+    }
+
+    pub fn cancel_deleting(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Deletes the feed or saved search at a Feed Manager row
+    pub fn delete_feed(&mut self, row: usize) {
+        if let Some(path) = self.category_at(row) {
+            self.error_message = Some(format!(
+                "Category '{}' is not empty; delete or move its feeds first",
+                path
+            ));
+            return;
+        }
+        if let Some(search) = self.saved_search_at(row).cloned() {
+            self.config.saved_searches.retain(|s| s.name != search.name);
+            self.update_saved_search_counts();
+            self.selected_index = None;
+            if let Err(e) = self.save_config() {
+                error!("Failed to save config after deleting search: {}", e);
+                self.error_message = Some("Failed to save config".to_string());
+            }
+            return;
+        }
+        if let Some(index) = self.feed_index_at(row) {
+            self.remove_feeds(&[index]);
+        }
+    }
+
+    /// Unsubscribes from the feeds at the given indices of `rss_feeds`
+    fn remove_feeds(&mut self, indices: &[usize]) {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+        // From the back, so the remaining indices stay valid
+        for &index in indices.iter().rev() {
+            let feed = self.rss_feeds.remove(index);
+            info!("Deleted feed: {} ({})", feed.title, feed.url);
+            self.cached_items.remove(&feed.url);
+            self.feed_stats.remove(&feed.url);
+            if self.loaded_feed.as_deref() == Some(feed.url.as_str()) {
+                self.loaded_feed = None;
+            }
+        }
+        self.update_saved_search_counts();
+        self.selected_index = None;
+        self.current_feed_content.clear();
+        if let Err(e) = self.save_feeds() {
+            error!("Failed to save feeds after deletion: {}", e);
+            self.error_message = Some("Failed to save feeds".to_string());
+        }
+    }
+
+    /// Exports the marked feed URLs, or all of them, to the clipboard using OSC 52,
+    /// one URL per line
+    pub fn export_feeds_to_clipboard(&mut self) {
+        let mut indices = self.take_marked_feeds();
+        if indices.is_empty() {
+            indices = (0..self.rss_feeds.len()).collect();
+        }
+        if indices.is_empty() {
+            self.error_message = Some("No feeds to export".to_string());
+            return;
+        }
+
+        let feed_list: String = indices
             .iter()
-            .filter_map(|f| f.category.clone())
-            .collect::<std::collections::HashSet<_>>()
+            .map(|&i| self.rss_feeds[i].url.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        match copy_to_clipboard_osc52(&feed_list) {
+            Ok(()) => {
+                info!("Exported {} feeds to clipboard", indices.len());
+                self.status_message =
+                    Some(format!("Exported {} feeds to clipboard", indices.len()));
+            }
+            Err(e) => {
+                error!("Failed to copy to clipboard: {}", e);
+                self.error_message = Some(format!("Failed to copy to clipboard: {}", e));
+            }
+        }
+    }
+
+    /// Starts the import mode
+    pub fn start_importing(&mut self) {
+        self.input_mode = InputMode::Importing;
+        self.input_buffer.clear();
+        self.import_result = None;
+
+        // Try to pre-fill from clipboard
+        if let Ok(mut clipboard) = arboard::Clipboard::new() {
+            if let Ok(text) = clipboard.get_text() {
+                self.input_buffer = text;
+            }
+        }
+    }
+
+    /// Cancels the import mode
+    pub fn cancel_importing(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+        self.import_result = None;
+        self.clear_error();
+    }
+
+    /// Exports feeds to OPML format and saves to a file
+    /// Returns the path where the file was saved
+    pub fn export_opml(&mut self) -> AppResult<PathBuf> {
+        // Marked feeds go to their own file so the full export is left alone
+        let marked: HashSet<String> = self
+            .take_marked_feeds()
             .into_iter()
+            .map(|i| self.rss_feeds[i].url.clone())
             .collect();
-        categories.sort();
-        categories
+        if !marked.is_empty() {
+            let path = Self::get_opml_path().with_file_name("feeds-selected.opml");
+            fs::write(&path, self.generate_opml_of(Some(&marked))?)?;
+            info!(
+                "Exported {} marked feeds to OPML: {}",
+                marked.len(),
+                path.display()
+            );
+            self.status_message = Some(format!(
+                "Exported {} feeds to {}",
+                marked.len(),
+                path.display()
+            ));
+            return Ok(path);
+        }
+
+        if self.rss_feeds.is_empty() {
+            self.error_message = Some("No feeds to export".to_string());
+            return Err("No feeds to export".into());
+        }
+
+        let opml_content = self.generate_opml()?;
+
+        // Save to the config directory
+        let path = Self::get_opml_path();
+
+        fs::write(&path, opml_content)?;
+
+        info!(
+            "Exported {} feeds to OPML: {}",
+            self.rss_feeds.len(),
+            path.display()
+        );
+        self.error_message = Some(format!(
+            "Exported {} feeds to {}",
+            self.rss_feeds.len(),
+            path.display()
+        ));
+
+        Ok(path)
     }
 
-    /// Returns feeds grouped by category. Uncategorized feeds are grouped under None.
-    pub fn get_feeds_by_category(&self) -> Vec<(Option<String>, Vec<&FeedInfo>)> {
-        use std::collections::BTreeMap;
+    /// Generates OPML XML content from current feeds
+    pub fn generate_opml(&self) -> AppResult<String> {
+        self.generate_opml_of(None)
+    }
+
+    /// Generates OPML XML content from the feeds with the given URLs (all feeds for `None`)
+    pub fn generate_opml_of(&self, only: Option<&HashSet<String>>) -> AppResult<String> {
+        let mut writer = Writer::new(Cursor::new(Vec::new()));
+
+        // XML declaration
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+        // OPML root element
+        let mut opml = BytesStart::new("opml");
+        opml.push_attribute(("version", "2.0"));
+        writer.write_event(Event::Start(opml))?;
+
+        // Head section
+        writer.write_event(Event::Start(BytesStart::new("head")))?;
+        writer.write_event(Event::Start(BytesStart::new("title")))?;
+        writer.write_event(Event::Text(BytesText::new("Reedy RSS Feeds")))?;
+        writer.write_event(Event::End(BytesEnd::new("title")))?;
+        writer.write_event(Event::End(BytesEnd::new("head")))?;
+
+        // Body section
+        writer.write_event(Event::Start(BytesStart::new("body")))?;
+
+        // Uncategorized feeds at the top level, then the category tree as nested outlines
+        self.write_opml_outlines(&mut writer, None, only)?;
+
+        writer.write_event(Event::End(BytesEnd::new("body")))?;
+        writer.write_event(Event::End(BytesEnd::new("opml")))?;
 
-        let mut grouped: BTreeMap<Option<String>, Vec<&FeedInfo>> = BTreeMap::new();
+        let result = writer.into_inner().into_inner();
+        Ok(String::from_utf8(result)?)
+    }
 
-        for feed in &self.rss_feeds {
-            grouped.entry(feed.category.clone()).or_default().push(feed);
+    /// Writes the feeds directly in `parent` (None for the top level), then one nested
+    /// outline per sub-category. Feed tags go in the standard `category` attribute.
+    /// With `only`, other feeds and the categories left empty are skipped.
+    fn write_opml_outlines(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        parent: Option<&str>,
+        only: Option<&HashSet<String>>,
+    ) -> AppResult<()> {
+        let included = |f: &FeedInfo| only.is_none_or(|urls| urls.contains(&f.url));
+        let mut feeds = self.sorted_feeds(|f| f.category.as_deref() == parent && included(f));
+        // Pinned feeds lead their category; OPML has no way to mark them otherwise
+        feeds.sort_by_key(|&index| !self.rss_feeds[index].pinned);
+        for feed in feeds.into_iter().map(|index| &self.rss_feeds[index]) {
+            let mut outline = BytesStart::new("outline");
+            outline.push_attribute(("type", "rss"));
+            outline.push_attribute(("text", feed.title.as_str()));
+            outline.push_attribute(("title", feed.title.as_str()));
+            outline.push_attribute(("xmlUrl", feed.url.as_str()));
+            if !feed.tags.is_empty() {
+                outline.push_attribute(("category", feed.tags.join(",").as_str()));
+            }
+            writer.write_event(Event::Empty(outline))?;
         }
 
-        // Convert to Vec and sort: None (uncategorized) first, then alphabetically by category
-        let mut result: Vec<(Option<String>, Vec<&FeedInfo>)> = grouped.into_iter().collect();
-        result.sort_by(|a, b| match (&a.0, &b.0) {
-            (None, None) => std::cmp::Ordering::Equal,
-            (None, Some(_)) => std::cmp::Ordering::Less,
-            (Some(_), None) => std::cmp::Ordering::Greater,
-            (Some(a), Some(b)) => a.cmp(b),
-        });
-        result
+        for path in self.child_categories(parent) {
+            let has_feeds = self.rss_feeds.iter().any(|f| {
+                included(f)
+                    && f.category
+                        .as_deref()
+                        .is_some_and(|c| category_in_path(c, &path))
+            });
+            if only.is_some() && !has_feeds {
+                continue;
+            }
+            let name = path.rsplit('/').next().unwrap_or(&path);
+            let mut cat_outline = BytesStart::new("outline");
+            cat_outline.push_attribute(("text", name));
+            cat_outline.push_attribute(("title", name));
+            writer.write_event(Event::Start(cat_outline))?;
+            self.write_opml_outlines(writer, Some(&path), only)?;
+            writer.write_event(Event::End(BytesEnd::new("outline")))?;
+        }
+        Ok(())
     }
 
-    /// Imports feeds from the input buffer (one URL per line)
-    pub async fn import_feeds(&mut self) -> AppResult<()> {
-        let urls: Vec<String> = self
-            .input_buffer
-            .lines()
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
+    /// Returns the articles from `items` that belong in the given published feed,
+    /// newest first and capped at the feed's `max_items`.
+    pub fn items_for_published_feed<'a>(
+        &self,
+        feed: &PublishedFeed,
+        items: &'a [FeedItem],
+    ) -> Vec<&'a FeedItem> {
+        // Parse a search source once; an invalid query publishes nothing
+        let saved = match &feed.source {
+            PublishSource::SavedSearch(name) => {
+                self.config.saved_searches.iter().find(|s| &s.name == name)
+            }
+            _ => None,
+        };
+        let search = match (&feed.source, saved) {
+            (PublishSource::Search(query), _) | (_, Some(SavedSearch { query, .. })) => {
+                Query::parse(query)
+                    .map_err(|e| warn!("Invalid search for published feed '{}': {}", feed.title, e))
+                    .ok()
+            }
+            _ => None,
+        };
+        let mut selected: Vec<&FeedItem> = items
+            .iter()
+            .filter(|item| match &feed.source {
+                PublishSource::Favorites => self.favorites.contains(&item.id),
+                PublishSource::Category(category) => {
+                    self.item_in_scope(item, &CategoryScope::Category(category.clone()))
+                }
+                PublishSource::Search(_) => search
+                    .as_ref()
+                    .is_some_and(|q| q.as_ref().is_none_or(|q| q.matches(item, self))),
+                PublishSource::Tag(tag) => {
+                    let tag = normalize_tag(tag);
+                    self.item_tags(&item.id).any(|t| t == tag)
+                }
+                PublishSource::SavedSearch(_) => {
+                    saved.is_some_and(|s| !s.unread_only || !self.is_item_read(item))
+                        && search
+                            .as_ref()
+                            .is_some_and(|q| q.as_ref().is_none_or(|q| q.matches(item, self)))
+                }
+            })
             .collect();
+        selected.sort_by_key(|item| std::cmp::Reverse(item.published));
+        selected.truncate(feed.max_items);
+        selected
+    }
 
-        if urls.is_empty() {
-            self.error_message = Some("No URLs to import".to_string());
-            return Ok(());
+    /// Generates Atom XML for a published feed from the given articles
+    pub fn generate_atom_feed(
+        &self,
+        feed: &PublishedFeed,
+        items: &[FeedItem],
+    ) -> AppResult<String> {
+        let mut writer = Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2);
+        let now = chrono::Utc::now().to_rfc3339();
+
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+        let mut root = BytesStart::new("feed");
+        root.push_attribute(("xmlns", "http://www.w3.org/2005/Atom"));
+        writer.write_event(Event::Start(root))?;
+
+        let feed_slug = feed
+            .title
+            .to_lowercase()
+            .replace(|c: char| !c.is_alphanumeric(), "-");
+        write_text_element(&mut writer, "title", &feed.title)?;
+        write_text_element(&mut writer, "id", &format!("urn:reedy:{}", feed_slug))?;
+        write_text_element(&mut writer, "updated", &now)?;
+        write_text_element(&mut writer, "generator", "Reedy")?;
+
+        for item in self.items_for_published_feed(feed, items) {
+            // Item titles are stored as "Article | Feed"; split them back apart
+            let (article_title, title_suffix) = match item.title.rfind(" | ") {
+                Some(pos) => (&item.title[..pos], &item.title[pos + 3..]),
+                None => (item.title.as_str(), ""),
+            };
+            let source_title = self
+                .rss_feeds
+                .iter()
+                .find(|f| f.url == item.feed_url)
+                .map(|f| f.title.as_str())
+                .unwrap_or(title_suffix);
+            let updated = item
+                .published
+                .map(|p| chrono::DateTime::<chrono::Utc>::from(p).to_rfc3339())
+                .unwrap_or_else(|| now.clone());
+            let entry_id = if item.link.is_empty() {
+                format!("urn:reedy:item:{}", item.id)
+            } else {
+                item.link.clone()
+            };
+
+            writer.write_event(Event::Start(BytesStart::new("entry")))?;
+            write_text_element(&mut writer, "title", article_title)?;
+            if !item.link.is_empty() {
+                let mut link = BytesStart::new("link");
+                link.push_attribute(("href", item.link.as_str()));
+                writer.write_event(Event::Empty(link))?;
+            }
+            write_text_element(&mut writer, "id", &entry_id)?;
+            write_text_element(&mut writer, "updated", &updated)?;
+            if !source_title.is_empty() {
+                writer.write_event(Event::Start(BytesStart::new("author")))?;
+                write_text_element(&mut writer, "name", source_title)?;
+                writer.write_event(Event::End(BytesEnd::new("author")))?;
+            }
+            write_text_element(&mut writer, "summary", item.description.trim())?;
+            writer.write_event(Event::End(BytesEnd::new("entry")))?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("feed")))?;
+
+        let result = writer.into_inner().into_inner();
+        Ok(String::from_utf8(result)?)
+    }
+
+    /// Writes every configured published feed to disk using the given articles.
+    /// Failures are logged and reported without interrupting the refresh.
+    pub fn publish_feeds(&mut self, items: &[FeedItem]) {
+        let tagged = self.tagged_snapshots();
+        let favorites = self.favorite_items();
+        for feed in self.config.publish.clone() {
+            let path = expand_home(&feed.path);
+            // Tagged articles are published from their saved copies, and favorites the same
+            // way as the Favorites page lists them
+            let source_items = match feed.source {
+                PublishSource::Tag(_) => &tagged,
+                PublishSource::Favorites => &favorites,
+                _ => items,
+            };
+            let result = self
+                .generate_atom_feed(&feed, source_items)
+                .and_then(|xml| {
+                    if let Some(parent) = path.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::write(&path, xml)?;
+                    Ok(())
+                });
+            match result {
+                Ok(()) => info!("Published '{}' to {}", feed.title, path.display()),
+                Err(e) => {
+                    error!(
+                        "Failed to publish '{}' to {}: {}",
+                        feed.title,
+                        path.display(),
+                        e
+                    );
+                    self.error_message = Some(format!("Failed to publish '{}': {}", feed.title, e));
+                }
+            }
+        }
+    }
+
+    /// Imports feeds from an OPML file
+    pub async fn import_opml(&mut self, path: &PathBuf) -> AppResult<()> {
+        let content = fs::read_to_string(path)?;
+        self.parse_and_import_opml(&content).await
+    }
+
+    /// Imports feeds from OPML content string
+    pub async fn import_opml_content(&mut self, content: &str) -> AppResult<()> {
+        self.parse_and_import_opml(content).await
+    }
+
+    /// Parses OPML content and imports feeds
+    async fn parse_and_import_opml(&mut self, content: &str) -> AppResult<()> {
+        let mut reader = Reader::from_str(content);
+        reader.config_mut().trim_text(true);
+
+        let mut added = 0;
+        let mut skipped_duplicate = 0;
+        // One entry per open outline; feeds and unnamed outlines push None so every end tag
+        // pops its own start tag
+        let mut category_stack: Vec<Option<String>> = Vec::new();
+
+        loop {
+            let (outline, has_children) = match reader.read_event() {
+                Ok(Event::Start(e)) if e.name().as_ref() == b"outline" => (e, true),
+                Ok(Event::Empty(e)) if e.name().as_ref() == b"outline" => (e, false),
+                Ok(Event::End(ref e)) if e.name().as_ref() == b"outline" => {
+                    category_stack.pop();
+                    continue;
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => {
+                    error!("Error parsing OPML: {}", e);
+                    return Err(format!("Error parsing OPML: {}", e).into());
+                }
+                _ => continue,
+            };
+
+            let mut xml_url: Option<String> = None;
+            let mut title: Option<String> = None;
+            let mut category_attr: Option<String> = None;
+
+            for attr in outline.attributes().flatten() {
+                match attr.key.as_ref() {
+                    b"xmlUrl" | b"xmlurl" => {
+                        xml_url = Some(String::from_utf8_lossy(&attr.value).to_string());
+                    }
+                    b"text" | b"title" if title.is_none() => {
+                        title = Some(String::from_utf8_lossy(&attr.value).to_string());
+                    }
+                    b"category" => {
+                        category_attr = Some(String::from_utf8_lossy(&attr.value).to_string());
+                    }
+                    _ => {}
+                }
+            }
+
+            let Some(url) = xml_url else {
+                // A category outline - its name joins the path of everything nested inside
+                if has_children {
+                    category_stack.push(title.filter(|t| !t.trim().is_empty()));
+                }
+                continue;
+            };
+            if has_children {
+                // A feed with a start tag (unusual but valid)
+                category_stack.push(None);
+            }
+
+            // Skip duplicates
+            if self.rss_feeds.iter().any(|f| f.url == url) {
+                skipped_duplicate += 1;
+                continue;
+            }
+
+            // Use the title from OPML or use the URL as fallback
+            let feed_title = title
+                .filter(|t| !t.is_empty())
+                .unwrap_or_else(|| url.clone());
+            let nested: Vec<&str> = category_stack
+                .iter()
+                .flatten()
+                .map(String::as_str)
+                .collect();
+            let (attr_path, tags) = parse_opml_category_attr(category_attr.as_deref());
+            let category = normalize_category_path(&nested.join("/")).or(attr_path);
+
+            info!("Adding feed from OPML: {} ({})", feed_title, url);
+            self.rss_feeds.push(FeedInfo {
+                url,
+                title: feed_title,
+                category,
+                tags,
+                ..Default::default()
+            });
+            added += 1;
+        }
+
+        // Save if we added any feeds
+        if added > 0 {
+            self.save_feeds()?;
+        }
+
+        // Build result message
+        let mut result_parts = Vec::new();
+        if added > 0 {
+            result_parts.push(format!("{} added", added));
+        }
+        if skipped_duplicate > 0 {
+            result_parts.push(format!("{} duplicate", skipped_duplicate));
+        }
+
+        let result_msg = if result_parts.is_empty() {
+            "OPML Import: No feeds found".to_string()
+        } else {
+            format!("OPML Import: {}", result_parts.join(", "))
+        };
+        self.import_result = Some(result_msg.clone());
+        self.error_message = Some(result_msg);
+
+        Ok(())
+    }
+
+    /// Gets the default OPML file path for import
+    pub fn get_opml_path() -> PathBuf {
+        let mut path = paths::config_dir();
+        path.push("feeds.opml");
+        path
+    }
+
+    /// Starts category setting mode for the selected feed, or renaming mode for the
+    /// selected category
+    pub fn start_setting_category(&mut self) {
+        let marked = self.take_marked_feeds();
+        if !marked.is_empty() {
+            // Pre-fill with the category the marked feeds share, if they share one
+            let first = &self.rss_feeds[marked[0]].category;
+            self.input_buffer = if marked.iter().all(|&i| &self.rss_feeds[i].category == first) {
+                first.clone().unwrap_or_default()
+            } else {
+                String::new()
+            };
+            self.input_mode = InputMode::SettingCategory;
+            return;
+        }
+        let Some(row) = self.selected_index else {
+            return;
+        };
+        // Pre-fill with the current category path if it exists
+        if let Some(index) = self.feed_index_at(row) {
+            self.input_buffer = self.rss_feeds[index].category.clone().unwrap_or_default();
+        } else if let Some(path) = self.category_at(row) {
+            self.input_buffer = path;
+        } else {
+            return;
+        }
+        self.input_mode = InputMode::SettingCategory;
+    }
+
+    /// Cancels category setting mode
+    pub fn cancel_setting_category(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+        self.clear_error();
+    }
+
+    /// Sets the category path for the selected feed, or moves the selected category
+    /// (with everything nested below it) to the entered path
+    pub fn set_category(&mut self) {
+        let category = normalize_category_path(&self.input_buffer);
+        let row = self.selected_index.unwrap_or(usize::MAX);
+        let marked = self.take_marked_feeds();
+        let mut changed = false;
+        if !marked.is_empty() {
+            for &i in &marked {
+                self.rss_feeds[i].category = category.clone();
+            }
+            info!("Set category {:?} for {} feeds", category, marked.len());
+            self.status_message = Some(format!(
+                "Moved {} feeds to {}",
+                marked.len(),
+                category.as_deref().unwrap_or("no category")
+            ));
+            changed = true;
+        } else if let Some(index) = self.feed_index_at(row) {
+            let feed = &mut self.rss_feeds[index];
+            match &category {
+                None => info!("Cleared category for feed: {}", feed.title),
+                Some(category) => info!("Set category '{}' for feed: {}", category, feed.title),
+            }
+            feed.category = category;
+            changed = true;
+        } else if let Some(old_path) = self.category_at(row) {
+            for feed in &mut self.rss_feeds {
+                let Some(current) = feed.category.as_deref() else {
+                    continue;
+                };
+                if category_in_path(current, &old_path) {
+                    let rest = &current[old_path.len()..];
+                    feed.category = match &category {
+                        Some(new_path) => Some(format!("{}{}", new_path, rest)),
+                        None => rest.strip_prefix('/').map(str::to_string),
+                    };
+                    changed = true;
+                }
+            }
+            // A renamed category keeps its place
+            if let Some(new_path) = &category {
+                for ordered in &mut self.category_order {
+                    if category_in_path(ordered, &old_path) {
+                        *ordered = format!("{}{}", new_path, &ordered[old_path.len()..]);
+                    }
+                }
+            }
+            info!("Moved category '{}' to {:?}", old_path, category);
+        }
+        if changed {
+            if let Err(e) = self.save_feeds() {
+                error!("Failed to save feeds after setting category: {}", e);
+                self.error_message = Some("Failed to save category".to_string());
+            }
+        }
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+    }
+
+    /// Starts editing the tags of the selected feed
+    pub fn start_setting_feed_tags(&mut self) {
+        if let Some(index) = self.selected_index.and_then(|row| self.feed_index_at(row)) {
+            self.input_buffer = self.rss_feeds[index].tags.join(", ");
+            self.input_mode = InputMode::SettingFeedTags;
+        }
+    }
+
+    /// Replaces the selected feed's tags with the comma or space separated input
+    pub fn set_feed_tags(&mut self) {
+        if let Some(index) = self.selected_index.and_then(|row| self.feed_index_at(row)) {
+            let mut tags: Vec<String> = Vec::new();
+            for tag in self
+                .input_buffer
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .map(normalize_tag)
+                .filter(|tag| !tag.is_empty())
+            {
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+            let feed = &mut self.rss_feeds[index];
+            info!("Set tags {:?} for feed: {}", tags, feed.title);
+            feed.tags = tags;
+            if let Err(e) = self.save_feeds() {
+                error!("Failed to save feeds after setting tags: {}", e);
+                self.error_message = Some("Failed to save tags".to_string());
+            }
+        }
+        self.cancel_setting_category();
+    }
+
+    /// Starts renaming the selected feed
+    pub fn start_renaming_feed(&mut self) {
+        if let Some(index) = self.selected_index.and_then(|row| self.feed_index_at(row)) {
+            self.input_buffer = self.rss_feeds[index].title.clone();
+            self.input_mode = InputMode::RenamingFeed;
+        }
+    }
+
+    /// Renames the selected feed to the input
+    pub fn rename_selected_feed(&mut self) {
+        let title = self.input_buffer.trim().to_string();
+        if title.is_empty() {
+            self.error_message = Some("Feed title cannot be empty".to_string());
+            return;
+        }
+        self.cancel_setting_category();
+        if let Some(index) = self.selected_index.and_then(|row| self.feed_index_at(row)) {
+            self.set_feed_title(index, &title);
+        }
+    }
+
+    /// Starts editing the URL of the selected feed
+    pub fn start_editing_feed_url(&mut self) {
+        if let Some(index) = self.selected_index.and_then(|row| self.feed_index_at(row)) {
+            self.input_buffer = self.rss_feeds[index].url.clone();
+            self.input_mode = InputMode::EditingFeedUrl;
+        }
+    }
+
+    /// Moves the selected feed to the URL in the input; an invalid URL keeps the input open
+    pub fn set_selected_feed_url(&mut self) {
+        let url = self.input_buffer.trim().to_string();
+        let Some(index) = self.selected_index.and_then(|row| self.feed_index_at(row)) else {
+            self.cancel_setting_category();
+            return;
+        };
+        if self.set_feed_url(index, &url) {
+            self.input_mode = InputMode::Normal;
+            self.input_buffer.clear();
+        }
+    }
+
+    /// Renames a feed, updating the feed title shown with its loaded and cached articles
+    pub fn set_feed_title(&mut self, index: usize, title: &str) -> bool {
+        let old_title = std::mem::replace(&mut self.rss_feeds[index].title, title.to_string());
+        let url = self.rss_feeds[index].url.clone();
+        info!("Renamed feed {} from '{}' to '{}'", url, old_title, title);
+
+        // Articles carry their feed's title after " | "
+        let old_suffix = format!(" | {}", old_title);
+        let retitle = |item: &mut FeedItem| {
+            if item.feed_url == url {
+                if let Some(article) = item.title.strip_suffix(&old_suffix) {
+                    item.title = format!("{} | {}", article, title);
+                }
+            }
+        };
+        self.current_feed_content.iter_mut().for_each(retitle);
+        if let Err(e) = self.rewrite_feed_cache(&url, &url, retitle) {
+            warn!("Failed to update cached articles of {}: {}", url, e);
+        }
+        if let Some(items) = self.cached_items.get_mut(&url) {
+            items.iter_mut().for_each(retitle);
+        }
+        self.tagged_items
+            .values_mut()
+            .for_each(|tagged| retitle(&mut tagged.item));
+        self.search_index.retitle_feed(&url, title, retitle);
+        self.save_search_index();
+
+        if let Err(e) = self.save_feeds() {
+            error!("Failed to save feeds after renaming: {}", e);
+            self.error_message = Some("Failed to save feeds".to_string());
+            return false;
+        }
+        self.status_message = Some(format!("Renamed to {}", title));
+        true
+    }
+
+    /// Moves a feed to a new URL, e.g. after its site moved. The read, favorite and tag
+    /// state of its articles, its cache and its health are carried over.
+    pub fn set_feed_url(&mut self, index: usize, url: &str) -> bool {
+        let valid = reqwest::Url::parse(url)
+            .is_ok_and(|parsed| parsed.scheme() == "http" || parsed.scheme() == "https");
+        if !valid {
+            self.error_message = Some(format!("Invalid feed URL: {}", url));
+            return false;
+        }
+        if self.rss_feeds[index].url == url {
+            return true;
+        }
+        if self.rss_feeds.iter().any(|feed| feed.url == url) {
+            self.error_message = Some(format!("Already subscribed to {}", url));
+            return false;
+        }
+        let old_url = std::mem::replace(&mut self.rss_feeds[index].url, url.to_string());
+        info!(
+            "Moved feed '{}' from {} to {}",
+            self.rss_feeds[index].title, old_url, url
+        );
+
+        // Article IDs include a hash of the feed URL
+        let new_id = |id: &str| moved_item_id(id, &old_url, url);
+        let move_item = |item: &mut FeedItem| {
+            if item.feed_url == old_url {
+                item.id = new_id(&item.id);
+                item.feed_url = url.to_string();
+            }
+        };
+        self.read_items = self.read_items.iter().map(|id| new_id(id)).collect();
+        self.favorites = self.favorites.iter().map(|id| new_id(id)).collect();
+        self.seen_items = self.seen_items.iter().map(|id| new_id(id)).collect();
+        self.tagged_items = std::mem::take(&mut self.tagged_items)
+            .into_iter()
+            .map(|(id, mut tagged)| {
+                move_item(&mut tagged.item);
+                (new_id(&id), tagged)
+            })
+            .collect();
+        self.current_feed_content.iter_mut().for_each(move_item);
+        if let Err(e) = self.rewrite_feed_cache(&old_url, url, move_item) {
+            warn!("Failed to move cached articles of {}: {}", old_url, e);
+        }
+        if let Some(mut items) = self.cached_items.remove(&old_url) {
+            items.iter_mut().for_each(move_item);
+            self.cached_items.insert(url.to_string(), items);
+        }
+        self.update_feed_stats();
+        self.search_index.move_feed(&old_url, url, new_id);
+        self.save_search_index();
+
+        if let Some(health) = self.feed_health.remove(&old_url) {
+            self.feed_health.insert(url.to_string(), health);
+        }
+        if self.loaded_feed.as_deref() == Some(old_url.as_str()) {
+            self.loaded_feed = Some(url.to_string());
+        }
+        if let Some(order) = self.view_orders.remove(&format!("feed:{}", old_url)) {
+            self.view_orders.insert(format!("feed:{}", url), order);
+        }
+
+        if let Err(e) = self.save_feeds() {
+            error!("Failed to save feeds after changing URL: {}", e);
+            self.error_message = Some("Failed to save feeds".to_string());
+            return false;
+        }
+        self.status_message = Some(format!("Feed moved to {}", url));
+        true
+    }
+
+    /// Rewrites the cached articles of a feed, storing them under `new_url`. The cache
+    /// keeps its age, so a moved feed is not fetched any sooner than before.
+    fn rewrite_feed_cache(
+        &self,
+        old_url: &str,
+        new_url: &str,
+        update: impl FnMut(&mut FeedItem),
+    ) -> AppResult<()> {
+        let old_path = Self::get_cache_path(old_url);
+        let Ok(content) = fs::read_to_string(&old_path) else {
+            return Ok(());
+        };
+        let mut cache: CachedFeed = serde_json::from_str(&content)?;
+        cache.url = new_url.to_string();
+        cache.content.iter_mut().for_each(update);
+        let new_path = Self::get_cache_path(new_url);
+        fs::write(&new_path, serde_json::to_string_pretty(&cache)?)?;
+        if new_path != old_path {
+            fs::remove_file(old_path)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a sorted list of unique categories used by feeds
+    pub fn get_categories(&self) -> Vec<String> {
+        let mut categories: Vec<String> = self
+            .rss_feeds
+            .iter()
+            .filter_map(|f| f.category.clone())
+            .collect::<std::collections::HashSet<_>>()
+            .into_iter()
+            .collect();
+        categories.sort();
+        categories
+    }
+
+    /// Returns feeds grouped by category. Uncategorized feeds are grouped under None.
+    /// The groups follow the Feed Manager: uncategorized feeds first, then the category tree
+    /// in its chosen order, with each group's feeds sorted the same way (pinned ones first).
+    pub fn get_feeds_by_category(&self) -> Vec<(Option<String>, Vec<&FeedInfo>)> {
+        let mut categories = vec![None];
+        let mut paths = Vec::new();
+        self.ordered_category_paths(None, &mut paths);
+        categories.extend(paths.into_iter().map(Some));
+
+        categories
+            .into_iter()
+            .filter_map(|category| {
+                let mut feeds = self.sorted_feeds(|f| f.category == category);
+                feeds.sort_by_key(|&index| !self.rss_feeds[index].pinned);
+                let feeds: Vec<&FeedInfo> = feeds
+                    .into_iter()
+                    .map(|index| &self.rss_feeds[index])
+                    .collect();
+                (!feeds.is_empty()).then_some((category, feeds))
+            })
+            .collect()
+    }
+
+    /// Imports feeds from the input buffer (one URL per line)
+    pub async fn import_feeds(&mut self) -> AppResult<()> {
+        let urls: Vec<String> = self
+            .input_buffer
+            .lines()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        if urls.is_empty() {
+            self.error_message = Some("No URLs to import".to_string());
+            return Ok(());
+        }
+
+        let mut added = 0;
+        let mut skipped_duplicate = 0;
+        let mut skipped_invalid = 0;
+
+        for url in urls {
+            // Skip duplicates
+            if self.rss_feeds.iter().any(|f| f.url == url) {
+                skipped_duplicate += 1;
+                continue;
+            }
+
+            // Validate the URL and get title
+            match Self::validate_and_get_feed_title(&url, self.config.http_timeout_secs).await {
+                Ok(Some(title)) => {
+                    info!("Successfully validated and added feed: {} ({})", title, url);
+                    self.rss_feeds.push(FeedInfo {
+                        url,
+                        title,
+                        ..Default::default()
+                    });
+                    added += 1;
+                }
+                Ok(None) => {
+                    debug!("Invalid RSS feed URL during import: {}", url);
+                    skipped_invalid += 1;
+                }
+                Err(e) => {
+                    debug!("Error validating feed during import: {} - {}", url, e);
+                    skipped_invalid += 1;
+                }
+            }
+        }
+
+        // Save if we added any feeds
+        if added > 0 {
+            self.save_feeds()?;
+        }
+
+        // Build result message
+        let mut result_parts = Vec::new();
+        if added > 0 {
+            result_parts.push(format!("{} added", added));
+        }
+        if skipped_duplicate > 0 {
+            result_parts.push(format!("{} duplicate", skipped_duplicate));
+        }
+        if skipped_invalid > 0 {
+            result_parts.push(format!("{} invalid", skipped_invalid));
+        }
+
+        let result_msg = format!("Import: {}", result_parts.join(", "));
+        self.import_result = Some(result_msg.clone());
+        self.error_message = Some(result_msg);
+
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+
+        Ok(())
+    }
+
+    /// Validates a URL as a valid RSS/Atom feed and returns its title if valid.
+    /// Returns Ok(Some(title)) if valid, Ok(None) if invalid, or Err on failure.
+    pub async fn validate_and_get_feed_title(
+        url: &str,
+        timeout_secs: u64,
+    ) -> AppResult<Option<String>> {
+        // First validate URL format
+        let url = reqwest::Url::parse(url)?;
+        if url.scheme() != "http" && url.scheme() != "https" {
+            return Ok(None);
+        }
+
+        // Try to fetch and parse the feed
+        let client = create_http_client(timeout_secs);
+        match client.get(url.as_str()).send().await {
+            Ok(response) => {
+                // Check for HTTP errors before trying to parse
+                if !response.status().is_success() {
+                    error!(
+                        "HTTP error {} when fetching feed: {}",
+                        response.status(),
+                        url
+                    );
+                    return Ok(None);
+                }
+
+                let bytes = response.bytes().await?;
+                // Try RSS first
+                if let Ok(channel) = Channel::read_from(&bytes[..]) {
+                    let title = channel.title().to_string();
+                    return Ok(Some(if title.is_empty() {
+                        url.to_string()
+                    } else {
+                        title
+                    }));
+                }
+                // Try Atom if RSS fails
+                if let Ok(feed) = AtomFeed::read_from(&bytes[..]) {
+                    let title = feed.title().value.clone();
+                    return Ok(Some(if title.is_empty() {
+                        url.to_string()
+                    } else {
+                        title
+                    }));
+                }
+                Ok(None)
+            }
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Validates a feed URL and subscribes to it, returning the feed's title.
+    /// Fails if the URL is already subscribed or is not a valid RSS/Atom feed.
+    pub async fn subscribe(&mut self, url: &str, category: Option<String>) -> AppResult<String> {
+        let url = url.trim();
+        if self.rss_feeds.iter().any(|f| f.url == url) {
+            return Err(format!("Already subscribed to {}", url).into());
+        }
+        match Self::validate_and_get_feed_title(url, self.config.http_timeout_secs).await? {
+            Some(title) => {
+                self.add_subscription(url, &title, category)?;
+                Ok(title)
+            }
+            None => Err("Invalid RSS feed URL".into()),
+        }
+    }
+
+    /// Subscribes to a feed whose title was already fetched
+    pub fn add_subscription(
+        &mut self,
+        url: &str,
+        title: &str,
+        category: Option<String>,
+    ) -> AppResult<()> {
+        if self.rss_feeds.iter().any(|f| f.url == url) {
+            return Err(format!("Already subscribed to {}", url).into());
+        }
+        info!("Subscribed to feed: {} ({})", title, url);
+        self.rss_feeds.push(FeedInfo {
+            url: url.to_string(),
+            title: title.to_string(),
+            category,
+            ..Default::default()
+        });
+        self.save_feeds()?;
+        Ok(())
+    }
+
+    pub async fn add_feed(&mut self) -> AppResult<()> {
+        debug!("Attempting to add feed: {}", self.input_buffer);
+        match Self::validate_and_get_feed_title(&self.input_buffer, self.config.http_timeout_secs)
+            .await
+        {
+            Ok(Some(title)) => {
+                info!(
+                    "Successfully validated feed: {} ({})",
+                    title, self.input_buffer
+                );
+                self.rss_feeds.push(FeedInfo {
+                    url: self.input_buffer.clone(),
+                    title,
+                    ..Default::default()
+                });
+                self.save_feeds()?;
+                self.input_buffer.clear();
+                self.input_mode = InputMode::Normal;
+                Ok(())
+            }
+            Ok(None) => {
+                error!("Invalid RSS feed URL: {}", self.input_buffer);
+                self.error_message = Some("Invalid RSS feed URL".to_string());
+                Ok(())
+            }
+            Err(e) => {
+                error!("Error validating feed: {}", e);
+                self.error_message = Some(format!("Error: {}", e));
+                Ok(())
+            }
+        }
+    }
+
+    /// Opens the feed or saved search at a Feed Manager row
+    pub async fn select_feed(&mut self, row: usize) -> AppResult<()> {
+        if let Some(index) = self.feed_index_at(row) {
+            debug!("Loading feed content from index {}", index);
+            if self.active_saved_search.is_some()
+                || self.active_tag.is_some()
+                || self.search_everything
+            {
+                self.clear_search();
+            }
+            // A single feed is shown regardless of the category scope
+            self.category_scope = CategoryScope::All;
+            self.loaded_feed = Some(self.rss_feeds[index].url.clone());
+            self.selected_index = Some(index);
+            self.load_feed_content().await?;
+        } else if let Some(FeedManagerRow::SavedSearch(i)) = self.feed_manager_rows().get(row) {
+            self.open_saved_search(*i);
+        }
+        Ok(())
+    }
+
+    pub async fn load_feed_content(&mut self) -> AppResult<()> {
+        if let Some(index) = self.selected_index {
+            if let Some(feed_info) = self.rss_feeds.get(index).cloned() {
+                let url = &feed_info.url;
+                let feed_title = &feed_info.title;
+                debug!("Checking cache for URL: {}", url);
+
+                // Try to load from cache first
+                if let Some(cached_content) = self.load_feed_cache(url) {
+                    debug!("Using cached content for {}", url);
+                    self.current_feed_content = cached_content;
+                    self.refresh_filters();
+                    return Ok(());
+                }
+
+                debug!("Fetching feed content from URL: {}", url);
+                let client = create_http_client(self.config.http_timeout_secs);
+                let response = client.get(url.as_str()).send().await?;
+
+                // Check for HTTP errors
+                if !response.status().is_success() {
+                    self.error_message = Some(format!(
+                        "HTTP error {}: {}",
+                        response.status(),
+                        response
+                            .status()
+                            .canonical_reason()
+                            .unwrap_or("Unknown error")
+                    ));
+                    return Ok(());
+                }
+
+                let content = response.bytes().await?;
+
+                let feed_title_clone = feed_title.clone();
+                let mut feed_items: Vec<FeedItem> = match Channel::read_from(&content[..]) {
+                    Ok(channel) => {
+                        // Handle RSS feed
+                        channel
+                            .items()
+                            .iter()
+                            .map(|item| {
+                                let description = item
+                                    .description()
+                                    .unwrap_or("No description")
+                                    .replace(|c| ['\n', '\r'].contains(&c), " ");
+                                let clean_description =
+                                    html2text::from_read(description.as_bytes(), 80);
+
+                                let published = item.pub_date().and_then(|date| {
+                                    DateTime::parse_from_rfc2822(date).ok().map(|dt| dt.into())
+                                });
+
+                                FeedItem {
+                                    title: format!(
+                                        "{} | {}",
+                                        item.title().unwrap_or("No title"),
+                                        feed_title_clone
+                                    ),
+                                    description: clean_description,
+                                    link: item.link().unwrap_or("").to_string(),
+                                    published,
+                                    id: Self::create_item_id(
+                                        item.title().unwrap_or("No title"),
+                                        published,
+                                        url,
+                                    ),
+                                    feed_url: url.clone(),
+                                    enclosure: item.enclosure().map(|e| e.url().to_string()),
+                                }
+                            })
+                            .collect()
+                    }
+                    Err(_) => {
+                        // Try parsing as Atom feed
+                        match AtomFeed::read_from(&content[..]) {
+                            Ok(feed) => feed
+                                .entries()
+                                .iter()
+                                .map(|entry| {
+                                    let description = entry
+                                        .content()
+                                        .and_then(|c| c.value.clone())
+                                        .or_else(|| entry.summary().map(|s| s.value.clone()))
+                                        .unwrap_or_else(|| "No description".to_string());
+                                    let clean_description =
+                                        html2text::from_read(description.as_bytes(), 80);
+
+                                    let published = entry
+                                        .published()
+                                        .or_else(|| Some(entry.updated()))
+                                        .map(|date| date.to_owned().into());
+
+                                    FeedItem {
+                                        title: format!(
+                                            "{} | {}",
+                                            entry.title().value,
+                                            feed_title_clone
+                                        ),
+                                        description: clean_description,
+                                        link: entry
+                                            .links()
+                                            .first()
+                                            .map(|l| l.href().to_string())
+                                            .unwrap_or_default(),
+                                        published,
+                                        id: Self::create_item_id(
+                                            &entry.title().value,
+                                            published,
+                                            url,
+                                        ),
+                                        feed_url: url.clone(),
+                                        enclosure: atom_enclosure(entry),
+                                    }
+                                })
+                                .collect(),
+                            Err(e) => {
+                                error!("Failed to parse feed as either RSS or Atom: {}", e);
+                                return Err(Box::new(e));
+                            }
+                        }
+                    }
+                };
+
+                // Sort by date, newest first
+                feed_items.sort_by_key(|item| std::cmp::Reverse(item.published));
+
+                // Save to cache
+                if let Err(e) = self.save_feed_cache(url, &feed_items) {
+                    error!("Failed to cache feed content: {}", e);
+                }
+                self.update_saved_search_counts();
+                self.index_items(&feed_items);
+                self.save_search_index();
+
+                self.current_feed_content = feed_items;
+                self.refresh_filters();
+                Ok(())
+            } else {
+                debug!("No feed URL found at index {}", index);
+                Ok(())
+            }
+        } else {
+            debug!("No feed selected");
+            Ok(())
+        }
+    }
+
+    fn save_feeds(&self) -> AppResult<()> {
+        let saved = SavedState {
+            feeds: self.rss_feeds.clone(),
+            read_items: self.read_items.clone(),
+            favorites: self.favorites.clone(),
+            tagged_items: self.tagged_items.clone(),
+            view_orders: self.view_orders.clone(),
+            command_history: self.command_history.clone(),
+            category_order: self.category_order.clone(),
+            feed_sort: self.feed_sort,
+        };
+        let content = serde_json::to_string_pretty(&saved)?;
+        fs::write(&self.save_path, content)?;
+        debug!(
+            "Saved {} feeds, {} read items, and {} favorites to {}",
+            self.rss_feeds.len(),
+            self.read_items.len(),
+            self.favorites.len(),
+            self.save_path.display()
+        );
+        Ok(())
+    }
+
+    pub fn open_selected_feed(&mut self) {
+        let Some(item) = self.get_preview_item().cloned() else {
+            return;
+        };
+        self.open_item(&item, true);
+    }
+
+    /// Opens an article with its opener or the system browser. Returns false if it needs a
+    /// foreground opener and `foreground` is false, as several of them cannot share the terminal.
+    fn open_item(&mut self, item: &FeedItem, foreground: bool) -> bool {
+        if item.link.is_empty() && item.enclosure.is_none() {
+            return true;
+        }
+
+        match self.resolve_opener(item) {
+            Some((_, true)) if !foreground => return false,
+            Some((command, true)) => {
+                debug!("Queueing foreground opener: {:?}", command);
+                self.pending_external = Some(command);
+            }
+            Some((command, false)) => {
+                debug!("Spawning opener: {:?}", command);
+                if let Err(e) = std::process::Command::new(&command.program)
+                    .args(&command.args)
+                    .stdin(std::process::Stdio::null())
+                    .stdout(std::process::Stdio::null())
+                    .stderr(std::process::Stdio::null())
+                    .spawn()
+                {
+                    error!("Failed to run {}: {}", command.program, e);
+                    self.error_message = Some(format!("Failed to run {}: {}", command.program, e));
+                }
+            }
+            None => {
+                if !item.link.is_empty() {
+                    let _ = open::that(&item.link);
+                }
+            }
+        }
+        true
+    }
+
+    /// Pipes the selected article, formatted as markdown, into `$PAGER` (default: less)
+    pub fn view_in_pager(&mut self) {
+        let Some(item) = self.get_preview_item().cloned() else {
+            self.error_message = Some("No article selected".to_string());
+            return;
+        };
+        match command_from_env(&["PAGER"], "less", &[]) {
+            Some(mut command) => {
+                command.input = Some(self.format_article_markdown(&item));
+                self.pending_external = Some(command);
+            }
+            None => self.error_message = Some("Invalid $PAGER".to_string()),
+        }
+    }
+
+    /// Opens the selected item's link in the configured terminal browser
+    pub fn open_in_text_browser(&mut self) {
+        let link = match self.get_preview_item() {
+            Some(item) if !item.link.is_empty() => item.link.clone(),
+            Some(_) => {
+                self.error_message = Some("Article has no link".to_string());
+                return;
+            }
+            None => {
+                self.error_message = Some("No article selected".to_string());
+                return;
+            }
+        };
+        let opener = Opener::new("", &self.config.text_browser, true);
+        match opener.build_command(&link) {
+            Some(command) => self.pending_external = Some(command),
+            None => {
+                self.error_message = Some(format!(
+                    "Invalid text browser: {}",
+                    self.config.text_browser
+                ))
+            }
+        }
+    }
+
+    /// Opens the config file in `$VISUAL` / `$EDITOR` (default: vi); the config is reloaded afterwards
+    pub fn edit_config(&mut self) {
+        let mut config_path = Self::get_config_path();
+        if !config_path.exists() {
+            // Write out a commented config.toml so there is something to edit
+            config_path.set_file_name("config.toml");
+            if let Err(e) = fs::write(&config_path, default_toml()) {
+                self.error_message = Some(format!("Failed to create config: {}", e));
+                return;
+            }
+        }
+        let path = config_path.to_string_lossy().to_string();
+        match command_from_env(&["VISUAL", "EDITOR"], "vi", &[path]) {
+            Some(mut command) => {
+                command.reload_config = true;
+                self.pending_external = Some(command);
+            }
+            None => self.error_message = Some("Invalid $EDITOR".to_string()),
+        }
+    }
+
+    /// Runs a terminal program to completion. The caller must suspend the TUI first.
+    pub fn run_external_command(&mut self, command: ExternalCommand) {
+        use std::process::{Command, Stdio};
+
+        debug!("Running external command: {:?}", command);
+        let spawned = Command::new(&command.program)
+            .args(&command.args)
+            .stdin(if command.input.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::inherit()
+            })
+            .spawn();
+
+        let status = spawned.and_then(|mut child| {
+            if let (Some(input), Some(mut stdin)) = (&command.input, child.stdin.take()) {
+                // The pager may exit before reading everything; that is not an error
+                let _ = stdin.write_all(input.as_bytes());
+            }
+            child.wait()
+        });
+
+        match status {
+            Ok(status) if !status.success() => {
+                self.error_message = Some(format!("{} exited with {}", command.program, status));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                error!("Failed to run {}: {}", command.program, e);
+                self.error_message = Some(format!("Failed to run {}: {}", command.program, e));
+            }
+        }
+
+        if command.reload_config {
+            self.reload_config();
+        }
+    }
+
+    /// Finds the first configured opener matching the item's link or enclosure URL.
+    /// Returns the expanded command and whether it must run in the foreground.
+    pub fn resolve_opener(&self, item: &FeedItem) -> Option<(ExternalCommand, bool)> {
+        let urls: Vec<&str> = std::iter::once(item.link.as_str())
+            .chain(item.enclosure.as_deref())
+            .filter(|url| !url.is_empty())
+            .collect();
+        self.config.openers.iter().find_map(|opener| {
+            urls.iter()
+                .find(|url| opener.matches(url))
+                .and_then(|url| opener.build_command(url))
+                .map(|command| (command, opener.foreground))
+        })
+    }
+
+    /// Selects the next unread item after the current selection (wrapping around),
+    /// opens it in the browser and marks it as read. Returns the opened item's title.
+    pub fn open_next_unread(&mut self) -> Option<String> {
+        let count = self.visible_item_count();
+        if count == 0 {
+            return None;
+        }
+        let start = self.selected_index.map(|i| i + 1).unwrap_or(0);
+        let next = (0..count)
+            .map(|offset| (start + offset) % count)
+            .find(|&visible_index| {
+                self.get_actual_index(visible_index)
+                    .and_then(|i| self.current_feed_content.get(i))
+                    .is_some_and(|item| !self.is_item_read(item))
+            })?;
+        self.selected_index = Some(next);
+        self.ensure_selection_visible();
+        self.open_selected_feed();
+        self.mark_as_read();
+        self.get_preview_item().map(|item| item.title.clone())
+    }
+
+    /// Copies the selected item's link to the clipboard using OSC 52
+    pub fn copy_selected_link(&mut self) {
+        if let Some(visible_index) = self.selected_index {
+            if let Some(actual_index) = self.get_actual_index(visible_index) {
+                if let Some(item) = self.current_feed_content.get(actual_index) {
+                    if !item.link.is_empty() {
+                        match copy_to_clipboard_osc52(&item.link) {
+                            Ok(()) => {
+                                self.status_message = Some("Link copied!".to_string());
+                                debug!("Copied link to clipboard: {}", item.link);
+                            }
+                            Err(e) => {
+                                error!("Failed to copy to clipboard: {}", e);
+                                self.error_message = Some(format!("Failed to copy: {}", e));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn clear_error(&mut self) {
+        self.error_message = None;
+    }
+
+    /// Clears both status and error messages
+    pub fn clear_messages(&mut self) {
+        self.status_message = None;
+        self.error_message = None;
+    }
+
+    pub fn scroll_up(&mut self) {
+        if self.scroll > 0 {
+            self.scroll = self.scroll.saturating_sub(1);
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        let max_scroll = match self.page_mode {
+            PageMode::FeedList | PageMode::Favorites => {
+                if self.current_feed_content.is_empty() {
+                    0
+                } else {
+                    self.current_feed_content.len().saturating_sub(1)
+                }
+            }
+            PageMode::FeedManager => self.feed_manager_len().saturating_sub(1),
+            PageMode::MuteRules => self.config.mute_rules.len().saturating_sub(1),
+            PageMode::Settings => self.settings().len().saturating_sub(1),
+        };
+
+        if (self.scroll as usize) < max_scroll {
+            self.scroll += 1;
+        }
+    }
+
+    pub fn page_up(&mut self) {
+        let page_size = self.items_per_page() as u16;
+
+        // Scroll up by page size
+        self.scroll = self.scroll.saturating_sub(page_size);
+
+        // Update selection to follow scrolling
+        if let Some(index) = self.selected_index {
+            if (index as u16) >= self.scroll + page_size {
+                self.selected_index = Some(self.scroll as usize);
+            }
+        }
+    }
+
+    pub fn page_down(&mut self) {
+        let page_size = self.items_per_page();
+
+        // Get the appropriate list length based on page mode
+        let list_len = match self.page_mode {
+            PageMode::FeedList | PageMode::Favorites => self.current_feed_content.len(),
+            PageMode::FeedManager => self.feed_manager_len(),
+            PageMode::MuteRules => self.config.mute_rules.len(),
+            PageMode::Settings => self.settings().len(),
+        };
+
+        // Calculate maximum possible scroll value
+        let max_scroll = if list_len == 0 {
+            0
+        } else {
+            list_len
+                .saturating_sub(1)
+                .saturating_sub(page_size.saturating_sub(1))
+        };
+
+        // Calculate new scroll position, capped at maximum scroll
+        let new_scroll = (self.scroll as usize + page_size).min(max_scroll);
+
+        self.scroll = new_scroll as u16;
+
+        // If the selected index is now above the visible area, update it
+        if let Some(index) = self.selected_index {
+            if index < new_scroll {
+                self.selected_index = Some(new_scroll);
+            }
+        }
+    }
+
+    /// Scrolls to the top of the feed and selects the first item
+    pub fn scroll_to_top(&mut self) {
+        self.scroll = 0;
+
+        // Select the first item if there are any items
+        match self.page_mode {
+            PageMode::FeedList | PageMode::Favorites => {
+                if !self.current_feed_content.is_empty() {
+                    self.selected_index = Some(0);
+                }
+            }
+            PageMode::FeedManager => {
+                if self.feed_manager_len() > 0 {
+                    self.selected_index = Some(0);
+                }
+            }
+            PageMode::MuteRules => {
+                if !self.config.mute_rules.is_empty() {
+                    self.selected_index = Some(0);
+                }
+            }
+            PageMode::Settings => self.selected_index = Some(0),
+        }
+    }
+
+    /// Scrolls to the bottom of the feed and selects the last item
+    pub fn scroll_to_bottom(&mut self) {
+        let len = match self.page_mode {
+            PageMode::FeedList | PageMode::Favorites => self.visible_item_count(),
+            PageMode::FeedManager => self.feed_manager_len(),
+            PageMode::MuteRules => self.config.mute_rules.len(),
+            PageMode::Settings => self.settings().len(),
+        };
+
+        if len == 0 {
+            return;
+        }
+
+        // Select the last item
+        self.selected_index = Some(len - 1);
+
+        // Ensure the selection is visible by scrolling to show it
+        self.ensure_selection_visible();
+    }
+
+    /// Opens the article preview pane for the currently selected item
+    pub fn open_preview(&mut self) {
+        if let Some(visible_index) = self.selected_index {
+            if self.get_actual_index(visible_index).is_some() {
+                self.input_mode = InputMode::Preview;
+                self.preview_scroll = 0;
+            }
+        }
+    }
+
+    /// Closes the article preview pane
+    pub fn close_preview(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.preview_scroll = 0;
+    }
+
+    /// Scrolls the preview pane up by one line
+    pub fn preview_scroll_up(&mut self) {
+        self.preview_scroll = self.preview_scroll.saturating_sub(1);
+    }
+
+    /// Scrolls the preview pane down by one line
+    pub fn preview_scroll_down(&mut self) {
+        self.preview_scroll = self.preview_scroll.saturating_add(1);
+    }
+
+    /// Scrolls the preview pane up by a page
+    pub fn preview_page_up(&mut self) {
+        let page_size = self.terminal_height.saturating_sub(10);
+        self.preview_scroll = self.preview_scroll.saturating_sub(page_size);
+    }
+
+    /// Scrolls the preview pane down by a page
+    pub fn preview_page_down(&mut self) {
+        let page_size = self.terminal_height.saturating_sub(10);
+        self.preview_scroll = self.preview_scroll.saturating_add(page_size);
+    }
+
+    /// Gets the currently selected feed item for preview
+    pub fn get_preview_item(&self) -> Option<&FeedItem> {
+        if let Some(visible_index) = self.selected_index {
+            if let Some(actual_index) = self.get_actual_index(visible_index) {
+                return self.current_feed_content.get(actual_index);
+            }
+        }
+        None
+    }
+
+    /// Formats a feed item as markdown for export
+    fn format_article_markdown(&self, item: &FeedItem) -> String {
+        let mut output = String::new();
+
+        // Title
+        output.push_str(&format!("# {}\n\n", item.title));
+
+        // Metadata
+        if let Some(published) = item.published {
+            if let Ok(duration) = published.duration_since(SystemTime::UNIX_EPOCH) {
+                let secs = duration.as_secs() as i64;
+                if let Some(dt) = chrono::DateTime::from_timestamp(secs, 0) {
+                    output.push_str(&format!("**Date:** {}\n\n", dt.format("%Y-%m-%d %H:%M")));
+                }
+            }
+        }
+
+        if !item.link.is_empty() {
+            output.push_str(&format!("**Link:** {}\n\n", item.link));
+        }
+
+        // Status
+        let read_status = if self.read_items.contains(&item.id) {
+            "Read"
+        } else {
+            "Unread"
+        };
+        let fav_status = if self.favorites.contains(&item.id) {
+            "★ Favorited"
+        } else {
+            ""
+        };
+        if !fav_status.is_empty() {
+            output.push_str(&format!("**Status:** {} | {}\n\n", read_status, fav_status));
+        } else {
+            output.push_str(&format!("**Status:** {}\n\n", read_status));
+        }
+
+        output.push_str("---\n\n");
+
+        // Content - convert HTML to plain text
+        let plain_text = html2text::from_read(item.description.as_bytes(), 80);
+        output.push_str(&plain_text);
+
+        output
+    }
+
+    /// Exports the currently selected article to clipboard using OSC 52
+    pub fn export_article_to_clipboard(&mut self) {
+        let item = if let Some(item) = self.get_preview_item() {
+            item.clone()
+        } else {
+            self.error_message = Some("No article selected".to_string());
+            return;
+        };
+
+        let content = self.format_article_markdown(&item);
+
+        match copy_to_clipboard_osc52(&content) {
+            Ok(()) => {
+                info!("Exported article to clipboard: {}", item.title);
+                self.status_message = Some("Article copied!".to_string());
+            }
+            Err(e) => {
+                error!("Failed to copy to clipboard: {}", e);
+                self.error_message = Some(format!("Failed to copy: {}", e));
+            }
+        }
+    }
+
+    /// Exports the currently selected article to a file
+    pub fn export_article_to_file(&mut self) {
+        let item = if let Some(item) = self.get_preview_item() {
+            item.clone()
+        } else {
+            self.error_message = Some("No article selected".to_string());
+            return;
+        };
+
+        let content = self.format_article_markdown(&item);
+
+        // Create a safe filename from the title
+        let safe_title: String = item
+            .title
+            .chars()
+            .take(50)
+            .map(|c| {
+                if c.is_alphanumeric() || c == ' ' || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>()
+            .trim()
+            .replace(' ', "_");
+
+        self.save_export(&safe_title, &content);
+    }
+
+    /// Writes markdown to `<name>_<timestamp>.md` in the exports directory
+    fn save_export(&mut self, name: &str, content: &str) {
+        // Get export directory
+        let mut path = paths::data_dir();
+        path.push("exports");
+        if let Err(e) = fs::create_dir_all(&path) {
+            error!("Failed to create export directory: {}", e);
+            self.error_message = Some(format!("Failed to create export directory: {}", e));
+            return;
+        }
+
+        // Add timestamp to filename to avoid collisions
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        path.push(format!("{}_{}.md", name, timestamp));
+
+        match fs::write(&path, content) {
+            Ok(()) => {
+                info!("Exported article to: {}", path.display());
+                self.error_message = Some(format!("Saved to: {}", path.display()));
+            }
+            Err(e) => {
+                error!("Failed to write file: {}", e);
+                self.error_message = Some(format!("Failed to save article: {}", e));
+            }
+        }
+    }
+
+    /// Enters vi-style command mode (triggered by ':')
+    pub fn start_command_mode(&mut self) {
+        self.input_mode = InputMode::Command;
+        self.command_buffer.clear();
+        self.command_history_pos = None;
+    }
+
+    /// Cancels command mode without executing
+    pub fn cancel_command_mode(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.command_buffer.clear();
+        self.command_history_pos = None;
+    }
+
+    /// Executes the current command buffer and returns to normal mode.
+    /// Returns Ok(true) if the command was executed successfully,
+    /// Ok(false) if the command was not recognized,
+    /// or an error if execution failed.
+    pub fn execute_command(&mut self) -> AppResult<bool> {
+        let raw = self.command_buffer.trim().to_string();
+        let command = raw.to_lowercase();
+        self.input_mode = InputMode::Normal;
+        self.command_buffer.clear();
+
+        // Commands with arguments (arguments keep their case)
+        if let Some((verb, arg)) = raw.split_once(char::is_whitespace) {
+            match verb.to_lowercase().as_str() {
+                "savesearch" | "ss" => return Ok(self.save_current_search(arg)),
+                "mute" => return Ok(self.add_mute_rule(arg)),
+                "tag" => return Ok(self.open_tag_view(arg)),
+                "cat" | "category" => return Ok(self.set_category_scope_by_name(arg)),
+                "sort" => return Ok(self.set_sort_by_name(arg)),
+                "group" => return Ok(self.set_group_by_name(arg)),
+                "find" => return Ok(self.find_everywhere(arg)),
+                "add" => return Ok(self.queue_subscription(arg)),
+                "delete" | "del" => return Ok(self.delete_feed_by_title(arg)),
+                "rename" => return Ok(self.rename_feed(arg)),
+                "filter" => return Ok(self.filter_articles(arg)),
+                "open" => return Ok(self.open_article_number(arg)),
+                "set" => return Ok(self.set_config_from_command(arg)),
+                "export" => return Ok(self.export_from_command(arg)),
+                "profile" => return Ok(self.queue_profile_switch(arg)),
+                _ => {}
+            }
+        }
+
+        match command.as_str() {
+            // Quit commands
+            "q" | "quit" => {
+                self.quit();
+                Ok(true)
+            }
+            // Write/save commands
+            "w" | "write" | "save" => {
+                self.save_state()?;
+                self.error_message = Some("State saved".to_string());
+                Ok(true)
+            }
+            // Write and quit
+            "wq" | "x" => {
+                self.save_state()?;
+                self.quit();
+                Ok(true)
+            }
+            // Force quit (without save - but we always save state anyway)
+            "q!" => {
+                self.quit();
+                Ok(true)
+            }
+            // Refresh feeds
+            "refresh" | "r" => {
+                // Set a flag to indicate refresh is needed (actual refresh is async)
+                self.auto_refresh_pending = true;
+                Ok(true)
+            }
+            // Help
+            "help" | "h" => {
+                self.toggle_help();
+                Ok(true)
+            }
+            // Open feed manager
+            "feeds" | "manage" => {
+                self.toggle_feed_manager();
+                Ok(true)
+            }
+            // Toggle favorites view
+            "favorites" | "fav" => {
+                // Return false to indicate async action needed
+                // The handler will call toggle_favorites_page().await
+                self.error_message = Some("__toggle_favorites__".to_string());
+                Ok(true)
+            }
+            // Mark all as read
+            "read" | "markread" => {
+                self.mark_all_as_read();
+                Ok(true)
+            }
+            // Scroll to top
+            "0" | "top" | "gg" => {
+                self.scroll_to_top();
+                Ok(true)
+            }
+            // Scroll to bottom
+            "$" | "bottom" => {
+                self.scroll_to_bottom();
+                Ok(true)
+            }
+            // Save search without a name - show usage
+            "savesearch" | "ss" => Ok(self.save_current_search("")),
+            // List categories with their unread counts
+            "cat" | "category" | "categories" => {
+                self.show_category_counts();
+                Ok(true)
+            }
+            // List tags, or show usage for a tag view without a name
+            "tags" | "tag" => {
+                self.show_tags();
+                Ok(true)
+            }
+            // Search everything: start typing a query over the whole index
+            "find" => {
+                self.start_search();
+                self.toggle_search_everything();
+                Ok(true)
+            }
+            // Show the current sort and grouping with the available choices
+            "sort" | "group" => {
+                let order = self.view_order();
+                let names = |labels: Vec<&str>| labels.join("|");
+                self.status_message = Some(format!(
+                    "Sort: {} ({}), group: {} ({})",
+                    order.sort.label(),
+                    names(SortMode::ALL.iter().map(SortMode::label).collect()),
+                    order.group.label(),
+                    names(GroupBy::ALL.iter().map(GroupBy::label).collect()),
+                ));
+                Ok(true)
+            }
+            // Delete the selected or open feed
+            "delete" | "del" => Ok(self.delete_feed_by_title("")),
+            "profile" => {
+                self.status_message = Some(format!(
+                    "Profile: {} (available: {})",
+                    paths::profile(),
+                    paths::profiles().join(", ")
+                ));
+                Ok(true)
+            }
+            // Clear the search filter
+            "filter" => {
+                self.clear_search();
+                Ok(true)
+            }
+            // Open the Settings page
+            "set" | "settings" => {
+                self.open_settings();
+                Ok(true)
+            }
+            // Commands that need an argument - show usage
+            "add" | "rename" | "open" | "export" => {
+                self.error_message = Some(format!("Usage: {}", command_usage(&command)));
+                Ok(false)
+            }
+            // Manage mute rules
+            "mute" | "rules" => {
+                self.clear_search();
+                self.open_mute_rules();
+                Ok(true)
+            }
+            // Empty command - just cancel
+            "" => Ok(true),
+            // Unknown command
+            _ => {
+                self.error_message = Some(format!("Unknown command: {}", command));
+                Ok(false)
+            }
+        }
+    }
+
+    /// Remembers an entered command line for Up/Down recall, moving repeats to the end
+    pub fn record_command(&mut self, line: &str) {
+        let line = line.trim();
+        self.command_history_pos = None;
+        if line.is_empty() {
+            return;
+        }
+        self.command_history.retain(|entry| entry != line);
+        self.command_history.push(line.to_string());
+        if self.command_history.len() > COMMAND_HISTORY_LIMIT {
+            let excess = self.command_history.len() - COMMAND_HISTORY_LIMIT;
+            self.command_history.drain(..excess);
+        }
+        // Saved with the next state change or on quit, not on every command
+        self.unsaved_state = true;
+    }
+
+    /// Writes the state file if something changed since it was last saved
+    pub fn save_unsaved_state(&mut self) {
+        if self.unsaved_state {
+            if let Err(e) = self.save_state() {
+                error!("Failed to save state: {}", e);
+            }
+        }
+    }
+
+    /// Replaces the command buffer with the previous (older) history entry
+    pub fn command_history_previous(&mut self) {
+        if self.command_history.is_empty() {
+            return;
+        }
+        let pos = match self.command_history_pos {
+            None => self.command_history.len() - 1,
+            Some(pos) => pos.saturating_sub(1),
+        };
+        self.command_history_pos = Some(pos);
+        self.command_buffer = self.command_history[pos].clone();
+    }
+
+    /// Replaces the command buffer with the next (newer) history entry, or clears it
+    /// after the newest one
+    pub fn command_history_next(&mut self) {
+        match self.command_history_pos {
+            Some(pos) if pos + 1 < self.command_history.len() => {
+                self.command_history_pos = Some(pos + 1);
+                self.command_buffer = self.command_history[pos + 1].clone();
+            }
+            Some(_) => {
+                self.command_history_pos = None;
+                self.command_buffer.clear();
+            }
+            None => {}
         }
+    }
 
-        let mut added = 0;
-        let mut skipped_duplicate = 0;
-        let mut skipped_invalid = 0;
-
-        for url in urls {
-            // Skip duplicates
-            if self.rss_feeds.iter().any(|f| f.url == url) {
-                skipped_duplicate += 1;
-                continue;
+    /// Completions for the word being typed in command mode: command names for the
+    /// first word, then feed titles, categories, tags, sort modes or config keys
+    /// depending on the command
+    pub fn command_suggestions(&self) -> Vec<String> {
+        let Some((verb, arg)) = self.command_buffer.split_once(char::is_whitespace) else {
+            let prefix = self.command_buffer.to_lowercase();
+            return COMMAND_NAMES
+                .iter()
+                .filter(|name| name.starts_with(&prefix))
+                .map(|name| name.to_string())
+                .collect();
+        };
+        let candidates: Vec<String> = match verb.to_lowercase().as_str() {
+            "delete" | "del" => self.rss_feeds.iter().map(|f| f.title.clone()).collect(),
+            "cat" | "category" => self
+                .category_scopes()
+                .iter()
+                .map(|scope| scope.label().to_string())
+                .collect(),
+            "tag" => self.all_tags().into_iter().map(|(tag, _)| tag).collect(),
+            "sort" if self.page_mode == PageMode::FeedManager => FeedSort::ALL
+                .iter()
+                .map(|s| s.label().to_string())
+                .collect(),
+            "sort" => SortMode::ALL
+                .iter()
+                .map(|m| m.label().to_string())
+                .collect(),
+            "group" => GroupBy::ALL.iter().map(|g| g.label().to_string()).collect(),
+            "set" if !arg.contains('=') => self
+                .settings()
+                .into_iter()
+                .filter(|setting| setting.editable)
+                .map(|setting| setting.key)
+                .collect(),
+            "profile" => paths::profiles(),
+            "export" if !arg.contains(char::is_whitespace) => {
+                vec!["json".to_string(), "opml".to_string()]
             }
+            _ => Vec::new(),
+        };
+        let prefix = arg.trim_start().to_lowercase();
+        let mut suggestions: Vec<String> = candidates
+            .into_iter()
+            .filter(|c| c.to_lowercase().starts_with(&prefix))
+            .collect();
+        suggestions.sort_by_key(|c| c.to_lowercase());
+        suggestions.dedup();
+        suggestions
+    }
 
-            // Validate the URL and get title
-            match Self::validate_and_get_feed_title(&url, self.config.http_timeout_secs).await {
-                Ok(Some(title)) => {
-                    info!("Successfully validated and added feed: {} ({})", title, url);
-                    self.rss_feeds.push(FeedInfo {
-                        url,
-                        title,
-                        category: None,
-                    });
-                    added += 1;
+    /// Completes the word being typed in command mode to the longest common prefix of
+    /// the suggestions (the whole suggestion if only one matches)
+    pub fn complete_command(&mut self) {
+        let suggestions = self.command_suggestions();
+        if suggestions.is_empty() {
+            return;
+        }
+        let common = common_prefix(&suggestions);
+        match self.command_buffer.split_once(char::is_whitespace) {
+            None => {
+                if common.len() >= self.command_buffer.len() {
+                    self.command_buffer = common;
                 }
-                Ok(None) => {
-                    debug!("Invalid RSS feed URL during import: {}", url);
-                    skipped_invalid += 1;
+                if suggestions.len() == 1 {
+                    self.command_buffer.push(' ');
                 }
-                Err(e) => {
-                    debug!("Error validating feed during import: {} - {}", url, e);
-                    skipped_invalid += 1;
+            }
+            Some((verb, arg)) => {
+                if common.len() < arg.trim_start().len() {
+                    return;
+                }
+                let verb = verb.to_string();
+                self.command_buffer = format!("{} {}", verb, common);
+                if suggestions.len() == 1 && verb.eq_ignore_ascii_case("set") {
+                    self.command_buffer.push('=');
                 }
             }
         }
+    }
 
-        // Save if we added any feeds
-        if added > 0 {
-            self.save_feeds()?;
+    /// Queues a subscription from `:add <url> [category]`; the event handler performs it
+    fn queue_subscription(&mut self, arg: &str) -> bool {
+        let (url, category) = match arg.trim().split_once(char::is_whitespace) {
+            Some((url, category)) => (url, Some(category.trim().trim_matches('"'))),
+            None => (arg.trim(), None),
+        };
+        if url.is_empty() {
+            self.error_message = Some(format!("Usage: {}", command_usage("add")));
+            return false;
         }
+        let category = category.filter(|c| !c.is_empty()).map(str::to_string);
+        self.pending_subscription = Some((url.to_string(), category));
+        self.status_message = Some(format!("Adding {}...", url));
+        true
+    }
 
-        // Build result message
-        let mut result_parts = Vec::new();
-        if added > 0 {
-            result_parts.push(format!("{} added", added));
-        }
-        if skipped_duplicate > 0 {
-            result_parts.push(format!("{} duplicate", skipped_duplicate));
+    /// Queues a switch to another profile for `:profile <name>`; unknown names create
+    /// a new, empty profile
+    fn queue_profile_switch(&mut self, name: &str) -> bool {
+        let name = name.trim();
+        if !paths::valid_profile_name(name) {
+            self.error_message = Some(format!(
+                "Invalid profile name \"{}\" (use letters, digits, '-' and '_')",
+                name
+            ));
+            return false;
         }
-        if skipped_invalid > 0 {
-            result_parts.push(format!("{} invalid", skipped_invalid));
+        if name == paths::profile() {
+            self.status_message = Some(format!("Already using profile {}", name));
+            return false;
         }
-
-        let result_msg = format!("Import: {}", result_parts.join(", "));
-        self.import_result = Some(result_msg.clone());
-        self.error_message = Some(result_msg);
-
-        self.input_mode = InputMode::Normal;
-        self.input_buffer.clear();
-
-        Ok(())
+        self.pending_profile = Some(name.to_string());
+        self.status_message = Some(format!("Switching to profile {}...", name));
+        true
     }
 
-    /// Validates a URL as a valid RSS/Atom feed and returns its title if valid.
-    /// Returns Ok(Some(title)) if valid, Ok(None) if invalid, or Err on failure.
-    pub async fn validate_and_get_feed_title(
-        url: &str,
-        timeout_secs: u64,
-    ) -> AppResult<Option<String>> {
-        // First validate URL format
-        let url = reqwest::Url::parse(url)?;
-        if url.scheme() != "http" && url.scheme() != "https" {
-            return Ok(None);
+    /// Switches to the profile queued by `:profile`, if any: the current state is saved
+    /// and the app is loaded again from the other profile's files and caches. Its feeds are
+    /// fetched by the next refresh rather than holding up the switch.
+    pub fn switch_profile_pending(&mut self) {
+        let Some(profile) = self.pending_profile.take() else {
+            return;
+        };
+        if let Err(e) = self.save_state() {
+            error!("Failed to save state before switching profile: {}", e);
+            self.error_message = Some(format!("Failed to save state: {}", e));
+            return;
         }
-
-        // Try to fetch and parse the feed
-        let client = create_http_client(timeout_secs);
-        match client.get(url.as_str()).send().await {
-            Ok(response) => {
-                // Check for HTTP errors before trying to parse
-                if !response.status().is_success() {
-                    error!(
-                        "HTTP error {} when fetching feed: {}",
-                        response.status(),
-                        url
-                    );
-                    return Ok(None);
-                }
-
-                let bytes = response.bytes().await?;
-                // Try RSS first
-                if let Ok(channel) = Channel::read_from(&bytes[..]) {
-                    let title = channel.title().to_string();
-                    return Ok(Some(if title.is_empty() {
-                        url.to_string()
-                    } else {
-                        title
-                    }));
-                }
-                // Try Atom if RSS fails
-                if let Ok(feed) = AtomFeed::read_from(&bytes[..]) {
-                    let title = feed.title().value.clone();
-                    return Ok(Some(if title.is_empty() {
-                        url.to_string()
-                    } else {
-                        title
-                    }));
-                }
-                Ok(None)
-            }
-            Err(_) => Ok(None),
+        info!("Switching to profile {}", profile);
+        paths::set_profile(Some(profile.clone()));
+        *self = App::load();
+        self.last_refresh = Some(SystemTime::now());
+        self.profile_switched = true;
+        if self.error_message.is_none() {
+            self.status_message = Some(format!("Profile: {}", profile));
         }
     }
 
-    pub async fn add_feed(&mut self) -> AppResult<()> {
-        debug!("Attempting to add feed: {}", self.input_buffer);
-        match Self::validate_and_get_feed_title(&self.input_buffer, self.config.http_timeout_secs)
-            .await
-        {
-            Ok(Some(title)) => {
-                info!(
-                    "Successfully validated feed: {} ({})",
-                    title, self.input_buffer
-                );
-                self.rss_feeds.push(FeedInfo {
-                    url: self.input_buffer.clone(),
-                    title,
-                    category: None,
-                });
-                self.save_feeds()?;
-                self.input_buffer.clear();
-                self.input_mode = InputMode::Normal;
-                Ok(())
-            }
-            Ok(None) => {
-                error!("Invalid RSS feed URL: {}", self.input_buffer);
-                self.error_message = Some("Invalid RSS feed URL".to_string());
-                Ok(())
-            }
+    /// Subscribes to the feed queued by `:add`, if any
+    pub async fn subscribe_pending(&mut self) {
+        let Some((url, category)) = self.pending_subscription.take() else {
+            return;
+        };
+        match self.subscribe(&url, category).await {
+            Ok(title) => self.status_message = Some(format!("Added {}", title)),
             Err(e) => {
-                error!("Error validating feed: {}", e);
+                error!("Failed to add feed {}: {}", url, e);
+                self.status_message = None;
                 self.error_message = Some(format!("Error: {}", e));
-                Ok(())
             }
         }
     }
 
-    pub async fn select_feed(&mut self, index: usize) -> AppResult<()> {
-        if index < self.rss_feeds.len() {
-            debug!("Loading feed content from index {}", index);
-            self.selected_index = Some(index);
-            self.load_feed_content().await?;
+    /// Index in `rss_feeds` of the feed a command acts on: the selected row in the Feed
+    /// Manager, otherwise the feed whose articles are shown
+    fn command_feed_index(&self) -> Option<usize> {
+        if self.page_mode == PageMode::FeedManager {
+            return self.selected_index.and_then(|row| self.feed_index_at(row));
         }
-        Ok(())
+        let url = self.loaded_feed.as_deref()?;
+        self.rss_feeds.iter().position(|f| f.url == url)
     }
 
-    pub async fn load_feed_content(&mut self) -> AppResult<()> {
-        if let Some(index) = self.selected_index {
-            if let Some(feed_info) = self.rss_feeds.get(index) {
-                let url = &feed_info.url;
-                let feed_title = &feed_info.title;
-                debug!("Checking cache for URL: {}", url);
+    /// Asks to unsubscribe from the feed with the given title (`:delete <title>`), or from
+    /// the selected or open feed when no title is given, by selecting it in the Feed Manager
+    /// and waiting for the same confirmation as deleting there
+    fn delete_feed_by_title(&mut self, title: &str) -> bool {
+        let title = title.trim();
+        if title.is_empty() && self.page_mode == PageMode::FeedManager && self.has_marked_feeds() {
+            self.start_deleting();
+            return true;
+        }
+        let index = if title.is_empty() {
+            self.command_feed_index()
+        } else {
+            self.rss_feeds
+                .iter()
+                .position(|f| f.title.eq_ignore_ascii_case(title))
+        };
+        let Some(index) = index else {
+            self.error_message = Some(if title.is_empty() {
+                "No feed selected".to_string()
+            } else {
+                format!("Unknown feed '{}'", title)
+            });
+            return false;
+        };
+        if self.page_mode != PageMode::FeedManager {
+            self.toggle_feed_manager();
+        }
+        self.clear_feed_marks();
+        // Unfold the categories hiding the feed
+        if let Some(category) = self.rss_feeds[index].category.clone() {
+            self.collapsed_categories
+                .retain(|path| !category_in_path(&category, path));
+        }
+        self.select_feed_row(index);
+        self.input_mode = InputMode::Deleting;
+        true
+    }
 
-                // Try to load from cache first
-                if let Some(cached_content) = self.load_feed_cache(url) {
-                    debug!("Using cached content for {}", url);
-                    self.current_feed_content = cached_content;
-                    return Ok(());
-                }
+    /// Renames the selected or open feed (`:rename <title>`)
+    fn rename_feed(&mut self, title: &str) -> bool {
+        let title = title.trim();
+        let Some(index) = self.command_feed_index() else {
+            self.error_message = Some("No feed selected".to_string());
+            return false;
+        };
+        if title.is_empty() {
+            self.error_message = Some(format!("Usage: {}", command_usage("rename")));
+            return false;
+        }
+        self.set_feed_title(index, title)
+    }
 
-                debug!("Fetching feed content from URL: {}", url);
-                let client = create_http_client(self.config.http_timeout_secs);
-                let response = client.get(url.as_str()).send().await?;
+    /// Filters the article list with a search query (`:filter <query>`)
+    fn filter_articles(&mut self, query: &str) -> bool {
+        self.search_query = query.trim().to_string();
+        self.update_search_filter();
+        if let Some(e) = &self.search_error {
+            self.error_message = Some(format!("Invalid search: {}", e));
+            return false;
+        }
+        self.status_message = Some(format!("{} matching articles", self.visible_item_count()));
+        true
+    }
 
-                // Check for HTTP errors
-                if !response.status().is_success() {
-                    self.error_message = Some(format!(
-                        "HTTP error {}: {}",
-                        response.status(),
-                        response
-                            .status()
-                            .canonical_reason()
-                            .unwrap_or("Unknown error")
-                    ));
-                    return Ok(());
+    /// Opens the n-th listed article, counting from 1 (`:open <n>`)
+    fn open_article_number(&mut self, arg: &str) -> bool {
+        if !matches!(self.page_mode, PageMode::FeedList | PageMode::Favorites) {
+            self.error_message = Some("Not in an article list".to_string());
+            return false;
+        }
+        let count = self.visible_item_count();
+        match arg.trim().parse::<usize>() {
+            Ok(n) if (1..=count).contains(&n) => {
+                self.selected_index = Some(n - 1);
+                self.ensure_selection_visible();
+                self.open_selected_feed();
+                true
+            }
+            _ => {
+                self.error_message = Some(format!("No article {} (1-{})", arg.trim(), count));
+                false
+            }
+        }
+    }
+
+    /// Every config setting with its current value, in Settings page order
+    pub fn settings(&self) -> Vec<Setting> {
+        let config = serde_json::to_value(&self.config).unwrap_or_default();
+        let mut settings = Vec::new();
+        for (key, description) in SETTINGS {
+            match value_at(&config, key) {
+                Some(serde_json::Value::Object(fields)) => {
+                    settings.extend(fields.iter().map(|(field, value)| Setting {
+                        key: format!("{}.{}", key, field),
+                        value: setting_text(value),
+                        description: format!("{} {}", description, field.replace('_', " ")),
+                        editable: true,
+                    }));
                 }
+                Some(value) => settings.push(Setting {
+                    key: key.to_string(),
+                    value: setting_text(value),
+                    description: description.to_string(),
+                    editable: !value.is_array(),
+                }),
+                None => {}
+            }
+        }
+        settings
+    }
 
-                let content = response.bytes().await?;
+    /// Current value of a setting, or None for an unknown key
+    pub fn config_value(&self, key: &str) -> Option<String> {
+        let config = serde_json::to_value(&self.config).ok()?;
+        value_at(&config, key)
+            .filter(|value| !value.is_object())
+            .map(setting_text)
+    }
 
-                let feed_title_clone = feed_title.clone();
-                let mut feed_items: Vec<FeedItem> = match Channel::read_from(&content[..]) {
-                    Ok(channel) => {
-                        // Handle RSS feed
-                        channel
-                            .items()
-                            .iter()
-                            .map(|item| {
-                                let description = item
-                                    .description()
-                                    .unwrap_or("No description")
-                                    .replace(|c| ['\n', '\r'].contains(&c), " ");
-                                let clean_description =
-                                    html2text::from_read(description.as_bytes(), 80);
+    /// Changes a setting, validates the resulting config, applies it and saves the config
+    /// file. Values are parsed according to the setting's type.
+    pub fn set_config_value(&mut self, key: &str, value: &str) -> Result<(), String> {
+        use serde_json::Value;
+
+        let mut config = serde_json::to_value(&self.config).map_err(|e| e.to_string())?;
+        let slot = key
+            .split('.')
+            .try_fold(&mut config, |value, part| value.get_mut(part))
+            .ok_or_else(|| format!("Unknown setting '{}'", key))?;
+        *slot = match slot {
+            Value::Bool(_) => match value.to_lowercase().as_str() {
+                "true" | "on" | "yes" | "1" => Value::Bool(true),
+                "false" | "off" | "no" | "0" => Value::Bool(false),
+                _ => return Err(format!("{} expects true or false, got '{}'", key, value)),
+            },
+            Value::Number(_) => value
+                .parse::<u64>()
+                .map(Value::from)
+                .map_err(|_| format!("{} expects a number, got '{}'", key, value))?,
+            Value::String(_) => Value::String(value.to_string()),
+            _ => return Err(format!("{} cannot be changed here", key)),
+        };
 
-                                let published = item.pub_date().and_then(|date| {
-                                    DateTime::parse_from_rfc2822(date).ok().map(|dt| dt.into())
-                                });
+        let config: Config =
+            serde_json::from_value(config).map_err(|e| format!("Invalid {}: {}", key, e))?;
+        config.validate().map_err(|problems| problems.join("; "))?;
+        info!("Setting {} = {}", key, value);
+        self.config = config;
+        self.save_config()
+            .map_err(|e| format!("Failed to save config: {}", e))
+    }
 
-                                FeedItem {
-                                    title: format!(
-                                        "{} | {}",
-                                        item.title().unwrap_or("No title"),
-                                        feed_title_clone
-                                    ),
-                                    description: clean_description,
-                                    link: item.link().unwrap_or("").to_string(),
-                                    published,
-                                    id: Self::create_item_id(
-                                        item.title().unwrap_or("No title"),
-                                        published,
-                                        url,
-                                    ),
-                                    feed_url: url.clone(),
-                                }
-                            })
-                            .collect()
-                    }
-                    Err(_) => {
-                        // Try parsing as Atom feed
-                        match AtomFeed::read_from(&content[..]) {
-                            Ok(feed) => feed
-                                .entries()
-                                .iter()
-                                .map(|entry| {
-                                    let description = entry
-                                        .content()
-                                        .and_then(|c| c.value.clone())
-                                        .or_else(|| entry.summary().map(|s| s.value.clone()))
-                                        .unwrap_or_else(|| "No description".to_string());
-                                    let clean_description =
-                                        html2text::from_read(description.as_bytes(), 80);
+    /// Restores a setting to its default value
+    pub fn reset_config_value(&mut self, key: &str) -> Result<(), String> {
+        let defaults = serde_json::to_value(Config::default()).map_err(|e| e.to_string())?;
+        let value = value_at(&defaults, key).ok_or_else(|| format!("Unknown setting '{}'", key))?;
+        self.set_config_value(key, &setting_text(value))
+    }
 
-                                    let published = entry
-                                        .published()
-                                        .or_else(|| Some(entry.updated()))
-                                        .map(|date| date.to_owned().into());
+    /// The setting selected on the Settings page
+    fn selected_setting(&self) -> Option<Setting> {
+        self.selected_index
+            .and_then(|index| self.settings().into_iter().nth(index))
+    }
 
-                                    FeedItem {
-                                        title: format!(
-                                            "{} | {}",
-                                            entry.title().value,
-                                            feed_title_clone
-                                        ),
-                                        description: clean_description,
-                                        link: entry
-                                            .links()
-                                            .first()
-                                            .map(|l| l.href().to_string())
-                                            .unwrap_or_default(),
-                                        published,
-                                        id: Self::create_item_id(
-                                            &entry.title().value,
-                                            published,
-                                            url,
-                                        ),
-                                        feed_url: url.clone(),
-                                    }
-                                })
-                                .collect(),
-                            Err(e) => {
-                                error!("Failed to parse feed as either RSS or Atom: {}", e);
-                                return Err(Box::new(e));
-                            }
-                        }
-                    }
+    /// Opens the Settings page
+    pub fn open_settings(&mut self) {
+        self.page_mode = PageMode::Settings;
+        self.input_mode = InputMode::Normal;
+        self.selected_index = Some(0);
+        self.scroll = 0;
+    }
+
+    /// Leaves the Settings page for the feed list
+    pub fn close_settings(&mut self) {
+        self.page_mode = PageMode::FeedList;
+        self.input_mode = InputMode::Normal;
+        self.selected_index = None;
+        self.scroll = 0;
+        self.refresh_filters();
+    }
+
+    /// Toggles the selected setting if it is a flag, otherwise starts editing its value
+    pub fn start_editing_setting(&mut self) {
+        let Some(setting) = self.selected_setting() else {
+            return;
+        };
+        if !setting.editable {
+            self.error_message = Some(setting.description);
+            return;
+        }
+        match setting.value.as_str() {
+            "true" | "false" => {
+                let flipped = if setting.value == "true" {
+                    "false"
+                } else {
+                    "true"
                 };
+                self.apply_setting(&setting.key, flipped);
+            }
+            _ => {
+                self.input_buffer = setting.value;
+                self.input_mode = InputMode::EditingSetting;
+            }
+        }
+    }
 
-                // Sort by date, newest first
-                feed_items.sort_by(|a, b| b.published.cmp(&a.published));
+    /// Saves the value typed for the selected setting; invalid values keep the input open
+    pub fn confirm_setting(&mut self) {
+        let Some(setting) = self.selected_setting() else {
+            self.cancel_setting_input();
+            return;
+        };
+        let value = self.input_buffer.trim().to_string();
+        if self.apply_setting(&setting.key, &value) {
+            self.input_mode = InputMode::Normal;
+            self.input_buffer.clear();
+        }
+    }
 
-                // Save to cache
-                if let Err(e) = self.save_feed_cache(url, &feed_items) {
-                    error!("Failed to cache feed content: {}", e);
-                }
+    /// Cancels editing or resetting a setting
+    pub fn cancel_setting_input(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+        self.clear_error();
+    }
 
-                self.current_feed_content = feed_items;
-                Ok(())
-            } else {
-                debug!("No feed URL found at index {}", index);
-                Ok(())
-            }
-        } else {
-            debug!("No feed selected");
-            Ok(())
+    /// Asks for confirmation before the selected setting goes back to its default
+    pub fn start_resetting_setting(&mut self) {
+        if self.selected_setting().is_some_and(|s| s.editable) {
+            self.input_mode = InputMode::ResettingSetting;
         }
     }
 
-    fn save_feeds(&self) -> AppResult<()> {
-        let saved = SavedState {
-            feeds: self.rss_feeds.clone(),
-            read_items: self.read_items.clone(),
-            favorites: self.favorites.clone(),
+    /// Restores the selected setting to its default value
+    pub fn reset_selected_setting(&mut self) {
+        self.input_mode = InputMode::Normal;
+        let Some(setting) = self.selected_setting().filter(|s| s.editable) else {
+            return;
         };
-        let content = serde_json::to_string_pretty(&saved)?;
-        fs::write(&self.save_path, content)?;
-        debug!(
-            "Saved {} feeds, {} read items, and {} favorites to {}",
-            self.rss_feeds.len(),
-            self.read_items.len(),
-            self.favorites.len(),
-            self.save_path.display()
-        );
-        Ok(())
+        match self.reset_config_value(&setting.key) {
+            Ok(()) => {
+                self.clear_error();
+                self.status_message = Some(format!(
+                    "{} reset to {}",
+                    setting.key,
+                    self.config_value(&setting.key).unwrap_or_default()
+                ));
+            }
+            Err(e) => self.error_message = Some(e),
+        }
     }
 
-    pub fn open_selected_feed(&self) {
-        if let Some(visible_index) = self.selected_index {
-            if let Some(actual_index) = self.get_actual_index(visible_index) {
-                if let Some(item) = self.current_feed_content.get(actual_index) {
-                    if !item.link.is_empty() {
-                        let _ = open::that(&item.link);
-                    }
-                }
+    /// Changes a setting, reporting the result in the status line. Returns false if the
+    /// value was rejected.
+    fn apply_setting(&mut self, key: &str, value: &str) -> bool {
+        match self.set_config_value(key, value) {
+            Ok(()) => {
+                self.clear_error();
+                self.status_message = Some(format!("{}={}", key, value));
+                true
+            }
+            Err(e) => {
+                self.error_message = Some(e);
+                false
             }
         }
     }
 
-    /// Copies the selected item's link to the clipboard using OSC 52
-    pub fn copy_selected_link(&mut self) {
-        if let Some(visible_index) = self.selected_index {
-            if let Some(actual_index) = self.get_actual_index(visible_index) {
-                if let Some(item) = self.current_feed_content.get(actual_index) {
-                    if !item.link.is_empty() {
-                        match copy_to_clipboard_osc52(&item.link) {
-                            Ok(()) => {
-                                self.status_message = Some("Link copied!".to_string());
-                                debug!("Copied link to clipboard: {}", item.link);
-                            }
-                            Err(e) => {
-                                error!("Failed to copy to clipboard: {}", e);
-                                self.error_message = Some(format!("Failed to copy: {}", e));
-                            }
-                        }
-                    }
+    /// Shows or changes a runtime setting (`:set key=value`, `:set key`)
+    fn set_config_from_command(&mut self, arg: &str) -> bool {
+        let arg = arg.trim();
+        let (key, value) = match arg.split_once(|c: char| c == '=' || c.is_whitespace()) {
+            Some((key, value)) => (key.trim(), Some(value.trim())),
+            None => (arg, None),
+        };
+        let key = key.to_lowercase();
+        let Some(value) = value else {
+            return match self.config_value(&key) {
+                Some(current) => {
+                    self.status_message = Some(format!("{}={}", key, current));
+                    true
+                }
+                None => {
+                    self.error_message = Some(format!("Unknown setting '{}'", key));
+                    false
                 }
+            };
+        };
+        self.apply_setting(&key, value)
+    }
+
+    /// Writes the listed articles as JSON or the subscriptions as OPML to a file
+    /// (`:export json|opml <path>`)
+    fn export_from_command(&mut self, arg: &str) -> bool {
+        let Some((format, path)) = arg.trim().split_once(char::is_whitespace) else {
+            self.error_message = Some(format!("Usage: {}", command_usage("export")));
+            return false;
+        };
+        let path = expand_home(path.trim());
+        let (content, what) = match format.to_lowercase().as_str() {
+            "json" => {
+                let items: Vec<&FeedItem> = self
+                    .get_visible_items()
+                    .into_iter()
+                    .map(|(_, item)| item)
+                    .collect();
+                let what = format!("{} articles", items.len());
+                (
+                    serde_json::to_string_pretty(&items).map_err(|e| e.into()),
+                    what,
+                )
+            }
+            "opml" => (
+                self.generate_opml(),
+                format!("{} feeds", self.rss_feeds.len()),
+            ),
+            other => {
+                self.error_message = Some(format!("Unknown export format '{}'", other));
+                return false;
+            }
+        };
+        match content.and_then(|content| Ok(fs::write(&path, content)?)) {
+            Ok(()) => {
+                info!("Exported {} to {}", what, path.display());
+                self.status_message = Some(format!("Exported {} to {}", what, path.display()));
+                true
+            }
+            Err(e) => {
+                error!("Failed to export to {}: {}", path.display(), e);
+                self.error_message = Some(format!("Failed to export: {}", e));
+                false
             }
         }
     }
 
-    pub fn clear_error(&mut self) {
-        self.error_message = None;
+    /// Opens the jump palette over feeds, categories, saved searches, commands and
+    /// recent articles
+    pub fn open_palette(&mut self) {
+        let mut entries: Vec<PaletteEntry> = Vec::new();
+        let mut feeds: Vec<&FeedInfo> = self.rss_feeds.iter().collect();
+        feeds.sort_by_key(|f| f.title.to_lowercase());
+        entries.extend(feeds.into_iter().map(|feed| PaletteEntry {
+            label: feed.title.clone(),
+            detail: feed.category.clone().unwrap_or_default(),
+            target: PaletteTarget::Feed(feed.url.clone()),
+        }));
+        entries.extend(self.category_paths().into_iter().map(|path| {
+            let (feeds, unread) = self.count_for_category(&path);
+            PaletteEntry {
+                detail: format!("{} feeds, {} unread", feeds, unread),
+                label: path.clone(),
+                target: PaletteTarget::Category(path),
+            }
+        }));
+        entries.extend(
+            self.config
+                .saved_searches
+                .iter()
+                .map(|search| PaletteEntry {
+                    label: search.name.clone(),
+                    detail: search.query.clone(),
+                    target: PaletteTarget::SavedSearch(search.name.clone()),
+                }),
+        );
+        entries.extend(
+            PALETTE_COMMANDS
+                .iter()
+                .map(|(command, description)| PaletteEntry {
+                    label: format!(":{}", command.trim_end()),
+                    detail: description.to_string(),
+                    target: PaletteTarget::Command(command.to_string()),
+                }),
+        );
+
+        // Recent articles: the loaded ones first, then the newest cached ones
+        let mut seen = HashSet::new();
+        let cached = self.all_cached_items();
+        for item in self.current_feed_content.iter().chain(cached.iter()) {
+            if seen.len() >= PALETTE_RECENT_ARTICLES {
+                break;
+            }
+            if seen.insert(item.id.clone()) {
+                let feed_title = self
+                    .rss_feeds
+                    .iter()
+                    .find(|f| f.url == item.feed_url)
+                    .map(|f| f.title.clone())
+                    .unwrap_or_default();
+                entries.push(PaletteEntry {
+                    label: item.title.clone(),
+                    detail: feed_title,
+                    target: PaletteTarget::Article(Box::new(item.clone())),
+                });
+            }
+        }
+
+        self.palette_entries = entries;
+        self.palette_query.clear();
+        self.palette_selected = 0;
+        self.input_mode = InputMode::Palette;
     }
 
-    /// Clears both status and error messages
-    pub fn clear_messages(&mut self) {
-        self.status_message = None;
-        self.error_message = None;
+    /// Closes the jump palette without doing anything
+    pub fn close_palette(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.palette_query.clear();
+        self.palette_entries.clear();
     }
 
-    pub fn scroll_up(&mut self) {
-        if self.scroll > 0 {
-            self.scroll = self.scroll.saturating_sub(1);
+    /// Palette entries matching the query, best first, with the matched character indices
+    /// of each label. An empty query lists the entries in their original order.
+    pub fn palette_matches(&self) -> Vec<(&PaletteEntry, Vec<usize>)> {
+        let mut matches: Vec<(i64, usize, &PaletteEntry, Vec<usize>)> = self
+            .palette_entries
+            .iter()
+            .enumerate()
+            .filter_map(|(order, entry)| {
+                fuzzy_match(&self.palette_query, &entry.label)
+                    .map(|(score, positions)| (score, order, entry, positions))
+            })
+            .collect();
+        matches.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        matches
+            .into_iter()
+            .take(PALETTE_MAX_MATCHES)
+            .map(|(_, _, entry, positions)| (entry, positions))
+            .collect()
+    }
+
+    /// Updates the palette query, keeping the selection on the best match
+    pub fn palette_input(&mut self, c: Option<char>) {
+        match c {
+            Some(c) => self.palette_query.push(c),
+            None => {
+                self.palette_query.pop();
+            }
         }
+        self.palette_selected = 0;
     }
 
-    pub fn scroll_down(&mut self) {
-        let max_scroll = match self.page_mode {
-            PageMode::FeedList | PageMode::Favorites => {
-                if self.current_feed_content.is_empty() {
-                    0
-                } else {
-                    self.current_feed_content.len().saturating_sub(1)
+    /// Moves the palette selection, wrapping around
+    pub fn palette_move(&mut self, down: bool) {
+        let count = self.palette_matches().len();
+        if count == 0 {
+            return;
+        }
+        self.palette_selected = if down {
+            (self.palette_selected + 1) % count
+        } else {
+            (self.palette_selected + count - 1) % count
+        };
+    }
+
+    /// Goes to the selected palette entry
+    pub async fn confirm_palette(&mut self) -> AppResult<()> {
+        let target = self
+            .palette_matches()
+            .get(self.palette_selected)
+            .map(|(entry, _)| entry.target.clone());
+        self.close_palette();
+        let Some(target) = target else {
+            return Ok(());
+        };
+
+        match target {
+            PaletteTarget::Feed(url) => {
+                let Some(index) = self.rss_feeds.iter().position(|f| f.url == url) else {
+                    return Ok(());
+                };
+                // Unfold the feed's categories so it has a Feed Manager row
+                if let Some(category) = self.rss_feeds[index].category.clone() {
+                    self.collapsed_categories
+                        .retain(|path| !category_in_path(&category, path));
+                }
+                let row = self.feed_manager_rows().iter().position(
+                    |row| matches!(row, FeedManagerRow::Feed { index: i, .. } if *i == index),
+                );
+                if let Some(row) = row {
+                    self.page_mode = PageMode::FeedList;
+                    self.select_feed(row).await?;
+                    self.selected_index = (!self.current_feed_content.is_empty()).then_some(0);
+                    self.scroll = 0;
                 }
             }
-            PageMode::FeedManager => {
-                if self.rss_feeds.is_empty() {
-                    0
-                } else {
-                    self.rss_feeds.len().saturating_sub(1)
+            PaletteTarget::Category(path) => {
+                self.page_mode = PageMode::FeedList;
+                self.set_category_scope(CategoryScope::Category(path));
+            }
+            PaletteTarget::SavedSearch(name) => {
+                if let Some(index) = self
+                    .config
+                    .saved_searches
+                    .iter()
+                    .position(|s| s.name == name)
+                {
+                    self.page_mode = PageMode::FeedList;
+                    self.open_saved_search(index);
                 }
             }
+            PaletteTarget::Command(command) if command.ends_with(' ') => {
+                // The command needs an argument: let the user type it
+                self.start_command_mode();
+                self.command_buffer = command;
+            }
+            PaletteTarget::Command(command) => {
+                self.command_buffer = command;
+                self.execute_command()?;
+            }
+            PaletteTarget::Article(item) => self.jump_to_article(&item),
+        }
+        Ok(())
+    }
+
+    /// Shows an article in the feed list and opens its preview, loading all articles if
+    /// it is not among the visible ones
+    fn jump_to_article(&mut self, item: &FeedItem) {
+        let position = |app: &App| {
+            app.get_visible_items()
+                .iter()
+                .position(|(_, visible)| visible.id == item.id)
         };
+        if self.page_mode != PageMode::FeedList || position(self).is_none() {
+            self.page_mode = PageMode::FeedList;
+            self.clear_search();
+            self.category_scope = CategoryScope::All;
+            self.loaded_feed = None;
+            self.current_feed_content = self.all_cached_items();
+            if !self.current_feed_content.iter().any(|i| i.id == item.id) {
+                self.current_feed_content.insert(0, item.clone());
+            }
+            self.apply_filters();
+        }
+        if let Some(index) = position(self) {
+            self.selected_index = Some(index);
+            self.ensure_selection_visible();
+            self.open_preview();
+        }
+    }
 
-        if (self.scroll as usize) < max_scroll {
-            self.scroll += 1;
+    /// Starts search mode
+    pub fn start_search(&mut self) {
+        self.input_mode = InputMode::Searching;
+        self.search_query.clear();
+        self.search_error = None;
+        self.update_filtered_indices();
+    }
+
+    /// Cancels search mode and clears the filter
+    pub fn cancel_search(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.search_query.clear();
+        self.search_error = None;
+        if self.search_everything {
+            self.search_everything = false;
+            self.current_feed_content = self.all_cached_items();
+        }
+        self.update_filtered_indices();
+        self.scroll = 0;
+        // Reset selection to first item if available
+        if !self.current_feed_content.is_empty() {
+            self.selected_index = Some(0);
+        }
+    }
+
+    /// Confirms the search and stays in filtered mode
+    pub fn confirm_search(&mut self) {
+        self.input_mode = InputMode::Normal;
+        // Keep the filter active, selection remains on current filtered item.
+        // A query that does not parse is dropped rather than left half-applied.
+        if let Some(e) = self.search_error.take() {
+            self.error_message = Some(format!("Invalid search: {}", e));
+            self.search_query.clear();
+            self.refresh_filters();
+        }
+    }
+
+    /// Updates the search filter based on the current query
+    pub fn update_search_filter(&mut self) {
+        if self.search_everything {
+            self.current_feed_content = self.everything_results();
+        }
+        self.apply_filters();
+    }
+
+    /// Articles from the search index matching the search query, best matches first
+    fn everything_results(&self) -> Vec<FeedItem> {
+        self.search_index
+            .search(&self.search_query, EVERYTHING_RESULTS)
+            .into_iter()
+            .map(|hit| hit.item.clone())
+            .collect()
+    }
+
+    /// Switches the search between the loaded articles and every indexed article
+    pub fn toggle_search_everything(&mut self) {
+        if self.page_mode != PageMode::FeedList {
+            self.error_message = Some("Searching everything works in the feed list".to_string());
+            return;
+        }
+        if self.search_everything {
+            self.search_everything = false;
+            self.current_feed_content = self.all_cached_items();
+        } else {
+            self.clear_views_for_everything();
+            self.search_everything = true;
+        }
+        self.update_search_filter();
+    }
+
+    /// Runs a search over every indexed article (`:find <query>`)
+    pub fn find_everywhere(&mut self, query: &str) -> bool {
+        if self.page_mode != PageMode::FeedList {
+            self.error_message = Some("Searching everything works in the feed list".to_string());
+            return false;
+        }
+        self.clear_views_for_everything();
+        self.search_everything = true;
+        self.search_query = query.trim().to_string();
+        self.update_search_filter();
+        self.status_message = Some(format!(
+            "{} matches in {} indexed articles",
+            self.visible_item_count(),
+            self.search_index.len()
+        ));
+        true
+    }
+
+    /// Leaves tag and saved search views, which searching everything replaces
+    fn clear_views_for_everything(&mut self) {
+        self.active_tag = None;
+        self.active_saved_search = None;
+        self.loaded_feed = None;
+    }
+
+    /// Adds articles to the search index
+    pub fn index_items(&mut self, items: &[FeedItem]) {
+        let titles: HashMap<&str, &str> = self
+            .rss_feeds
+            .iter()
+            .map(|f| (f.url.as_str(), f.title.as_str()))
+            .collect();
+        let added = self.search_index.add_items(items.iter().map(|item| {
+            let title = titles
+                .get(item.feed_url.as_str())
+                .copied()
+                .unwrap_or_default();
+            (item, title)
+        }));
+        if added > 0 {
+            debug!("Indexed {} new articles", added);
         }
     }
 
-    pub fn page_up(&mut self) {
-        let page_size = self.items_per_page() as u16;
-
-        // Scroll up by page size
-        self.scroll = self.scroll.saturating_sub(page_size);
+    /// Writes the search index to disk if it changed
+    pub fn save_search_index(&mut self) {
+        if let Err(e) = self.search_index.save() {
+            error!("Failed to save search index: {}", e);
+        }
+    }
 
-        // Update selection to follow scrolling
-        if let Some(index) = self.selected_index {
-            if (index as u16) >= self.scroll + page_size {
-                self.selected_index = Some(self.scroll as usize);
-            }
+    /// Applies all active filters (search query, unread-only and mute rules)
+    /// and resets the selection to the first visible item
+    fn apply_filters(&mut self) {
+        if !self.update_filtered_indices() {
+            // Keep the selection while a query is being typed
+            self.clamp_selection();
+            return;
         }
+        self.scroll = 0;
+        self.selected_index = match &self.filtered_indices {
+            Some(indices) if indices.is_empty() => None,
+            _ if self.current_feed_content.is_empty() => None,
+            _ => Some(0),
+        };
     }
 
-    pub fn page_down(&mut self) {
-        let page_size = self.items_per_page();
+    /// Re-applies the filters after the content changed, keeping the selection when possible
+    fn refresh_filters(&mut self) {
+        self.update_filtered_indices();
+        self.clamp_selection();
+    }
 
-        // Get the appropriate list length based on page mode
-        let list_len = match self.page_mode {
-            PageMode::FeedList | PageMode::Favorites => self.current_feed_content.len(),
-            PageMode::FeedManager => self.rss_feeds.len(),
+    /// Keeps the selection and scroll position within the visible items
+    fn clamp_selection(&mut self) {
+        let count = self.visible_item_count();
+        self.selected_index = match self.selected_index {
+            _ if count == 0 => None,
+            Some(index) => Some(index.min(count - 1)),
+            None => Some(0),
         };
+        if self.scroll as usize >= count {
+            self.scroll = 0;
+        }
+    }
 
-        // Calculate maximum possible scroll value
-        let max_scroll = if list_len == 0 {
-            0
+    /// Recomputes `filtered_indices` and `muted_count`.
+    /// Returns false if the search query does not parse; the other filters still apply.
+    fn update_filtered_indices(&mut self) -> bool {
+        self.drop_stale_item_marks();
+        // When searching everything the index has already matched the query
+        let mut parsed = true;
+        let query = match Query::parse(&self.search_query) {
+            _ if self.search_everything => None,
+            Ok(query) => {
+                self.search_error = None;
+                query
+            }
+            Err(e) => {
+                self.search_error = Some(e);
+                parsed = false;
+                None
+            }
+        };
+        let has_unread_filter = self.show_unread_only;
+        let scoped = self.category_scope != CategoryScope::All;
+        let hide_rules = if self.show_muted {
+            Vec::new()
         } else {
-            list_len
-                .saturating_sub(1)
-                .saturating_sub(page_size.saturating_sub(1))
+            self.mute_queries(MuteAction::Hide)
         };
 
-        // Calculate new scroll position, capped at maximum scroll
-        let new_scroll = (self.scroll as usize + page_size).min(max_scroll);
+        let mut muted_count = 0;
+        let filtered: Vec<usize> = self
+            .current_feed_content
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| {
+                if hide_rules.iter().any(|rule| rule.matches(item, self)) {
+                    muted_count += 1;
+                    return false;
+                }
 
-        self.scroll = new_scroll as u16;
+                // Limit to the selected category
+                if scoped && !self.item_in_scope(item, &self.category_scope) {
+                    return false;
+                }
 
-        // If the selected index is now above the visible area, update it
-        if let Some(index) = self.selected_index {
-            if index < new_scroll {
-                self.selected_index = Some(new_scroll);
-            }
-        }
-    }
+                // Apply search query if active
+                let matches_search = query.as_ref().is_none_or(|q| q.matches(item, self));
 
-    /// Scrolls to the top of the feed and selects the first item
-    pub fn scroll_to_top(&mut self) {
-        self.scroll = 0;
+                // Apply unread filter if active
+                let matches_unread = !has_unread_filter || !self.read_items.contains(&item.id);
 
-        // Select the first item if there are any items
-        match self.page_mode {
-            PageMode::FeedList | PageMode::Favorites => {
-                if !self.current_feed_content.is_empty() {
-                    self.selected_index = Some(0);
-                }
-            }
-            PageMode::FeedManager => {
-                if !self.rss_feeds.is_empty() {
-                    self.selected_index = Some(0);
-                }
-            }
-        }
-    }
+                matches_search && matches_unread
+            })
+            .map(|(i, _)| i)
+            .collect();
 
-    /// Scrolls to the bottom of the feed and selects the last item
-    pub fn scroll_to_bottom(&mut self) {
-        let len = match self.page_mode {
-            PageMode::FeedList | PageMode::Favorites => self.visible_item_count(),
-            PageMode::FeedManager => self.rss_feeds.len(),
+        // Move unread articles matching pinned highlight rules to the top, keeping their order
+        let pin_rules: Vec<Query> = self
+            .compiled_highlight_rules()
+            .into_iter()
+            .filter(|(rule, _)| rule.pin)
+            .map(|(_, query)| query)
+            .collect();
+        let (pinned, rest): (Vec<usize>, Vec<usize>) = filtered.into_iter().partition(|&i| {
+            let item = &self.current_feed_content[i];
+            !self.read_items.contains(&item.id) && pin_rules.iter().any(|q| q.matches(item, self))
+        });
+        let has_pinned = !pinned.is_empty();
+        self.pinned_count = pinned.len();
+
+        // Sort each part by the view's order (the default keeps the feed order)
+        let order = self.view_order();
+        let sorted = order != ViewOrder::default();
+        let filtered: Vec<usize> = if sorted {
+            let (mut pinned, mut rest) = (pinned, rest);
+            self.sort_indices(&mut pinned, order);
+            self.sort_indices(&mut rest, order);
+            pinned.into_iter().chain(rest).collect()
+        } else {
+            pinned.into_iter().chain(rest).collect()
         };
 
-        if len == 0 {
-            return;
-        }
-
-        // Select the last item
-        self.selected_index = Some(len - 1);
+        self.muted_count = muted_count;
+        // Without any active filter every item is visible in its original order
+        self.filtered_indices = if query.is_none()
+            && !has_unread_filter
+            && !scoped
+            && muted_count == 0
+            && !has_pinned
+            && !sorted
+        {
+            None
+        } else {
+            Some(filtered)
+        };
+        parsed
+    }
 
-        // Ensure the selection is visible by scrolling to show it
-        self.ensure_selection_visible();
+    /// Sorts indices into `current_feed_content` by group, then by the sort mode
+    fn sort_indices(&self, indices: &mut [usize], order: ViewOrder) {
+        let now = Local::now();
+        let keys: HashMap<usize, (usize, String)> = indices
+            .iter()
+            .map(|&i| (i, self.group_key(&self.current_feed_content[i], order, now)))
+            .collect();
+        let feed_title = |item: &FeedItem| {
+            self.rss_feeds
+                .iter()
+                .find(|f| f.url == item.feed_url)
+                .map(|f| f.title.to_lowercase())
+                .unwrap_or_default()
+        };
+        let newest = |a: &FeedItem, b: &FeedItem| b.published.cmp(&a.published);
+
+        indices.sort_by(|&a, &b| {
+            let (item_a, item_b) = (&self.current_feed_content[a], &self.current_feed_content[b]);
+            keys[&a].cmp(&keys[&b]).then_with(|| match order.sort {
+                SortMode::NewestFirst => newest(item_a, item_b),
+                // Undated articles go last either way
+                SortMode::OldestFirst => (item_a.published.is_none(), item_a.published)
+                    .cmp(&(item_b.published.is_none(), item_b.published)),
+                SortMode::Feed => feed_title(item_a)
+                    .cmp(&feed_title(item_b))
+                    .then_with(|| newest(item_a, item_b)),
+                SortMode::Title => item_a
+                    .title
+                    .to_lowercase()
+                    .cmp(&item_b.title.to_lowercase()),
+                SortMode::UnreadFirst => self
+                    .is_item_read(item_a)
+                    .cmp(&self.is_item_read(item_b))
+                    .then_with(|| newest(item_a, item_b)),
+                SortMode::FavoritesFirst => self
+                    .is_item_favorite(item_b)
+                    .cmp(&self.is_item_favorite(item_a))
+                    .then_with(|| newest(item_a, item_b)),
+            })
+        });
     }
 
-    /// Opens the article preview pane for the currently selected item
-    pub fn open_preview(&mut self) {
-        if let Some(visible_index) = self.selected_index {
-            if self.get_actual_index(visible_index).is_some() {
-                self.input_mode = InputMode::Preview;
-                self.preview_scroll = 0;
+    /// Returns the (rank, label) of the group an item falls in; groups sort by rank, then label
+    fn group_key(
+        &self,
+        item: &FeedItem,
+        order: ViewOrder,
+        now: DateTime<Local>,
+    ) -> (usize, String) {
+        match order.group {
+            GroupBy::None => (0, String::new()),
+            GroupBy::Day => {
+                let Some(published) = item.published else {
+                    return (DAY_GROUPS.len(), "No date".to_string());
+                };
+                let published: DateTime<Local> = published.into();
+                let days = (now.date_naive() - published.date_naive()).num_days();
+                let rank = match days {
+                    ..=0 => 0,
+                    1 => 1,
+                    2..=6 => 2,
+                    7..=13 => 3,
+                    _ => 4,
+                };
+                // Oldest first lists the day groups in reverse
+                let position = if order.sort == SortMode::OldestFirst {
+                    DAY_GROUPS.len() - 1 - rank
+                } else {
+                    rank
+                };
+                (position, DAY_GROUPS[rank].to_string())
             }
+            GroupBy::Feed => (
+                0,
+                self.rss_feeds
+                    .iter()
+                    .find(|f| f.url == item.feed_url)
+                    .map_or_else(|| item.feed_url.clone(), |f| f.title.clone()),
+            ),
+            GroupBy::Category => match self
+                .rss_feeds
+                .iter()
+                .find(|f| f.url == item.feed_url)
+                .and_then(|f| f.category.clone())
+            {
+                Some(category) => (0, category),
+                None => (1, "Uncategorized".to_string()),
+            },
         }
     }
 
-    /// Closes the article preview pane
-    pub fn close_preview(&mut self) {
-        self.input_mode = InputMode::Normal;
-        self.preview_scroll = 0;
+    /// Returns the group header shown above the article at a visible index, if it starts a group
+    pub fn group_header(&self, visible_index: usize) -> Option<String> {
+        let order = self.view_order();
+        if order.group == GroupBy::None {
+            return None;
+        }
+        let label = |index: usize| {
+            if index < self.pinned_count {
+                return Some("Pinned".to_string());
+            }
+            let item = &self.current_feed_content[self.get_actual_index(index)?];
+            Some(self.group_key(item, order, Local::now()).1)
+        };
+        let current = label(visible_index)?;
+        if visible_index > 0 && label(visible_index - 1).as_ref() == Some(&current) {
+            return None;
+        }
+        Some(current)
     }
 
-    /// Scrolls the preview pane up by one line
-    pub fn preview_scroll_up(&mut self) {
-        self.preview_scroll = self.preview_scroll.saturating_sub(1);
+    /// Returns how many articles, starting at a visible index, fit in `height` lines
+    /// (3 lines per article and 1 per group header); always at least one
+    pub fn items_fitting(&self, start: usize, height: usize) -> usize {
+        let total = self.visible_item_count();
+        let mut lines = 0;
+        let mut count = 0;
+        loop {
+            // Slots past the end of the list count as plain articles
+            let index = start + count;
+            let header = index < total && self.group_header(index).is_some();
+            lines += 3 + usize::from(header);
+            if lines > height {
+                break;
+            }
+            count += 1;
+        }
+        count.max(1)
     }
 
-    /// Scrolls the preview pane down by one line
-    pub fn preview_scroll_down(&mut self) {
-        self.preview_scroll = self.preview_scroll.saturating_add(1);
+    /// Returns the visible index of the article drawn at a line of the list, if any
+    pub fn item_at_line(&self, line: usize) -> Option<usize> {
+        let mut top = 0;
+        for index in self.scroll as usize..self.visible_item_count() {
+            top += usize::from(self.group_header(index).is_some());
+            if line < top {
+                // A header line
+                return None;
+            }
+            if line < top + 3 {
+                return Some(index);
+            }
+            top += 3;
+        }
+        None
     }
 
-    /// Scrolls the preview pane up by a page
-    pub fn preview_page_up(&mut self) {
-        let page_size = self.terminal_height.saturating_sub(10);
-        self.preview_scroll = self.preview_scroll.saturating_sub(page_size);
+    /// Key the sort and grouping of the current view are saved under
+    pub fn view_key(&self) -> String {
+        if self.search_everything {
+            "everything".to_string()
+        } else if self.page_mode == PageMode::Favorites {
+            "favorites".to_string()
+        } else if let Some(name) = &self.active_saved_search {
+            format!("search:{}", name)
+        } else if let Some(tag) = &self.active_tag {
+            format!("tag:{}", tag)
+        } else if let Some(url) = &self.loaded_feed {
+            format!("feed:{}", url)
+        } else if self.category_scope != CategoryScope::All {
+            format!("category:{}", self.category_scope.label())
+        } else {
+            "all".to_string()
+        }
     }
 
-    /// Scrolls the preview pane down by a page
-    pub fn preview_page_down(&mut self) {
-        let page_size = self.terminal_height.saturating_sub(10);
-        self.preview_scroll = self.preview_scroll.saturating_add(page_size);
+    /// Returns the sort and grouping of the current view
+    pub fn view_order(&self) -> ViewOrder {
+        self.view_orders
+            .get(&self.view_key())
+            .copied()
+            .unwrap_or_default()
     }
 
-    /// Gets the currently selected feed item for preview
-    pub fn get_preview_item(&self) -> Option<&FeedItem> {
-        if let Some(visible_index) = self.selected_index {
-            if let Some(actual_index) = self.get_actual_index(visible_index) {
-                return self.current_feed_content.get(actual_index);
-            }
+    /// Changes the sort and grouping of the current view and saves it
+    pub fn set_view_order(&mut self, order: ViewOrder) {
+        let key = self.view_key();
+        if order == ViewOrder::default() {
+            self.view_orders.remove(&key);
+        } else {
+            self.view_orders.insert(key, order);
         }
-        None
+        if let Err(e) = self.save_state() {
+            error!("Failed to save view order: {}", e);
+        }
+        self.apply_filters();
+        self.status_message = Some(format!(
+            "Sort: {}, group: {}",
+            order.sort.label(),
+            order.group.label()
+        ));
     }
 
-    /// Formats a feed item as markdown for export
-    fn format_article_markdown(&self, item: &FeedItem) -> String {
-        let mut output = String::new();
-
-        // Title
-        output.push_str(&format!("# {}\n\n", item.title));
-
-        // Metadata
-        if let Some(published) = item.published {
-            if let Ok(duration) = published.duration_since(SystemTime::UNIX_EPOCH) {
-                let secs = duration.as_secs() as i64;
-                if let Some(dt) = chrono::DateTime::from_timestamp(secs, 0) {
-                    output.push_str(&format!("**Date:** {}\n\n", dt.format("%Y-%m-%d %H:%M")));
+    /// Sets the sort mode of the current view by name (`:sort <mode>`)
+    pub fn set_sort_by_name(&mut self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        // In the Feed Manager, `:sort` orders the feeds instead of the articles
+        if self.page_mode == PageMode::FeedManager {
+            return match FeedSort::ALL.iter().find(|s| s.label() == name) {
+                Some(&sort) => {
+                    self.set_feed_sort(sort);
+                    true
                 }
-            }
+                None => {
+                    self.error_message = Some(format!("Unknown feed order: {}", name));
+                    false
+                }
+            };
         }
-
-        if !item.link.is_empty() {
-            output.push_str(&format!("**Link:** {}\n\n", item.link));
+        match SortMode::ALL.iter().find(|m| m.label() == name) {
+            Some(&sort) => {
+                let order = self.view_order();
+                self.set_view_order(ViewOrder { sort, ..order });
+                true
+            }
+            None => {
+                self.error_message = Some(format!("Unknown sort mode: {}", name));
+                false
+            }
         }
+    }
 
-        // Status
-        let read_status = if self.read_items.contains(&item.id) {
-            "Read"
-        } else {
-            "Unread"
-        };
-        let fav_status = if self.favorites.contains(&item.id) {
-            "★ Favorited"
-        } else {
-            ""
-        };
-        if !fav_status.is_empty() {
-            output.push_str(&format!("**Status:** {} | {}\n\n", read_status, fav_status));
-        } else {
-            output.push_str(&format!("**Status:** {}\n\n", read_status));
+    /// Sets the grouping of the current view by name (`:group <mode>`)
+    pub fn set_group_by_name(&mut self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        match GroupBy::ALL.iter().find(|g| g.label() == name) {
+            Some(&group) => {
+                let order = self.view_order();
+                self.set_view_order(ViewOrder { group, ..order });
+                true
+            }
+            None => {
+                self.error_message = Some(format!("Unknown grouping: {}", name));
+                false
+            }
         }
+    }
 
-        output.push_str("---\n\n");
+    /// Switches the current view to the next sort mode
+    pub fn cycle_sort_mode(&mut self) {
+        let order = self.view_order();
+        self.set_view_order(ViewOrder {
+            sort: order.sort.next(),
+            ..order
+        });
+    }
 
-        // Content - convert HTML to plain text
-        let plain_text = html2text::from_read(item.description.as_bytes(), 80);
-        output.push_str(&plain_text);
+    /// Switches the current view to the next grouping
+    pub fn cycle_group_by(&mut self) {
+        let order = self.view_order();
+        self.set_view_order(ViewOrder {
+            group: order.group.next(),
+            ..order
+        });
+    }
 
-        output
+    /// Parses the highlight rules, skipping invalid ones
+    pub fn compiled_highlight_rules(&self) -> Vec<(&HighlightRule, Query)> {
+        self.config
+            .highlight_rules
+            .iter()
+            .filter_map(|rule| {
+                Query::parse(&rule.query)
+                    .ok()
+                    .flatten()
+                    .map(|query| (rule, query))
+            })
+            .collect()
     }
 
-    /// Exports the currently selected article to clipboard using OSC 52
-    pub fn export_article_to_clipboard(&mut self) {
-        let item = if let Some(item) = self.get_preview_item() {
-            item.clone()
-        } else {
-            self.error_message = Some("No article selected".to_string());
-            return;
-        };
+    /// Returns the first highlight rule matching an unread item (read items are never highlighted)
+    pub fn highlight_rule_for<'a>(
+        &self,
+        item: &FeedItem,
+        rules: &[(&'a HighlightRule, Query)],
+    ) -> Option<&'a HighlightRule> {
+        if self.read_items.contains(&item.id) {
+            return None;
+        }
+        rules
+            .iter()
+            .find(|(_, query)| query.matches(item, self))
+            .map(|(rule, _)| *rule)
+    }
 
-        let content = self.format_article_markdown(&item);
+    /// Groups new items by the alerting highlight rules they match, in rule order
+    pub fn alerts_for_new_items<'a>(
+        &self,
+        new_items: &[&'a FeedItem],
+    ) -> Vec<(&HighlightRule, Vec<&'a FeedItem>)> {
+        self.compiled_highlight_rules()
+            .into_iter()
+            .filter(|(rule, _)| rule.notify)
+            .filter_map(|(rule, query)| {
+                let matches: Vec<&FeedItem> = new_items
+                    .iter()
+                    .copied()
+                    .filter(|item| query.matches(item, self))
+                    .collect();
+                (!matches.is_empty()).then_some((rule, matches))
+            })
+            .collect()
+    }
 
-        match copy_to_clipboard_osc52(&content) {
-            Ok(()) => {
-                info!("Exported article to clipboard: {}", item.title);
-                self.status_message = Some("Article copied!".to_string());
-            }
-            Err(e) => {
-                error!("Failed to copy to clipboard: {}", e);
-                self.error_message = Some(format!("Failed to copy: {}", e));
-            }
-        }
+    /// Returns true if a search, unread-only filter or tag view is active (mute rules don't count)
+    pub fn has_active_filter(&self) -> bool {
+        !self.search_query.is_empty()
+            || self.show_unread_only
+            || self.active_tag.is_some()
+            || self.search_everything
     }
 
-    /// Exports the currently selected article to a file
-    pub fn export_article_to_file(&mut self) {
-        let item = if let Some(item) = self.get_preview_item() {
-            item.clone()
-        } else {
-            self.error_message = Some("No article selected".to_string());
-            return;
-        };
+    /// Returns the scopes the feed list can be limited to: all feeds, uncategorized feeds,
+    /// every category path (parents before their children), then feed tags
+    pub fn category_scopes(&self) -> Vec<CategoryScope> {
+        let mut scopes = vec![CategoryScope::All];
+        if self.rss_feeds.iter().any(|f| f.category.is_none()) {
+            scopes.push(CategoryScope::Uncategorized);
+        }
+        let paths = self.category_paths();
+        let tags: BTreeSet<&str> = self
+            .rss_feeds
+            .iter()
+            .flat_map(|f| f.tags.iter().map(String::as_str))
+            .filter(|tag| !paths.iter().any(|p| p.eq_ignore_ascii_case(tag)))
+            .collect();
+        scopes.extend(paths.iter().cloned().map(CategoryScope::Category));
+        scopes.extend(
+            tags.into_iter()
+                .map(|t| CategoryScope::Category(t.to_string())),
+        );
+        scopes
+    }
 
-        let content = self.format_article_markdown(&item);
+    /// Returns true if the item's feed is part of the scope
+    pub fn item_in_scope(&self, item: &FeedItem, scope: &CategoryScope) -> bool {
+        scope.includes(self.rss_feeds.iter().find(|f| f.url == item.feed_url))
+    }
 
-        // Create a safe filename from the title
-        let safe_title: String = item
-            .title
-            .chars()
-            .take(50)
-            .map(|c| {
-                if c.is_alphanumeric() || c == ' ' || c == '-' {
-                    c
-                } else {
-                    '_'
-                }
+    /// Returns the (unread, total) number of cached articles in a scope; paused feeds have
+    /// no unread articles
+    pub fn count_for_scope(&self, scope: &CategoryScope) -> (usize, usize) {
+        self.rss_feeds
+            .iter()
+            .filter(|feed| scope.includes(Some(feed)))
+            .fold((0, 0), |(unread, total), feed| {
+                let stats = self.feed_stats(&feed.url);
+                let feed_unread = if feed.paused { 0 } else { stats.unread };
+                (unread + feed_unread, total + stats.total)
             })
-            .collect::<String>()
-            .trim()
-            .replace(' ', "_");
+    }
 
-        // Get export directory
-        let mut path = dirs::data_dir().unwrap_or_else(|| PathBuf::from("."));
-        path.push("reedy");
-        path.push("exports");
-        if let Err(e) = fs::create_dir_all(&path) {
-            error!("Failed to create export directory: {}", e);
-            self.error_message = Some(format!("Failed to create export directory: {}", e));
-            return;
+    /// Limits the feed list to a category (or all feeds)
+    pub fn set_category_scope(&mut self, scope: CategoryScope) {
+        if self.active_tag.is_some() {
+            self.clear_search();
         }
+        if self.loaded_feed.is_some() && self.page_mode == PageMode::FeedList {
+            // Scoping works on the combined view of all feeds
+            self.current_feed_content = self.all_cached_items();
+            self.loaded_feed = None;
+        }
+        self.category_scope = scope;
+        self.apply_filters();
+        let (unread, total) = self.count_for_scope(&self.category_scope);
+        self.status_message = Some(format!(
+            "Category: {} ({} unread of {})",
+            self.category_scope.label(),
+            unread,
+            total
+        ));
+    }
 
-        // Add timestamp to filename to avoid collisions
-        let timestamp = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
-        path.push(format!("{}_{}.md", safe_title, timestamp));
+    /// Moves the category scope forward (or backward) through all feeds and each category
+    pub fn cycle_category(&mut self, forward: bool) {
+        let scopes = self.category_scopes();
+        let current = scopes
+            .iter()
+            .position(|scope| *scope == self.category_scope)
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % scopes.len()
+        } else {
+            (current + scopes.len() - 1) % scopes.len()
+        };
+        self.set_category_scope(scopes[next].clone());
+    }
 
-        match fs::write(&path, content) {
-            Ok(()) => {
-                info!("Exported article to: {}", path.display());
-                self.error_message = Some(format!("Saved to: {}", path.display()));
+    /// Sets the category scope from `:cat <name>` ("all" for every feed).
+    /// Returns false if no category has that name.
+    pub fn set_category_scope_by_name(&mut self, name: &str) -> bool {
+        let name = name.trim();
+        let scope = self
+            .category_scopes()
+            .into_iter()
+            .find(|scope| scope.label().eq_ignore_ascii_case(name));
+        match scope {
+            Some(scope) => {
+                self.set_category_scope(scope);
+                true
             }
-            Err(e) => {
-                error!("Failed to write file: {}", e);
-                self.error_message = Some(format!("Failed to save article: {}", e));
+            None => {
+                self.error_message = Some(format!("Unknown category '{}'", name));
+                false
             }
         }
     }
 
-    /// Enters vi-style command mode (triggered by ':')
-    pub fn start_command_mode(&mut self) {
-        self.input_mode = InputMode::Command;
-        self.command_buffer.clear();
+    /// Lists every category with its unread count in the status line
+    fn show_category_counts(&mut self) {
+        let counts: Vec<String> = self
+            .category_scopes()
+            .iter()
+            .map(|scope| {
+                let (unread, _) = self.count_for_scope(scope);
+                format!("{} ({})", scope.label(), unread)
+            })
+            .collect();
+        self.status_message = Some(format!("Unread: {}", counts.join(", ")));
     }
 
-    /// Cancels command mode without executing
-    pub fn cancel_command_mode(&mut self) {
-        self.input_mode = InputMode::Normal;
-        self.command_buffer.clear();
+    /// Parsed queries of the mute rules with the given action; invalid rules are skipped
+    fn mute_queries(&self, action: MuteAction) -> Vec<&Query> {
+        self.config
+            .mute_rules
+            .iter()
+            .filter(|rule| rule.action == action)
+            .filter_map(MuteRule::parsed_query)
+            .collect()
     }
 
-    /// Executes the current command buffer and returns to normal mode.
-    /// Returns Ok(true) if the command was executed successfully,
-    /// Ok(false) if the command was not recognized,
-    /// or an error if execution failed.
-    pub fn execute_command(&mut self) -> AppResult<bool> {
-        let command = self.command_buffer.trim().to_lowercase();
-        self.input_mode = InputMode::Normal;
-        self.command_buffer.clear();
+    /// Returns true if any mute rule matches the item, regardless of its action
+    pub fn is_item_muted(&self, item: &FeedItem) -> bool {
+        self.config
+            .mute_rules
+            .iter()
+            .filter_map(MuteRule::parsed_query)
+            .any(|query| query.matches(item, self))
+    }
 
-        match command.as_str() {
-            // Quit commands
-            "q" | "quit" => {
-                self.quit();
-                Ok(true)
-            }
-            // Write/save commands
-            "w" | "write" | "save" => {
-                self.save_state()?;
-                self.error_message = Some("State saved".to_string());
-                Ok(true)
-            }
-            // Write and quit
-            "wq" | "x" => {
-                self.save_state()?;
-                self.quit();
-                Ok(true)
-            }
-            // Force quit (without save - but we always save state anyway)
-            "q!" => {
-                self.quit();
-                Ok(true)
-            }
-            // Refresh feeds
-            "refresh" | "r" => {
-                // Set a flag to indicate refresh is needed (actual refresh is async)
-                self.auto_refresh_pending = true;
-                Ok(true)
-            }
-            // Help
-            "help" | "h" => {
-                self.toggle_help();
-                Ok(true)
-            }
-            // Open feed manager
-            "feeds" | "manage" => {
-                self.toggle_feed_manager();
-                Ok(true)
-            }
-            // Toggle favorites view
-            "favorites" | "fav" => {
-                // Return false to indicate async action needed
-                // The handler will call toggle_favorites_page().await
-                self.error_message = Some("__toggle_favorites__".to_string());
-                Ok(true)
-            }
-            // Mark all as read
-            "read" | "markread" => {
-                self.mark_all_as_read();
-                Ok(true)
-            }
-            // Scroll to top
-            "0" | "top" | "gg" => {
-                self.scroll_to_top();
-                Ok(true)
-            }
-            // Scroll to bottom
-            "$" | "bottom" => {
-                self.scroll_to_bottom();
-                Ok(true)
-            }
-            // Empty command - just cancel
-            "" => Ok(true),
-            // Unknown command
-            _ => {
-                self.error_message = Some(format!("Unknown command: {}", command));
-                Ok(false)
+    /// Marks items matching "mark read" mute rules as read. Returns the number of newly read items.
+    pub fn apply_mute_rules(&mut self, items: &[FeedItem]) -> usize {
+        let rules = self.mute_queries(MuteAction::MarkRead);
+        if rules.is_empty() {
+            return 0;
+        }
+        let to_mark: Vec<&FeedItem> = items
+            .iter()
+            .filter(|item| !self.read_items.contains(&item.id))
+            .filter(|item| rules.iter().any(|rule| rule.matches(item, self)))
+            .collect();
+        let count = to_mark.len();
+        if count > 0 {
+            let urls = to_mark.iter().map(|item| item.feed_url.clone()).collect();
+            self.read_items
+                .extend(to_mark.into_iter().map(|item| item.id.clone()));
+            self.recount_feeds(&urls);
+            if let Err(e) = self.save_state() {
+                error!("Failed to save state after applying mute rules: {}", e);
             }
+            debug!("Mute rules marked {} items as read", count);
         }
+        count
     }
 
-    /// Starts search mode
-    pub fn start_search(&mut self) {
-        self.input_mode = InputMode::Searching;
-        self.search_query.clear();
-        self.filtered_indices = None;
+    /// Toggles whether articles hidden by mute rules are shown
+    pub fn toggle_show_muted(&mut self) {
+        self.show_muted = !self.show_muted;
+        self.refresh_filters();
+        self.status_message = Some(if self.show_muted {
+            "Showing muted articles".to_string()
+        } else {
+            format!("Hiding {} muted articles", self.muted_count)
+        });
     }
 
-    /// Cancels search mode and clears the filter
-    pub fn cancel_search(&mut self) {
+    /// Opens the mute rules screen
+    pub fn open_mute_rules(&mut self) {
+        self.page_mode = PageMode::MuteRules;
         self.input_mode = InputMode::Normal;
-        self.search_query.clear();
-        self.filtered_indices = None;
+        self.selected_index = if self.config.mute_rules.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
         self.scroll = 0;
-        // Reset selection to first item if available
-        if !self.current_feed_content.is_empty() {
-            self.selected_index = Some(0);
-        }
     }
 
-    /// Confirms the search and stays in filtered mode
-    pub fn confirm_search(&mut self) {
+    /// Leaves the mute rules screen and re-applies the rules to the feed list
+    pub fn close_mute_rules(&mut self) {
+        self.page_mode = PageMode::FeedList;
         self.input_mode = InputMode::Normal;
-        // Keep the filter active, selection remains on current filtered item
+        self.selected_index = None;
+        self.scroll = 0;
+        self.refresh_filters();
     }
 
-    /// Updates the search filter based on the current query
-    pub fn update_search_filter(&mut self) {
-        self.apply_filters();
+    /// Starts entering a new mute rule
+    pub fn start_adding_mute_rule(&mut self) {
+        self.editing_rule = None;
+        self.input_mode = InputMode::Adding;
+        self.input_buffer.clear();
     }
 
-    /// Applies all active filters (search query and unread-only)
-    fn apply_filters(&mut self) {
-        let has_search = !self.search_query.is_empty();
-        let has_unread_filter = self.show_unread_only;
+    /// Starts editing the selected mute rule's query
+    pub fn start_editing_mute_rule(&mut self) {
+        if let Some(rule) = self
+            .selected_index
+            .and_then(|i| self.config.mute_rules.get(i))
+        {
+            self.input_buffer = rule.query.clone();
+            self.editing_rule = self.selected_index;
+            self.input_mode = InputMode::Adding;
+        }
+    }
 
-        // If no filters active, clear filtered_indices
-        if !has_search && !has_unread_filter {
-            self.filtered_indices = None;
-            self.scroll = 0;
-            if !self.current_feed_content.is_empty() {
-                self.selected_index = Some(0);
+    /// Cancels adding or editing a mute rule
+    pub fn cancel_mute_rule_input(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.editing_rule = None;
+        self.input_buffer.clear();
+        self.clear_error();
+    }
+
+    /// Saves the rule typed into the input buffer, as a new rule or over the one being edited
+    pub fn confirm_mute_rule(&mut self) {
+        let query = self.input_buffer.trim().to_string();
+        match Query::parse(&query) {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                self.error_message = Some("Mute rule cannot be empty".to_string());
+                return;
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Invalid rule: {}", e));
+                return;
             }
-            return;
         }
 
-        let query_lower = self.search_query.to_lowercase();
-        let filtered: Vec<usize> = self
-            .current_feed_content
-            .iter()
-            .enumerate()
-            .filter(|(_, item)| {
-                // Apply search filter if active
-                let matches_search = !has_search
-                    || item.title.to_lowercase().contains(&query_lower)
-                    || item.description.to_lowercase().contains(&query_lower);
+        match self
+            .editing_rule
+            .and_then(|i| self.config.mute_rules.get_mut(i))
+        {
+            Some(rule) => *rule = MuteRule::new(&query, rule.action),
+            None => {
+                self.config
+                    .mute_rules
+                    .push(MuteRule::new(&query, MuteAction::Hide));
+                self.selected_index = Some(self.config.mute_rules.len() - 1);
+            }
+        }
+        self.input_mode = InputMode::Normal;
+        self.editing_rule = None;
+        self.input_buffer.clear();
+        self.clear_error();
+        self.save_mute_rules();
+    }
 
-                // Apply unread filter if active
-                let matches_unread = !has_unread_filter || !self.read_items.contains(&item.id);
+    /// Adds a hiding mute rule from the command line (`:mute <query>`) and applies it.
+    /// Returns false if the query is invalid.
+    pub fn add_mute_rule(&mut self, query: &str) -> bool {
+        let query = query.trim();
+        match Query::parse(query) {
+            Ok(Some(_)) => {
+                self.config
+                    .mute_rules
+                    .push(MuteRule::new(query, MuteAction::Hide));
+                self.save_mute_rules();
+                self.refresh_filters();
+                self.status_message = Some(format!("Muted: {}", query));
+                true
+            }
+            Ok(None) => {
+                self.error_message = Some("Usage: :mute <query>".to_string());
+                false
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Invalid rule: {}", e));
+                false
+            }
+        }
+    }
 
-                matches_search && matches_unread
-            })
-            .map(|(i, _)| i)
-            .collect();
+    /// Switches the selected rule between hiding and marking as read
+    pub fn toggle_mute_rule_action(&mut self) {
+        if let Some(rule) = self
+            .selected_index
+            .and_then(|i| self.config.mute_rules.get_mut(i))
+        {
+            rule.action = match rule.action {
+                MuteAction::Hide => MuteAction::MarkRead,
+                MuteAction::MarkRead => MuteAction::Hide,
+            };
+            self.save_mute_rules();
+        }
+    }
 
-        self.scroll = 0;
-        if filtered.is_empty() {
-            self.selected_index = None;
-        } else {
-            self.selected_index = Some(0);
+    /// Deletes the selected mute rule
+    pub fn delete_mute_rule(&mut self) {
+        if let Some(index) = self.selected_index {
+            if index < self.config.mute_rules.len() {
+                let rule = self.config.mute_rules.remove(index);
+                info!("Deleted mute rule: {}", rule.query);
+                self.selected_index = if self.config.mute_rules.is_empty() {
+                    None
+                } else {
+                    Some(index.min(self.config.mute_rules.len() - 1))
+                };
+                self.save_mute_rules();
+            }
+        }
+    }
+
+    /// Returns how many of the loaded articles a rule matches
+    pub fn count_matches_for_rule(&self, rule: &MuteRule) -> usize {
+        match rule.parsed_query() {
+            Some(query) => self
+                .current_feed_content
+                .iter()
+                .filter(|item| query.matches(item, self))
+                .count(),
+            None => 0,
+        }
+    }
+
+    fn save_mute_rules(&mut self) {
+        if let Err(e) = self.save_config() {
+            error!("Failed to save mute rules: {}", e);
+            self.error_message = Some(format!("Failed to save mute rules: {}", e));
         }
-        self.filtered_indices = Some(filtered);
     }
 
     /// Toggles the unread-only filter
@@ -2401,7 +6630,14 @@ impl App {
     /// Clears all filters (search and unread-only) when pressing Esc
     pub fn clear_search(&mut self) {
         self.search_query.clear();
+        self.search_error = None;
         self.show_unread_only = false;
+        self.active_saved_search = None;
+        if self.active_tag.take().is_some() || self.search_everything {
+            // Leaving a tag view or a search of everything goes back to all articles
+            self.search_everything = false;
+            self.current_feed_content = self.all_cached_items();
+        }
         self.apply_filters();
     }
 
@@ -2439,8 +6675,7 @@ impl App {
     }
 
     fn get_cache_dir() -> PathBuf {
-        let mut path = dirs::cache_dir().unwrap_or_else(|| PathBuf::from("."));
-        path.push("reedy");
+        let mut path = paths::cache_dir();
         path.push("feed_cache");
         if let Err(e) = fs::create_dir_all(&path) {
             error!("Failed to create cache directory {:?}: {}", path, e);
@@ -2457,45 +6692,56 @@ impl App {
         path
     }
 
-    fn save_feed_cache(&self, url: &str, content: &[FeedItem]) -> AppResult<()> {
+    fn save_feed_cache(&mut self, url: &str, content: &[FeedItem]) -> AppResult<()> {
         let cache = CachedFeed {
             url: url.to_string(),
             content: content.to_vec(),
             last_updated: SystemTime::now(),
         };
         let cache_path = Self::get_cache_path(url);
-        let content = serde_json::to_string_pretty(&cache)?;
-        fs::write(cache_path, content)?;
+        let json = serde_json::to_string_pretty(&cache)?;
+        fs::write(cache_path, json)?;
+        self.set_cached_items(url, cache.content);
         Ok(())
     }
 
+    /// Keeps a feed's articles in memory and recounts them, as when its cache is written
+    pub fn set_cached_items(&mut self, url: &str, items: Vec<FeedItem>) {
+        let stats = self.count_items(&items);
+        self.feed_stats.insert(url.to_string(), stats);
+        self.cached_items.insert(url.to_string(), items);
+        self.saved_search_counts_stale = true;
+    }
+
     fn load_feed_cache(&self, url: &str) -> Option<Vec<FeedItem>> {
+        let cache = Self::read_feed_cache(url)?;
+        // Check if cache is within the configured duration
+        let cache_duration_secs = self.config.cache_duration_mins * 60;
+        match cache.last_updated.elapsed() {
+            Ok(duration) if duration.as_secs() < cache_duration_secs => Some(cache.content),
+            _ => None,
+        }
+    }
+
+    /// Reads a feed's cache file whatever its age, removing it if it is corrupted
+    fn read_feed_cache(url: &str) -> Option<CachedFeed> {
         let cache_path = Self::get_cache_path(url);
-        if let Ok(content) = fs::read_to_string(&cache_path) {
-            match serde_json::from_str::<CachedFeed>(&content) {
-                Ok(cache) => {
-                    // Check if cache is within the configured duration
-                    let cache_duration_secs = self.config.cache_duration_mins * 60;
-                    if let Ok(duration) = cache.last_updated.elapsed() {
-                        if duration.as_secs() < cache_duration_secs {
-                            return Some(cache.content);
-                        }
-                    }
-                }
-                Err(e) => {
-                    // Cache file is corrupted, delete it
-                    error!(
-                        "Failed to parse cache file for {}: {}. Removing corrupted cache.",
-                        url, e
-                    );
-                    match fs::remove_file(&cache_path) {
-                        Ok(_) => info!("Removed corrupted cache file for {}", url),
-                        Err(e) => error!("Failed to remove corrupted cache file: {}", e),
-                    }
+        let content = fs::read_to_string(&cache_path).ok()?;
+        match serde_json::from_str::<CachedFeed>(&content) {
+            Ok(cache) => Some(cache),
+            Err(e) => {
+                // Cache file is corrupted, delete it
+                error!(
+                    "Failed to parse cache file for {}: {}. Removing corrupted cache.",
+                    url, e
+                );
+                match fs::remove_file(&cache_path) {
+                    Ok(_) => info!("Removed corrupted cache file for {}", url),
+                    Err(e) => error!("Failed to remove corrupted cache file: {}", e),
                 }
+                None
             }
         }
-        None
     }
 
     /// Caches content from all configured RSS/Atom feeds.
@@ -2518,7 +6764,7 @@ impl App {
     /// - Feed parsing fails
     /// - Cache operations fail
     pub async fn cache_all_feeds(&mut self) {
-        for feed_info in self.rss_feeds.clone() {
+        for feed_info in self.rss_feeds.clone().into_iter().filter(|f| !f.paused) {
             debug!("Checking cache for URL: {}", feed_info.url);
 
             // Skip if already cached
@@ -2565,6 +6811,7 @@ impl App {
                         if let Err(e) = self.save_feed_cache(&feed_info.url, &feed_items) {
                             error!("Failed to cache feed content for {}: {}", feed_info.url, e);
                         }
+                        self.index_items(&feed_items);
                     }
                 }
                 Err(e) => {
@@ -2572,6 +6819,8 @@ impl App {
                 }
             }
         }
+        self.update_saved_search_counts();
+        self.save_search_index();
     }
 
     /// Refreshes all RSS/Atom feeds by fetching their latest content.
@@ -2591,14 +6840,19 @@ impl App {
     /// - Feed parsing fails
     /// - Cache operations fail
     pub async fn refresh_all_feeds(&mut self) -> AppResult<()> {
-        self.refresh_all_feeds_impl(false).await
+        self.refresh_all_feeds_impl(false, None).await
     }
 
     pub async fn force_refresh_all_feeds(&mut self) -> AppResult<()> {
-        self.refresh_all_feeds_impl(true).await
+        self.refresh_all_feeds_impl(true, None).await
     }
 
-    async fn refresh_all_feeds_impl(&mut self, force: bool) -> AppResult<()> {
+    /// With `only`, forcing applies to those feed URLs and the rest are read from the cache
+    async fn refresh_all_feeds_impl(
+        &mut self,
+        force: bool,
+        only: Option<&HashSet<String>>,
+    ) -> AppResult<()> {
         use std::time::Instant;
 
         let mut all_items = Vec::new();
@@ -2609,6 +6863,18 @@ impl App {
         let feeds: Vec<FeedInfo> = self.rss_feeds.clone();
 
         for feed_info in feeds {
+            // Paused feeds and feeds left out of a refresh of marked feeds are not fetched,
+            // but their cached articles stay listed
+            if feed_info.paused || only.is_some_and(|urls| !urls.contains(&feed_info.url)) {
+                all_items.extend(
+                    self.cached_items
+                        .get(&feed_info.url)
+                        .cloned()
+                        .unwrap_or_default(),
+                );
+                continue;
+            }
+
             // Check cache first unless forcing refresh
             if !force {
                 if let Some(cached_items) = self.load_feed_cache(&feed_info.url) {
@@ -2743,18 +7009,24 @@ impl App {
         }
 
         // Sort all items by date, newest first
-        all_items.sort_by(|a, b| b.published.cmp(&a.published));
+        all_items.sort_by_key(|item| std::cmp::Reverse(item.published));
 
-        // Check for new items and send notifications if enabled
-        if self.config.notifications_enabled {
-            let new_items: Vec<&FeedItem> = all_items
-                .iter()
-                .filter(|item| !self.seen_items.contains(&item.id))
-                .collect();
+        // Mark items matching "mark read" mute rules as read
+        self.apply_mute_rules(&all_items);
 
-            if !new_items.is_empty() {
-                self.send_new_articles_notification(&new_items);
-            }
+        // Check for new items and send notifications if enabled (muted items never notify)
+        let new_items: Vec<&FeedItem> = all_items
+            .iter()
+            .filter(|item| !self.seen_items.contains(&item.id))
+            .filter(|item| !self.is_item_muted(item))
+            .collect();
+        if self.config.notifications_enabled && !new_items.is_empty() {
+            self.send_new_articles_notification(&new_items);
+        }
+
+        // Highlight rules with `notify` alert on their own, regardless of notifications_enabled
+        for (rule, matches) in self.alerts_for_new_items(&new_items) {
+            self.send_alert_notification(rule, &matches);
         }
 
         // Update seen items with all current item IDs
@@ -2762,8 +7034,32 @@ impl App {
             self.seen_items.insert(item.id.clone());
         }
 
-        // Update the current feed content
-        self.current_feed_content = all_items;
+        // Regenerate any published Atom feeds from the fresh content
+        if !self.config.publish.is_empty() {
+            self.publish_feeds(&all_items);
+        }
+
+        // Index the articles so they stay searchable after leaving their feeds
+        self.index_items(&all_items);
+        self.save_search_index();
+
+        // Keep the saved copies of tagged articles up to date
+        for item in &all_items {
+            if let Some(tagged) = self.tagged_items.get_mut(&item.id) {
+                tagged.item = item.clone();
+            }
+        }
+
+        self.update_saved_search_counts();
+
+        // Update the current feed content (a tag view keeps showing its tagged articles)
+        self.loaded_feed = None;
+        self.current_feed_content = match &self.active_tag {
+            Some(tag) => self.items_with_tag(tag),
+            None if self.search_everything => self.everything_results(),
+            None => all_items,
+        };
+        self.refresh_filters();
 
         Ok(())
     }
@@ -2779,19 +7075,7 @@ impl App {
             format!("{} new articles", count)
         };
 
-        // Build body with up to 3 article titles
-        let body: String = new_items
-            .iter()
-            .take(3)
-            .map(|item| format!("• {}", item.title))
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        let body_with_more = if count > 3 {
-            format!("{}\n...and {} more", body, count - 3)
-        } else {
-            body
-        };
+        let body_with_more = notification_body(new_items);
 
         if let Err(e) = Notification::new()
             .summary(&summary)
@@ -2806,6 +7090,28 @@ impl App {
         }
     }
 
+    /// Sends a desktop notification for new articles matching an alerting highlight rule
+    fn send_alert_notification(&self, rule: &HighlightRule, matches: &[&FeedItem]) {
+        use notify_rust::Notification;
+
+        let summary = format!("Reedy alert: {} ({})", rule.display_name(), matches.len());
+        if let Err(e) = Notification::new()
+            .summary(&summary)
+            .body(&notification_body(matches))
+            .appname("Reedy")
+            .timeout(10000)
+            .show()
+        {
+            error!("Failed to send alert notification: {}", e);
+        } else {
+            info!(
+                "Sent alert for rule '{}' ({} article(s))",
+                rule.display_name(),
+                matches.len()
+            );
+        }
+    }
+
     pub fn mark_as_read(&mut self) {
         if let Some(visible_index) = self.selected_index {
             if let Some(actual_index) = self.get_actual_index(visible_index) {
@@ -2813,6 +7119,8 @@ impl App {
                     if !self.read_items.contains(&item.id) {
                         self.read_items.insert(item.id.clone());
                         debug!("Marked item as read: {}", item.title);
+                        let item = item.clone();
+                        self.recount_feed_of(&item);
                         self.save_state().unwrap_or_else(|e| {
                             error!("Failed to save read status: {}", e);
                         });
@@ -2824,34 +7132,559 @@ impl App {
 
     pub fn mark_all_as_read(&mut self) {
         // Get items to mark - either filtered items or all items
-        let items_to_mark: Vec<String> = match &self.filtered_indices {
+        let items_to_mark: Vec<(String, String)> = match &self.filtered_indices {
             Some(indices) => indices
                 .iter()
                 .filter_map(|&i| self.current_feed_content.get(i))
                 .filter(|item| !self.read_items.contains(&item.id))
-                .map(|item| item.id.clone())
+                .map(|item| (item.id.clone(), item.feed_url.clone()))
                 .collect(),
             None => self
                 .current_feed_content
                 .iter()
                 .filter(|item| !self.read_items.contains(&item.id))
-                .map(|item| item.id.clone())
+                .map(|item| (item.id.clone(), item.feed_url.clone()))
                 .collect(),
         };
 
-        for id in items_to_mark {
-            self.read_items.insert(id.clone());
+        let mut urls = HashSet::new();
+        for (id, url) in items_to_mark {
             debug!("Marked item as read: {}", id);
+            self.read_items.insert(id);
+            urls.insert(url);
+        }
+        self.recount_feeds(&urls);
+        self.save_state().unwrap_or_else(|e| {
+            error!("Failed to save read status: {}", e);
+        });
+    }
+
+    /// Marks the selected article for bulk actions, or unmarks it, and moves down a row
+    pub fn toggle_mark_selected_item(&mut self) {
+        let Some(item) = self
+            .selected_index
+            .and_then(|i| self.get_actual_index(i))
+            .and_then(|i| self.current_feed_content.get(i))
+        else {
+            return;
+        };
+        if !self.marked_items.remove(&item.id) {
+            self.marked_items.insert(item.id.clone());
+        }
+        self.select_next();
+    }
+
+    /// Starts a visual range of articles at the selection, or ends it and marks the articles
+    /// it covered
+    pub fn toggle_item_visual(&mut self) {
+        if self.item_visual_start.is_some() {
+            self.commit_item_visual();
+        } else {
+            self.item_visual_start = self
+                .selected_index
+                .and_then(|i| self.get_actual_index(i))
+                .and_then(|i| self.current_feed_content.get(i))
+                .map(|item| item.id.clone());
+        }
+    }
+
+    /// IDs of the articles covered by the visual range
+    pub fn visual_item_ids(&self) -> HashSet<String> {
+        let (Some(anchor), Some(end)) = (&self.item_visual_start, self.selected_index) else {
+            return HashSet::new();
+        };
+        let visible = self.get_visible_items();
+        let Some(start) = visible.iter().position(|(_, item)| &item.id == anchor) else {
+            return HashSet::new();
+        };
+        visible
+            .iter()
+            .take(start.max(end) + 1)
+            .skip(start.min(end))
+            .map(|(_, item)| item.id.clone())
+            .collect()
+    }
+
+    /// True if the marked article with this ID (or in the visual range) takes bulk actions
+    pub fn is_item_marked(&self, id: &str, visual: &HashSet<String>) -> bool {
+        self.marked_items.contains(id) || visual.contains(id)
+    }
+
+    /// Ends the visual range of articles, adding them to the marked ones
+    fn commit_item_visual(&mut self) {
+        let ids = self.visual_item_ids();
+        self.marked_items.extend(ids);
+        self.item_visual_start = None;
+    }
+
+    /// True if article actions apply to the marked articles instead of the selection,
+    /// which takes a visual range or a marked article in the list
+    pub fn has_marked_items(&self) -> bool {
+        self.item_visual_start.is_some()
+            || (!self.marked_items.is_empty()
+                && self
+                    .get_visible_items()
+                    .iter()
+                    .any(|(_, item)| self.marked_items.contains(&item.id)))
+    }
+
+    /// Number of listed articles that bulk actions apply to, including the visual range
+    pub fn marked_item_count(&self) -> usize {
+        let visual = self.visual_item_ids();
+        self.get_visible_items()
+            .iter()
+            .filter(|(_, item)| self.is_item_marked(&item.id, &visual))
+            .count()
+    }
+
+    /// Forgets the marks of articles that are no longer in the list, and a visual range
+    /// whose first article is gone
+    fn drop_stale_item_marks(&mut self) {
+        if self.marked_items.is_empty() && self.item_visual_start.is_none() {
+            return;
+        }
+        let listed: HashSet<&str> = self
+            .current_feed_content
+            .iter()
+            .map(|item| item.id.as_str())
+            .collect();
+        self.marked_items.retain(|id| listed.contains(id.as_str()));
+        if self
+            .item_visual_start
+            .as_ref()
+            .is_some_and(|id| !listed.contains(id.as_str()))
+        {
+            self.item_visual_start = None;
+        }
+    }
+
+    /// Unmarks every article and leaves visual mode
+    pub fn clear_item_marks(&mut self) {
+        self.marked_items.clear();
+        self.item_visual_start = None;
+    }
+
+    /// Ends any visual range and returns the listed marked articles in list order
+    fn take_marked_items(&mut self) -> Vec<FeedItem> {
+        self.commit_item_visual();
+        self.get_visible_items()
+            .into_iter()
+            .filter(|(_, item)| self.marked_items.contains(&item.id))
+            .map(|(_, item)| item.clone())
+            .collect()
+    }
+
+    /// Marks the marked articles read, or unread if every one of them is read already
+    pub fn toggle_read_marked_items(&mut self) {
+        let marked = self.take_marked_items();
+        let read = marked.iter().any(|item| !self.is_item_read(item));
+        self.set_marked_items_read(&marked, read);
+    }
+
+    /// Marks the marked articles read
+    pub fn mark_marked_items_read(&mut self) {
+        let marked = self.take_marked_items();
+        self.set_marked_items_read(&marked, true);
+    }
+
+    fn set_marked_items_read(&mut self, items: &[FeedItem], read: bool) {
+        for item in items {
+            if read {
+                self.read_items.insert(item.id.clone());
+            } else {
+                self.read_items.remove(&item.id);
+            }
         }
+        self.recount_feeds(&items.iter().map(|item| item.feed_url.clone()).collect());
+        debug!(
+            "Marked {} articles {}",
+            items.len(),
+            if read { "read" } else { "unread" }
+        );
+        self.status_message = Some(format!(
+            "Marked {} articles {}",
+            items.len(),
+            if read { "read" } else { "unread" }
+        ));
         self.save_state().unwrap_or_else(|e| {
             error!("Failed to save read status: {}", e);
         });
     }
 
+    /// Adds the marked articles to favorites, or removes them if all are favorites already
+    pub fn toggle_favorite_marked_items(&mut self) {
+        let marked = self.take_marked_items();
+        let favorite = marked.iter().any(|item| !self.is_item_favorite(item));
+        for item in &marked {
+            if favorite {
+                self.favorites.insert(item.id.clone());
+            } else {
+                self.favorites.remove(&item.id);
+            }
+        }
+        self.saved_search_counts_stale = true;
+        self.status_message = Some(format!(
+            "{} {} articles {} favorites",
+            if favorite { "Added" } else { "Removed" },
+            marked.len(),
+            if favorite { "to" } else { "from" }
+        ));
+        self.save_state().unwrap_or_else(|e| {
+            error!("Failed to save favorites: {}", e);
+        });
+
+        // The favorites view drops the articles it no longer holds
+        if !favorite && self.page_mode == PageMode::Favorites {
+            self.current_feed_content
+                .retain(|item| self.favorites.contains(&item.id));
+            self.apply_filters();
+            let visible_count = self.visible_item_count();
+            self.selected_index = if visible_count == 0 {
+                None
+            } else {
+                self.selected_index.map(|i| i.min(visible_count - 1))
+            };
+        }
+    }
+
+    /// Opens every marked article. Articles that need a foreground opener are skipped,
+    /// since only one program can have the terminal.
+    pub fn open_marked_items(&mut self) {
+        let marked = self.take_marked_items();
+        let skipped = marked
+            .iter()
+            .filter(|item| !self.open_item(item, false))
+            .count();
+        self.status_message = Some(if skipped > 0 {
+            format!(
+                "Opened {} articles ({} need a terminal opener; open them one at a time)",
+                marked.len() - skipped,
+                skipped
+            )
+        } else {
+            format!("Opened {} articles", marked.len())
+        });
+    }
+
+    /// The marked articles as one markdown document, separated by rules
+    fn marked_items_markdown(&mut self) -> (usize, String) {
+        let marked = self.take_marked_items();
+        let content = marked
+            .iter()
+            .map(|item| self.format_article_markdown(item))
+            .collect::<Vec<_>>()
+            .join("\n\n---\n\n");
+        (marked.len(), content)
+    }
+
+    /// Copies the marked articles to the clipboard as markdown using OSC 52
+    pub fn export_marked_items_to_clipboard(&mut self) {
+        let (count, content) = self.marked_items_markdown();
+        match copy_to_clipboard_osc52(&content) {
+            Ok(()) => {
+                info!("Exported {} articles to clipboard", count);
+                self.status_message = Some(format!("{} articles copied!", count));
+            }
+            Err(e) => {
+                error!("Failed to copy to clipboard: {}", e);
+                self.error_message = Some(format!("Failed to copy: {}", e));
+            }
+        }
+    }
+
+    /// Saves the marked articles to one markdown file in the exports directory
+    pub fn export_marked_items_to_file(&mut self) {
+        let (_, content) = self.marked_items_markdown();
+        self.save_export("articles", &content);
+    }
+
     pub fn is_item_favorite(&self, item: &FeedItem) -> bool {
         self.favorites.contains(&item.id)
     }
 
+    /// Marks an item as read or unread by its ID and persists the change
+    pub fn set_item_read(&mut self, id: &str, read: bool) {
+        let changed = if read {
+            self.read_items.insert(id.to_string())
+        } else {
+            self.read_items.remove(id)
+        };
+        if changed {
+            debug!(
+                "Marked item {} as {}",
+                id,
+                if read { "read" } else { "unread" }
+            );
+            match self.feed_url_of(id) {
+                Some(url) => self.recount_feeds(&HashSet::from([url])),
+                None => self.saved_search_counts_stale = true,
+            }
+            self.save_state().unwrap_or_else(|e| {
+                error!("Failed to save read status: {}", e);
+            });
+        }
+    }
+
+    /// Adds an item to the favorites or removes it by its ID and persists the change
+    pub fn set_item_favorite(&mut self, id: &str, favorite: bool) {
+        let changed = if favorite {
+            self.favorites.insert(id.to_string())
+        } else {
+            self.favorites.remove(id)
+        };
+        if changed {
+            debug!(
+                "{} favorite {}",
+                if favorite { "Added" } else { "Removed" },
+                id
+            );
+            self.saved_search_counts_stale = true;
+            self.save_state().unwrap_or_else(|e| {
+                error!("Failed to save favorites: {}", e);
+            });
+        }
+    }
+
+    /// Returns the user tags of an article, alphabetically
+    pub fn item_tags<'a>(&'a self, id: &str) -> impl Iterator<Item = &'a str> + 'a {
+        self.tagged_items
+            .get(id)
+            .into_iter()
+            .flat_map(|tagged| tagged.tags.iter().map(String::as_str))
+    }
+
+    /// Returns every tag in use with its number of articles, alphabetically
+    pub fn all_tags(&self) -> Vec<(String, usize)> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for tagged in self.tagged_items.values() {
+            for tag in &tagged.tags {
+                *counts.entry(tag.as_str()).or_default() += 1;
+            }
+        }
+        let mut tags: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(tag, count)| (tag.to_string(), count))
+            .collect();
+        tags.sort();
+        tags
+    }
+
+    /// Returns the saved copies of all tagged articles, newest first
+    pub fn tagged_snapshots(&self) -> Vec<FeedItem> {
+        let mut items: Vec<FeedItem> = self
+            .tagged_items
+            .values()
+            .map(|tagged| tagged.item.clone())
+            .collect();
+        items.sort_by_key(|item| std::cmp::Reverse(item.published));
+        items
+    }
+
+    /// Returns the favorite articles, newest first: every cached one, plus the saved copies
+    /// of tagged favorites that have left their feed
+    pub fn favorite_items(&self) -> Vec<FeedItem> {
+        let mut items: Vec<FeedItem> = self
+            .all_cached_items()
+            .into_iter()
+            .filter(|item| self.favorites.contains(&item.id))
+            .collect();
+        let listed: HashSet<String> = items.iter().map(|item| item.id.clone()).collect();
+        items.extend(
+            self.tagged_items
+                .values()
+                .filter(|tagged| {
+                    self.favorites.contains(&tagged.item.id) && !listed.contains(&tagged.item.id)
+                })
+                .map(|tagged| tagged.item.clone()),
+        );
+        items.sort_by_key(|item| std::cmp::Reverse(item.published));
+        items
+    }
+
+    /// Returns the saved copies of the articles carrying a tag, newest first
+    pub fn items_with_tag(&self, tag: &str) -> Vec<FeedItem> {
+        let mut items: Vec<FeedItem> = self
+            .tagged_items
+            .values()
+            .filter(|tagged| tagged.tags.contains(tag))
+            .map(|tagged| tagged.item.clone())
+            .collect();
+        items.sort_by_key(|item| std::cmp::Reverse(item.published));
+        items
+    }
+
+    /// Adds (`tag`) and removes (`-tag`) tags on an article. Tags are separated by
+    /// commas or spaces. Returns false if the input contains no tags.
+    pub fn apply_tag_input(&mut self, item: &FeedItem, input: &str) -> bool {
+        let mut changed = false;
+        for word in input.split(|c: char| c == ',' || c.is_whitespace()) {
+            let (remove, name) = match word.strip_prefix('-') {
+                Some(name) => (true, name),
+                None => (false, word),
+            };
+            let tag = normalize_tag(name);
+            if tag.is_empty() {
+                continue;
+            }
+            changed = true;
+            if remove {
+                if let Some(tagged) = self.tagged_items.get_mut(&item.id) {
+                    tagged.tags.remove(&tag);
+                    if tagged.tags.is_empty() {
+                        self.tagged_items.remove(&item.id);
+                    }
+                }
+            } else {
+                let tagged =
+                    self.tagged_items
+                        .entry(item.id.clone())
+                        .or_insert_with(|| TaggedItem {
+                            item: item.clone(),
+                            tags: BTreeSet::new(),
+                        });
+                tagged.item = item.clone();
+                tagged.tags.insert(tag);
+            }
+        }
+        if changed {
+            debug!("Updated tags for item: {}", item.title);
+            self.saved_search_counts_stale = true;
+            if let Err(e) = self.save_state() {
+                error!("Failed to save tags: {}", e);
+            }
+        }
+        changed
+    }
+
+    /// Opens the tag picker for the selected article
+    pub fn start_tagging(&mut self) {
+        if self.get_preview_item().is_none() {
+            return;
+        }
+        self.tagging_from_preview = self.input_mode == InputMode::Preview;
+        if !self.tagging_from_preview {
+            // The picker names how many articles it tags, so the visual range ends here
+            self.commit_item_visual();
+        }
+        self.input_mode = InputMode::Tagging;
+        self.input_buffer.clear();
+    }
+
+    /// Known tags starting with the word being typed in the tag picker
+    pub fn tag_suggestions(&self) -> Vec<String> {
+        let word = self
+            .input_buffer
+            .rsplit(|c: char| c == ',' || c.is_whitespace())
+            .next()
+            .unwrap_or("");
+        let prefix = normalize_tag(word.trim_start_matches('-'));
+        self.all_tags()
+            .into_iter()
+            .map(|(tag, _)| tag)
+            .filter(|tag| tag.starts_with(&prefix))
+            .collect()
+    }
+
+    /// Completes the word being typed in the tag picker to the longest common prefix
+    /// of the matching tags (the whole tag if only one matches)
+    pub fn complete_tag(&mut self) {
+        let suggestions = self.tag_suggestions();
+        if suggestions.is_empty() {
+            return;
+        }
+        let common = common_prefix(&suggestions);
+
+        let word_start = self
+            .input_buffer
+            .rfind(|c: char| c == ',' || c.is_whitespace())
+            .map_or(0, |i| i + 1);
+        let negated = self.input_buffer[word_start..].starts_with('-');
+        self.input_buffer.truncate(word_start);
+        if negated {
+            self.input_buffer.push('-');
+        }
+        self.input_buffer.push_str(&common);
+        if suggestions.len() == 1 {
+            self.input_buffer.push_str(", ");
+        }
+    }
+
+    /// Applies the tags typed into the picker to the selected article
+    pub fn confirm_tagging(&mut self) {
+        let input = std::mem::take(&mut self.input_buffer);
+        // Tagging from the list applies to every marked article
+        let marked = if self.tagging_from_preview {
+            Vec::new()
+        } else {
+            self.take_marked_items()
+        };
+        if !marked.is_empty() {
+            let mut changed = false;
+            for item in &marked {
+                changed |= self.apply_tag_input(item, &input);
+            }
+            if changed {
+                self.status_message = Some(format!("Tagged {} articles: {}", marked.len(), input));
+            }
+        } else if let Some(item) = self.get_preview_item().cloned() {
+            if self.apply_tag_input(&item, &input) {
+                let tags: Vec<&str> = self.item_tags(&item.id).collect();
+                self.status_message = Some(if tags.is_empty() {
+                    "Tags removed".to_string()
+                } else {
+                    format!("Tags: {}", tags.join(", "))
+                });
+            }
+        }
+        self.cancel_tagging();
+    }
+
+    /// Closes the tag picker, returning to the list or preview
+    pub fn cancel_tagging(&mut self) {
+        self.input_buffer.clear();
+        self.input_mode = if self.tagging_from_preview {
+            InputMode::Preview
+        } else {
+            InputMode::Normal
+        };
+        self.tagging_from_preview = false;
+    }
+
+    /// Shows the articles carrying a tag in the feed list, including ones that left their feed.
+    /// Returns false if no article has the tag.
+    pub fn open_tag_view(&mut self, tag: &str) -> bool {
+        let tag = normalize_tag(tag);
+        let items = self.items_with_tag(&tag);
+        if items.is_empty() {
+            self.error_message = Some(format!("No articles tagged '{}'", tag));
+            return false;
+        }
+        debug!("Opening tag view '{}'", tag);
+        self.page_mode = PageMode::FeedList;
+        self.input_mode = InputMode::Normal;
+        self.search_query.clear();
+        self.show_unread_only = false;
+        self.active_saved_search = None;
+        self.current_feed_content = items;
+        self.active_tag = Some(tag);
+        self.apply_filters();
+        true
+    }
+
+    /// Lists the tags in use in the status line
+    fn show_tags(&mut self) {
+        let tags = self.all_tags();
+        self.status_message = Some(if tags.is_empty() {
+            "No tags yet (press t on an article to tag it)".to_string()
+        } else {
+            let list: Vec<String> = tags
+                .iter()
+                .map(|(tag, count)| format!("{} ({})", tag, count))
+                .collect();
+            format!("Tags: {} - :tag <name> to view", list.join(", "))
+        });
+    }
+
     pub fn toggle_favorite(&mut self) {
         if let Some(visible_index) = self.selected_index {
             if let Some(actual_index) = self.get_actual_index(visible_index) {
@@ -2864,6 +7697,7 @@ impl App {
                         self.favorites.insert(item.id.clone());
                         debug!("Added item to favorites: {}", item.title);
                     }
+                    self.saved_search_counts_stale = true;
                     self.save_state().unwrap_or_else(|e| {
                         error!("Failed to save favorites: {}", e);
                     });
@@ -2887,6 +7721,7 @@ impl App {
     }
 
     pub async fn toggle_favorites_page(&mut self) {
+        self.clear_item_marks();
         match self.page_mode {
             PageMode::Favorites => {
                 self.page_mode = PageMode::FeedList;
@@ -2906,22 +7741,195 @@ impl App {
                 // Reset scroll position
                 self.scroll = 0;
 
-                // Filter current feed content to show only favorites
-                let favorites: Vec<FeedItem> = self
-                    .current_feed_content
-                    .iter()
-                    .filter(|item| self.favorites.contains(&item.id))
-                    .cloned()
-                    .collect();
-                self.current_feed_content = favorites;
-                self.selected_index = if self.current_feed_content.is_empty() {
-                    None
-                } else {
-                    Some(0)
-                };
+                self.current_feed_content = self.favorite_items();
+                self.selected_index = None;
+                self.refresh_filters();
+            }
+        }
+    }
+}
+
+/// Splits an OPML `category` attribute ("/Tech/Rust,news,rust") into a category path,
+/// from entries starting with '/', and tags, from the rest
+fn parse_opml_category_attr(attr: Option<&str>) -> (Option<String>, Vec<String>) {
+    let mut path = None;
+    let mut tags: Vec<String> = Vec::new();
+    for entry in attr.unwrap_or_default().split(',').map(str::trim) {
+        if entry.starts_with('/') {
+            path = path.or_else(|| normalize_category_path(entry));
+        } else {
+            let tag = normalize_tag(entry);
+            if !tag.is_empty() && !tags.contains(&tag) {
+                tags.push(tag);
             }
         }
     }
+    (path, tags)
+}
+
+/// Normalizes a category path: parts are trimmed and empty parts dropped,
+/// so " Tech / Rust/" becomes "Tech/Rust". Returns None for an empty path.
+fn normalize_category_path(path: &str) -> Option<String> {
+    let parts: Vec<&str> = path
+        .split('/')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect();
+    (!parts.is_empty()).then(|| parts.join("/"))
+}
+
+/// Returns true if `category` is `path` or nested below it. Paths are compared as
+/// written, like the categories of the Feed Manager tree.
+pub(crate) fn category_in_path(category: &str, path: &str) -> bool {
+    category == path
+        || category
+            .strip_prefix(path)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Normalizes a tag name: trimmed, lower case, without a leading '#'
+fn normalize_tag(tag: &str) -> String {
+    tag.trim().trim_start_matches('#').to_lowercase()
+}
+
+/// Builds a notification body listing up to 3 article titles
+fn notification_body(items: &[&FeedItem]) -> String {
+    let body: String = items
+        .iter()
+        .take(3)
+        .map(|item| format!("• {}", item.title))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if items.len() > 3 {
+        format!("{}\n...and {} more", body, items.len() - 3)
+    } else {
+        body
+    }
+}
+
+/// Writes `<name>text</name>` with the text escaped
+fn write_text_element<W: Write>(writer: &mut Writer<W>, name: &str, text: &str) -> AppResult<()> {
+    writer.write_event(Event::Start(BytesStart::new(name)))?;
+    writer.write_event(Event::Text(BytesText::new(text)))?;
+    writer.write_event(Event::End(BytesEnd::new(name)))?;
+    Ok(())
+}
+
+/// Longest prefix shared by all strings, ignoring case (taken from the first string)
+fn common_prefix(strings: &[String]) -> String {
+    let Some(first) = strings.first() else {
+        return String::new();
+    };
+    let len = strings
+        .iter()
+        .skip(1)
+        .fold(first.chars().count(), |len, s| {
+            first
+                .chars()
+                .zip(s.chars())
+                .take(len)
+                .take_while(|(a, b)| a.to_lowercase().eq(b.to_lowercase()))
+                .count()
+        });
+    first.chars().take(len).collect()
+}
+
+/// Usage line of a command that takes arguments
+fn command_usage(command: &str) -> &'static str {
+    match command {
+        "add" => ":add <url> [category]",
+        "rename" => ":rename <title>",
+        "open" => ":open <n>",
+        "export" => ":export json|opml <path>",
+        _ => ":help",
+    }
+}
+
+/// The feed index next to `current` in `siblings`, one place up or down
+fn sibling_target(siblings: &[usize], current: usize, down: bool) -> Option<usize> {
+    let position = siblings.iter().position(|&s| s == current)?;
+    let target = if down {
+        position + 1
+    } else {
+        position.checked_sub(1)?
+    };
+    siblings.get(target).copied()
+}
+
+/// Hash of a feed URL, part of the IDs of the feed's articles
+fn url_hash(feed_url: &str) -> u64 {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    feed_url.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The ID an article of a feed gets when the feed moves from `old_url` to `new_url`.
+/// IDs of other feeds' articles are returned unchanged.
+fn moved_item_id(id: &str, old_url: &str, new_url: &str) -> String {
+    let old = format!("_{:x}", url_hash(old_url));
+    let new = format!("_{:x}", url_hash(new_url));
+    // The hash follows the title slug and is either last or followed by the timestamp
+    match id.rfind(&format!("{}_", old)) {
+        Some(pos) => format!("{}{}{}", &id[..pos], new, &id[pos + old.len()..]),
+        None if id.ends_with(&old) => format!("{}{}", &id[..id.len() - old.len()], new),
+        None => id.to_string(),
+    }
+}
+
+/// Expands a leading "~/" in a user-supplied path to the home directory
+fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .map(|home| home.join(rest))
+            .unwrap_or_else(|| PathBuf::from(path)),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Builds a command from the first set environment variable (e.g. "less -R" in $PAGER),
+/// falling back to the given program, with extra arguments appended
+fn command_from_env(
+    vars: &[&str],
+    fallback: &str,
+    extra_args: &[String],
+) -> Option<ExternalCommand> {
+    let command_line = vars
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| fallback.to_string());
+    let mut words = shell_words::split(&command_line).ok()?;
+    if words.is_empty() {
+        return None;
+    }
+    let program = words.remove(0);
+    words.extend_from_slice(extra_args);
+    Some(ExternalCommand {
+        program,
+        args: words,
+        ..Default::default()
+    })
+}
+
+/// Expands `$VAR` and `${VAR}` references to environment variables (unset variables become empty)
+fn expand_env_vars(text: &str) -> String {
+    static ENV_VAR: LazyLock<regex::Regex> = LazyLock::new(|| {
+        regex::Regex::new(r"\$(?:\{([A-Za-z_][A-Za-z0-9_]*)\}|([A-Za-z_][A-Za-z0-9_]*))")
+            .expect("valid env var pattern")
+    });
+    ENV_VAR
+        .replace_all(text, |caps: &regex::Captures| {
+            let name = caps
+                .get(1)
+                .or_else(|| caps.get(2))
+                .map_or("", |m| m.as_str());
+            std::env::var(name).unwrap_or_default()
+        })
+        .into_owned()
 }
 
 pub async fn fetch_feed(url: &str, timeout_secs: Option<u64>) -> AppResult<Vec<FeedItem>> {
@@ -2986,11 +7994,21 @@ fn convert_rss_items(channel: Channel, feed_title: &str, feed_url: &str) -> Vec<
                 published,
                 id: App::create_item_id(item.title().unwrap_or("No title"), published, feed_url),
                 feed_url: feed_url.to_string(),
+                enclosure: item.enclosure().map(|e| e.url().to_string()),
             }
         })
         .collect()
 }
 
+/// Returns the href of the first rel="enclosure" link of an Atom entry
+fn atom_enclosure(entry: &atom_syndication::Entry) -> Option<String> {
+    entry
+        .links()
+        .iter()
+        .find(|l| l.rel() == "enclosure")
+        .map(|l| l.href().to_string())
+}
+
 fn convert_atom_items(feed: AtomFeed, feed_title: &str, feed_url: &str) -> Vec<FeedItem> {
     feed.entries()
         .iter()
@@ -3018,6 +8036,7 @@ fn convert_atom_items(feed: AtomFeed, feed_title: &str, feed_url: &str) -> Vec<F
                 published,
                 id: App::create_item_id(&entry.title().value, published, feed_url),
                 feed_url: feed_url.to_string(),
+                enclosure: atom_enclosure(entry),
             }
         })
         .collect()
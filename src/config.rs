@@ -0,0 +1,314 @@
+use std::fmt;
+use std::path::Path;
+
+use toml_edit::{DocumentMut, Item, Table};
+
+use crate::app::{AppResult, Config, SETTINGS};
+
+/// Examples written as comments above the empty lists of a generated config.toml
+const LIST_EXAMPLES: &[(&str, &str)] = &[
+    (
+        "publish",
+        r#"publish = [
+  { title = "Favorites", path = "~/public/favorites.xml", source = "favorites" },
+  { title = "Rust", path = "~/public/rust.xml", source = { category = "Rust" }, max_items = 20 },
+]"#,
+    ),
+    (
+        "openers",
+        r#"openers = [
+  { pattern = "youtube\\.com|\\.mp4$", command = "mpv {url}" },
+  { pattern = "\\.pdf$", command = "zathura {url}" },
+  { pattern = "^https://lwn\\.net/", command = "w3m {url}", foreground = true },
+]"#,
+    ),
+    (
+        "saved_searches",
+        r#"saved_searches = [
+  { name = "Rust unread", query = "cat:rust", unread_only = true },
+]"#,
+    ),
+    (
+        "mute_rules",
+        r#"mute_rules = [
+  { query = "title:/sponsored/" },
+  { query = "feed:\"Weekly\" domain:ads.example.com", action = "mark_read" },
+]"#,
+    ),
+    (
+        "highlight_rules",
+        r#"highlight_rules = [
+  { query = "title:/CVE-\\d{4}-\\d+/", name = "CVE", color = "light_red", pin = true, notify = true },
+]"#,
+    ),
+];
+
+/// File format of a config file, chosen by its extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Json,
+    Toml,
+}
+
+impl ConfigFormat {
+    /// TOML for `.toml` files, JSON otherwise
+    pub fn of(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => ConfigFormat::Toml,
+            _ => ConfigFormat::Json,
+        }
+    }
+}
+
+/// A problem found in a config file, with the 1-based line it was found on if known
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigProblem {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Parses a config file's contents without validating the values
+pub fn parse_config(contents: &str, format: ConfigFormat) -> Result<Config, ConfigProblem> {
+    match format {
+        ConfigFormat::Json => serde_json::from_str(contents).map_err(|e| ConfigProblem {
+            line: Some(e.line()),
+            message: e.to_string(),
+        }),
+        ConfigFormat::Toml => toml::from_str(contents).map_err(|e| ConfigProblem {
+            line: e
+                .span()
+                .map(|span| contents[..span.start].matches('\n').count() + 1),
+            message: e.message().to_string(),
+        }),
+    }
+}
+
+/// Parses and validates a config file's contents, returning every problem found.
+/// Validation problems are located on the line of the setting they concern.
+pub fn check_config(contents: &str, format: ConfigFormat) -> Vec<ConfigProblem> {
+    let config = match parse_config(contents, format) {
+        Ok(config) => config,
+        Err(problem) => return vec![problem],
+    };
+    match config.validate() {
+        Ok(()) => Vec::new(),
+        Err(problems) => problems
+            .into_iter()
+            .map(|message| {
+                let key = message.split([' ', ':']).next().unwrap_or_default();
+                ConfigProblem {
+                    line: key_line(contents, key),
+                    message,
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Finds the line of a dotted key such as "theme.primary" in a JSON or TOML file: each
+/// part of the key is looked for after the line where the previous part was found
+fn key_line(contents: &str, key: &str) -> Option<usize> {
+    let lines: Vec<&str> = contents.lines().collect();
+    let mut line = 0;
+    for part in key.split('.') {
+        line += lines[line..]
+            .iter()
+            .position(|text| line_has_key(text, part))?;
+    }
+    Some(line + 1)
+}
+
+/// True if a line starts with the given key, as `key =`, `"key":` or a `[key]` header
+fn line_has_key(line: &str, key: &str) -> bool {
+    line.trim_start()
+        .trim_start_matches('[')
+        .trim_start_matches('"')
+        .strip_prefix(key)
+        .is_some_and(|rest| rest.starts_with(['"', ' ', '=', ']', ':']))
+}
+
+/// Serializes a config for writing to a file. TOML files are updated in place from their
+/// current contents so comments and layout are kept; keys the file leaves out stay out
+/// while they hold their default value.
+pub fn serialize_config(
+    config: &Config,
+    format: ConfigFormat,
+    existing: Option<&str>,
+) -> AppResult<String> {
+    match format {
+        ConfigFormat::Json => Ok(serde_json::to_string_pretty(config)?),
+        ConfigFormat::Toml => {
+            let mut updated: DocumentMut = toml::to_string(config)?.parse()?;
+            let Some(mut document) = existing.and_then(|s| s.parse::<DocumentMut>().ok()) else {
+                return Ok(updated.to_string());
+            };
+            let defaults: DocumentMut = toml::to_string(&Config::default())?.parse()?;
+            inline_arrays(updated.as_table_mut());
+            merge_table(
+                document.as_table_mut(),
+                updated.as_table(),
+                Some(defaults.as_table()),
+            );
+            Ok(document.to_string())
+        }
+    }
+}
+
+/// Copies changed values from `updated` into `existing`, keeping the decoration (comments
+/// and whitespace) of values that are already there
+fn merge_table(existing: &mut Table, updated: &Table, defaults: Option<&Table>) {
+    for (key, new) in updated.iter() {
+        let default = defaults.and_then(|table| table.get(key));
+        match (existing.get_mut(key), new) {
+            (Some(Item::Table(old)), Item::Table(new)) => {
+                merge_table(old, new, default.and_then(Item::as_table));
+            }
+            (Some(Item::Value(old)), Item::Value(new)) => {
+                if !same_value(&Item::Value(old.clone()), &Item::Value(new.clone())) {
+                    let decor = old.decor().clone();
+                    *old = new.clone();
+                    *old.decor_mut() = decor;
+                }
+            }
+            (Some(old), new) => {
+                if !same_value(old, new) {
+                    *old = new.clone();
+                }
+            }
+            (None, new) => {
+                if default.is_none_or(|default| !same_value(default, new)) {
+                    existing.insert(key, new.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Turns arrays of tables (`[[mute_rules]]`) into inline arrays so they replace the
+/// `key = [...]` lines they come from instead of moving to the end of the file
+fn inline_arrays(table: &mut Table) {
+    for (_, item) in table.iter_mut() {
+        match item {
+            Item::ArrayOfTables(array) => {
+                let mut inline = array.clone().into_array();
+                for value in inline.iter_mut() {
+                    value.decor_mut().set_prefix("\n  ");
+                }
+                inline.set_trailing("\n");
+                inline.set_trailing_comma(true);
+                *item = Item::Value(inline.into());
+            }
+            Item::Table(inner) => inline_arrays(inner),
+            _ => {}
+        }
+    }
+}
+
+/// Compares two TOML items by value, ignoring comments and whitespace
+fn same_value(a: &Item, b: &Item) -> bool {
+    let plain = |item: &Item| -> Option<toml::Value> {
+        let mut document = DocumentMut::new();
+        document.insert("v", item.clone());
+        let parsed: toml::Table = toml::from_str(&document.to_string()).ok()?;
+        parsed.get("v").cloned()
+    };
+    plain(a).is_some() && plain(a) == plain(b)
+}
+
+/// A config.toml with every setting at its default value, each explained by a comment
+pub fn default_toml() -> String {
+    commented_toml(
+        &Config::default(),
+        "Every setting is optional; the values below are the defaults.",
+    )
+}
+
+/// A config.toml holding the settings of `config`, each explained by a comment
+pub fn config_toml(config: &Config) -> String {
+    commented_toml(config, "Every setting is optional.")
+}
+
+fn commented_toml(config: &Config, intro: &str) -> String {
+    let mut document: DocumentMut = toml::to_string(config)
+        .ok()
+        .and_then(|toml| toml.parse().ok())
+        .unwrap_or_default();
+    let root = document.as_table_mut();
+
+    for (key, description) in SETTINGS {
+        let (table, field) = match key.split_once('.') {
+            Some((table, field)) => match root.get_mut(table).and_then(Item::as_table_mut) {
+                Some(table) => (table, field),
+                None => continue,
+            },
+            None => (&mut *root, *key),
+        };
+        match table.get_mut(field) {
+            // A whole table (keybindings): describe each field
+            Some(Item::Table(fields)) => {
+                let names: Vec<String> = fields.iter().map(|(name, _)| name.to_string()).collect();
+                for name in names {
+                    if let Some(mut key) = fields.key_mut(&name) {
+                        key.leaf_decor_mut().set_prefix(format!(
+                            "# {} {}\n",
+                            description,
+                            name.replace('_', " ")
+                        ));
+                    }
+                }
+            }
+            Some(_) => {
+                // Lists start empty: show an example of their entries
+                let example = LIST_EXAMPLES
+                    .iter()
+                    .find(|(name, _)| name == key)
+                    .map(|(_, example)| {
+                        let lines: Vec<String> = example
+                            .lines()
+                            .map(|line| format!("# {}\n", line))
+                            .collect();
+                        format!("\n# {}, for example:\n{}", description, lines.concat())
+                    })
+                    .unwrap_or_else(|| format!("# {}\n", description));
+                if let Some(mut key) = table.key_mut(field) {
+                    key.leaf_decor_mut().set_prefix(example);
+                }
+            }
+            None => {}
+        }
+    }
+
+    for (name, title) in [
+        (
+            "theme",
+            "Colors: names such as \"dark_gray\" or \"light_red\", or hex codes such as \"#ff5500\"",
+        ),
+        (
+            "keybindings",
+            "Keys: comma-separated, e.g. \"j,Down\". Besides single characters: Enter, Esc,\n\
+             # Backspace, Tab, Space, Up, Down, Left, Right, PageUp, PageDown, Home, End, Insert, Delete",
+        ),
+        ("api", "Local JSON API"),
+    ] {
+        if let Some(table) = root.get_mut(name).and_then(Item::as_table_mut) {
+            table.decor_mut().set_prefix(format!("\n# {}\n", title));
+        }
+    }
+
+    format!(
+        "# Reedy configuration\n\
+         #\n\
+         # {} Check this file\n\
+         # with `reedy config check` after editing it.\n\n{}",
+        intro, document
+    )
+}
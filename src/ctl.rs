@@ -0,0 +1,241 @@
+use log::{debug, error, info};
+use tokio::sync::oneshot;
+
+use crate::app::{App, PageMode};
+
+/// A `reedy ctl` command forwarded to the main loop, with a channel for the reply.
+#[derive(Debug)]
+pub struct ControlCall {
+    pub command: String,
+    /// Title of the feed an `add` command subscribes to. The listener fetches it before
+    /// passing the command on, so the main loop never waits for the network.
+    pub feed_title: Option<String>,
+    pub reply: oneshot::Sender<String>,
+}
+
+/// Splits a command line into its verb and the (possibly empty) remaining arguments
+fn split_verb(line: &str) -> (String, &str) {
+    let line = line.trim();
+    match line.split_once(char::is_whitespace) {
+        Some((verb, rest)) => (verb.to_lowercase(), rest.trim()),
+        None => (line.to_lowercase(), ""),
+    }
+}
+
+/// Removes one pair of matching surrounding quotes, if present
+fn unquote(arg: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = arg
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    arg
+}
+
+/// Splits the arguments of `add` into the feed URL and optional category
+fn add_arguments(args: &str) -> Result<(String, Option<String>), String> {
+    let mut parts = args.splitn(2, char::is_whitespace);
+    let url = parts.next().map(unquote).unwrap_or_default();
+    if url.is_empty() {
+        return Err("Usage: add <url> [category]".to_string());
+    }
+    let category = parts
+        .next()
+        .map(|c| unquote(c.trim()).to_string())
+        .filter(|c| !c.is_empty());
+    Ok((url.to_string(), category))
+}
+
+/// Fetches the title of the feed an `add` command subscribes to. Other commands, and an
+/// `add` without a URL (left for [`dispatch`] to reject), need no title.
+pub async fn fetch_feed_title(
+    line: &str,
+    http_timeout_secs: u64,
+) -> Result<Option<String>, String> {
+    let (verb, args) = split_verb(line);
+    if verb != "add" {
+        return Ok(None);
+    }
+    let Ok((url, _)) = add_arguments(args) else {
+        return Ok(None);
+    };
+    match App::validate_and_get_feed_title(&url, http_timeout_secs).await {
+        Ok(Some(title)) => Ok(Some(title)),
+        Ok(None) => Err("Invalid RSS feed URL".to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Executes a remote control command against the running [`App`]. `feed_title` is the
+/// title [`fetch_feed_title`] fetched for an `add` command.
+///
+/// Besides every `:` command understood by [`App::execute_command`], this accepts:
+/// - `add <url> [category]` - subscribe to a feed
+/// - `refresh` - force-refresh all feeds on the next tick
+/// - `open-next-unread` - open the next unread item in the browser and mark it read
+/// - `search <query>` - filter the item list
+///
+/// Returns a human-readable result, or an error message.
+pub async fn dispatch(
+    app: &mut App,
+    line: &str,
+    feed_title: Option<&str>,
+) -> Result<String, String> {
+    let (verb, args) = split_verb(line);
+    debug!("Control command: {} {}", verb, args);
+
+    match verb.as_str() {
+        "add" => {
+            let (url, category) = add_arguments(args)?;
+            let title = feed_title.ok_or("Invalid RSS feed URL")?;
+            app.add_subscription(&url, title, category)
+                .map_err(|e| e.to_string())?;
+            Ok(format!("Added {}", title))
+        }
+        "refresh" | "r" => {
+            // The main loop refreshes on its next tick, so the reply does not wait for it
+            app.refresh_requested = true;
+            Ok(format!("Refreshing {} feeds", app.rss_feeds.len()))
+        }
+        "open-next-unread" => {
+            if app.page_mode == PageMode::FeedManager {
+                app.toggle_feed_manager();
+            }
+            app.open_next_unread()
+                .map(|title| format!("Opened {}", title))
+                .ok_or_else(|| "No unread items".to_string())
+        }
+        "search" => {
+            app.search_query = unquote(args).to_string();
+            app.update_search_filter();
+            if let Some(e) = &app.search_error {
+                return Err(format!("Invalid search: {}", e));
+            }
+            Ok(format!("{} matching items", app.visible_item_count()))
+        }
+        _ => {
+            app.command_buffer = line.trim().to_string();
+            match app.execute_command() {
+                Ok(true) => {
+                    // Favorites toggling is async and signalled through the error message
+                    if app.error_message.as_deref() == Some("__toggle_favorites__") {
+                        app.error_message = None;
+                        app.toggle_favorites_page().await;
+                    }
+                    app.switch_profile_pending();
+                    Ok(app
+                        .status_message
+                        .clone()
+                        .or_else(|| app.error_message.clone())
+                        .unwrap_or_else(|| "OK".to_string()))
+                }
+                Ok(false) => Err(app
+                    .error_message
+                    .clone()
+                    .unwrap_or_else(|| format!("Unknown command: {}", verb))),
+                Err(e) => Err(e.to_string()),
+            }
+        }
+    }
+}
+
+/// Formats a dispatch result as a single protocol line ("OK ..." or "ERR ...")
+pub fn format_reply(result: Result<String, String>) -> String {
+    match result {
+        Ok(message) => format!("OK {}", message.replace('\n', " ")),
+        Err(message) => format!("ERR {}", message.replace('\n', " ")),
+    }
+}
+
+/// Listens on the control socket and forwards each received line to the main loop.
+/// Fails if another running instance already owns the socket. Aborting the returned
+/// task stops listening.
+#[cfg(unix)]
+pub async fn start_listener(
+    path: &std::path::Path,
+    http_timeout_secs: u64,
+    sender: tokio::sync::mpsc::UnboundedSender<crate::event::Event>,
+) -> crate::app::AppResult<tokio::task::JoinHandle<()>> {
+    use crate::event::Event;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::UnixListener;
+
+    if path.exists() {
+        if std::os::unix::net::UnixStream::connect(path).is_ok() {
+            return Err(
+                format!("another reedy instance is listening on {}", path.display()).into(),
+            );
+        }
+        // Stale socket left behind by an instance that did not shut down cleanly
+        std::fs::remove_file(path)?;
+    }
+
+    let listener = UnixListener::bind(path)?;
+    info!("Control socket listening on {}", path.display());
+
+    let task = tokio::spawn(async move {
+        loop {
+            let stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    error!("Control socket accept failed: {}", e);
+                    break;
+                }
+            };
+            let sender = sender.clone();
+            tokio::spawn(async move {
+                let (reader, mut writer) = stream.into_split();
+                let mut lines = BufReader::new(reader).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    let response = match fetch_feed_title(&line, http_timeout_secs).await {
+                        Ok(feed_title) => {
+                            let (reply, receiver) = oneshot::channel();
+                            let call = ControlCall {
+                                command: line,
+                                feed_title,
+                                reply,
+                            };
+                            if sender.send(Event::Control(call)).is_err() {
+                                break;
+                            }
+                            receiver
+                                .await
+                                .unwrap_or_else(|_| "ERR reedy is shutting down".to_string())
+                        }
+                        Err(e) => format_reply(Err(e)),
+                    };
+                    if writer
+                        .write_all(format!("{}\n", response).as_bytes())
+                        .await
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+    Ok(task)
+}
+
+/// Sends a single command to a running instance and returns its reply line
+#[cfg(unix)]
+pub fn send_command(path: &std::path::Path, command: &str) -> crate::app::AppResult<String> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+
+    let mut stream = UnixStream::connect(path)
+        .map_err(|e| format!("could not connect to {}: {}", path.display(), e))?;
+    stream.write_all(format!("{}\n", command.replace('\n', " ")).as_bytes())?;
+    stream.shutdown(std::net::Shutdown::Write)?;
+
+    let mut reply = String::new();
+    BufReader::new(stream).read_line(&mut reply)?;
+    Ok(reply.trim_end().to_string())
+}
@@ -4,10 +4,12 @@ use crossterm::event::{Event as CrosstermEvent, KeyEvent, MouseEvent};
 use futures::{FutureExt, StreamExt};
 use tokio::sync::mpsc;
 
+use crate::api::ApiCall;
 use crate::app::AppResult;
+use crate::ctl::ControlCall;
 
 /// Terminal events.
-#[derive(Clone, Copy, Debug)]
+#[derive(Debug)]
 pub enum Event {
     /// Terminal tick.
     Tick,
@@ -17,15 +19,21 @@ pub enum Event {
     Mouse(MouseEvent),
     /// Terminal resize.
     Resize(u16, u16),
+    /// Request received by the local JSON API.
+    Api(ApiCall),
+    /// Command received from `reedy ctl` over the control socket.
+    Control(ControlCall),
 }
 
 /// Terminal event handler.
 #[derive(Debug)]
 pub struct EventHandler {
     /// Event sender channel (kept alive to prevent channel closure).
-    _sender: mpsc::UnboundedSender<Event>,
+    sender: mpsc::UnboundedSender<Event>,
     /// Event receiver channel.
     receiver: mpsc::UnboundedReceiver<Event>,
+    /// Interval between tick events.
+    tick_rate: Duration,
     /// Event handler thread (kept alive to maintain the spawned task).
     _handler: tokio::task::JoinHandle<()>,
 }
@@ -35,8 +43,21 @@ impl EventHandler {
     pub fn new(tick_rate: u64) -> Self {
         let tick_rate = Duration::from_millis(tick_rate);
         let (sender, receiver) = mpsc::unbounded_channel();
-        let _sender = sender.clone();
-        let handler = tokio::spawn(async move {
+        let handler = Self::spawn_reader(sender.clone(), tick_rate);
+        Self {
+            sender,
+            receiver,
+            tick_rate,
+            _handler: handler,
+        }
+    }
+
+    /// Spawns the task that reads terminal input and emits ticks.
+    fn spawn_reader(
+        _sender: mpsc::UnboundedSender<Event>,
+        tick_rate: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
             let mut reader = crossterm::event::EventStream::new();
             let mut tick = tokio::time::interval(tick_rate);
             loop {
@@ -72,12 +93,24 @@ impl EventHandler {
                   }
                 };
             }
-        });
-        Self {
-            _sender: sender,
-            receiver,
-            _handler: handler,
-        }
+        })
+    }
+
+    /// Stops reading terminal input so a foreground program can use the terminal.
+    pub async fn pause(&mut self) {
+        self._handler.abort();
+        // Wait for the reader to be dropped so it no longer competes for stdin
+        let _ = (&mut self._handler).await;
+    }
+
+    /// Restarts reading terminal input after [`EventHandler::pause`].
+    pub fn resume(&mut self) {
+        self._handler = Self::spawn_reader(self.sender.clone(), self.tick_rate);
+    }
+
+    /// Returns a sender that additional event sources can use to feed the main loop.
+    pub fn sender(&self) -> mpsc::UnboundedSender<Event> {
+        self.sender.clone()
     }
 
     /// Receive the next event from the handler thread.
@@ -88,9 +121,6 @@ impl EventHandler {
         self.receiver
             .recv()
             .await
-            .ok_or(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "This is an IO error",
-            )))
+            .ok_or(Box::new(std::io::Error::other("This is an IO error")))
     }
 }
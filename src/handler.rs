@@ -1,10 +1,10 @@
-use crate::app::{App, AppResult, InputMode, Keybindings, PageMode};
-use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use crate::app::{App, AppResult, CategoryScope, FeedManagerRow, InputMode, Keybindings, PageMode};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use log::{debug, error};
 
 /// Parses a key string (like "Enter", "k", "Up", "PageDown") into a KeyCode.
 /// Returns None if the string is not a valid key.
-fn parse_key(key_str: &str) -> Option<KeyCode> {
+pub(crate) fn parse_key(key_str: &str) -> Option<KeyCode> {
     let key_str = key_str.trim();
     match key_str.to_lowercase().as_str() {
         // Special keys
@@ -52,6 +52,38 @@ fn keys(app: &App) -> &Keybindings {
 
 /// Handles the key events and updates the state of [`App`].
 pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
+    // Ctrl-Z suspends to the shell from any mode (raw mode disables the terminal's own handling)
+    if key_event.code == KeyCode::Char('z') && key_event.modifiers.contains(KeyModifiers::CONTROL) {
+        app.suspend_pending = true;
+        return Ok(());
+    }
+
+    // Ctrl-P opens the jump palette from any page
+    if key_event.code == KeyCode::Char('p')
+        && key_event.modifiers.contains(KeyModifiers::CONTROL)
+        && app.input_mode == InputMode::Normal
+    {
+        app.open_palette();
+        return Ok(());
+    }
+
+    // Handle the jump palette - text input, not customizable
+    if app.input_mode == InputMode::Palette {
+        let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+        match key_event.code {
+            KeyCode::Enter => app.confirm_palette().await?,
+            KeyCode::Esc => app.close_palette(),
+            KeyCode::Up => app.palette_move(false),
+            KeyCode::Down | KeyCode::Tab => app.palette_move(true),
+            KeyCode::Char('p') if ctrl => app.palette_move(false),
+            KeyCode::Char('n') if ctrl => app.palette_move(true),
+            KeyCode::Char(c) => app.palette_input(Some(c)),
+            KeyCode::Backspace => app.palette_input(None),
+            _ => {}
+        }
+        return Ok(());
+    }
+
     // Handle help mode across all pages first
     if app.input_mode == InputMode::Help {
         // Help mode uses quit, Esc, and help keys to close
@@ -73,6 +105,9 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
             KeyCode::Esc => {
                 app.cancel_search();
             }
+            KeyCode::Tab => {
+                app.toggle_search_everything();
+            }
             KeyCode::Char(c) => {
                 app.search_query.push(c);
                 app.update_search_filter();
@@ -86,21 +121,44 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
         return Ok(());
     }
 
+    // Handle the tag picker - text input, not customizable
+    if app.input_mode == InputMode::Tagging {
+        match key_event.code {
+            KeyCode::Enter => app.confirm_tagging(),
+            KeyCode::Esc => app.cancel_tagging(),
+            KeyCode::Tab => app.complete_tag(),
+            KeyCode::Char(c) => app.input_buffer.push(c),
+            KeyCode::Backspace => {
+                app.input_buffer.pop();
+            }
+            _ => {}
+        }
+        return Ok(());
+    }
+
     // Handle vi-style command mode
     if app.input_mode == InputMode::Command {
         match key_event.code {
             KeyCode::Enter => {
-                if app.execute_command().is_ok() {
-                    // Check if we need to toggle favorites (async operation)
-                    if app.error_message == Some("__toggle_favorites__".to_string()) {
-                        app.error_message = None;
-                        app.toggle_favorites_page().await;
-                    }
+                let line = app.command_buffer.clone();
+                let executed = app.execute_command().is_ok();
+                app.record_command(&line);
+                // Check if we need to toggle favorites (async operation)
+                if executed && app.error_message == Some("__toggle_favorites__".to_string()) {
+                    app.error_message = None;
+                    app.toggle_favorites_page().await;
                 }
+                // Subscribing from :add is async as well
+                app.subscribe_pending().await;
+                // So is switching profiles, which reloads everything
+                app.switch_profile_pending();
             }
             KeyCode::Esc => {
                 app.cancel_command_mode();
             }
+            KeyCode::Tab => app.complete_command(),
+            KeyCode::Up => app.command_history_previous(),
+            KeyCode::Down => app.command_history_next(),
             KeyCode::Char(c) => {
                 app.command_buffer.push(c);
             }
@@ -136,6 +194,8 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
             app.toggle_read_status();
         } else if key_matches(&key_event, &kb.toggle_favorite) {
             app.toggle_favorite();
+        } else if key_matches(&key_event, &kb.tag_article) {
+            app.start_tagging();
         } else if key_matches(&key_event, &kb.scroll_to_top) {
             app.preview_scroll = 0;
         } else if key_matches(&key_event, &kb.scroll_to_bottom) {
@@ -146,6 +206,10 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
             app.export_article_to_clipboard();
         } else if key_event.code == KeyCode::Char('S') {
             app.export_article_to_file();
+        } else if key_matches(&key_event, &kb.view_in_pager) {
+            app.view_in_pager();
+        } else if key_matches(&key_event, &kb.open_in_text_browser) {
+            app.open_in_text_browser();
         }
         return Ok(());
     }
@@ -153,6 +217,53 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
     // Clone keybindings to avoid borrow issues
     let kb = keys(app).clone();
 
+    // Articles are marked the same way in the feed list and favorites; while any are marked,
+    // the article actions apply to all of them
+    if app.input_mode == InputMode::Normal
+        && matches!(app.page_mode, PageMode::FeedList | PageMode::Favorites)
+    {
+        if key_matches(&key_event, &kb.toggle_mark) {
+            app.toggle_mark_selected_item();
+            app.ensure_selection_visible();
+            return Ok(());
+        } else if key_matches(&key_event, &kb.visual_mode) {
+            app.toggle_item_visual();
+            return Ok(());
+        } else if app.has_marked_items() {
+            let handled = if key_event.code == KeyCode::Esc {
+                app.clear_item_marks();
+                true
+            } else if key_matches(&key_event, &kb.toggle_read) {
+                app.toggle_read_marked_items();
+                true
+            } else if key_matches(&key_event, &kb.mark_all_read) {
+                app.mark_marked_items_read();
+                true
+            } else if key_matches(&key_event, &kb.toggle_favorite) {
+                app.toggle_favorite_marked_items();
+                true
+            } else if key_matches(&key_event, &kb.tag_article) {
+                app.start_tagging();
+                true
+            } else if key_matches(&key_event, &kb.open_in_browser) {
+                app.open_marked_items();
+                true
+            } else if key_matches(&key_event, &kb.export_article) {
+                app.export_marked_items_to_clipboard();
+                true
+            } else if key_event.code == KeyCode::Char('S') {
+                app.export_marked_items_to_file();
+                true
+            } else {
+                // Anything else (moving, searching, ...) works as usual
+                false
+            };
+            if handled {
+                return Ok(());
+            }
+        }
+    }
+
     match app.page_mode {
         PageMode::FeedList => {
             // Enter vi-style command mode with ':'
@@ -161,9 +272,11 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
             } else if key_matches(&key_event, &kb.quit) {
                 app.quit();
             } else if key_event.code == KeyCode::Esc {
-                // If there's an active search filter, clear it; otherwise quit
-                if app.filtered_indices.is_some() {
+                // Clear an active search filter first, then the category scope, then quit
+                if app.has_active_filter() {
                     app.clear_search();
+                } else if app.category_scope != CategoryScope::All {
+                    app.set_category_scope(CategoryScope::All);
                 } else {
                     app.quit();
                 }
@@ -171,6 +284,16 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
                 app.start_search();
             } else if key_matches(&key_event, &kb.toggle_unread_only) {
                 app.toggle_unread_only();
+            } else if key_matches(&key_event, &kb.toggle_show_muted) {
+                app.toggle_show_muted();
+            } else if key_matches(&key_event, &kb.next_category) {
+                app.cycle_category(true);
+            } else if key_matches(&key_event, &kb.prev_category) {
+                app.cycle_category(false);
+            } else if key_matches(&key_event, &kb.cycle_sort) {
+                app.cycle_sort_mode();
+            } else if key_matches(&key_event, &kb.cycle_group) {
+                app.cycle_group_by();
             } else if key_matches(&key_event, &kb.open_preview) {
                 app.open_preview();
             } else if key_matches(&key_event, &kb.open_feed_manager) {
@@ -211,6 +334,8 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
                 }
             } else if key_matches(&key_event, &kb.toggle_favorite) {
                 app.toggle_favorite();
+            } else if key_matches(&key_event, &kb.tag_article) {
+                app.start_tagging();
             } else if key_matches(&key_event, &kb.toggle_favorites_view) {
                 app.clear_search(); // Clear search when toggling favorites
                 app.toggle_favorites_page().await;
@@ -218,6 +343,18 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
                 app.export_article_to_clipboard();
             } else if key_event.code == KeyCode::Char('S') {
                 app.export_article_to_file();
+            } else if key_matches(&key_event, &kb.view_in_pager) {
+                app.view_in_pager();
+            } else if key_matches(&key_event, &kb.open_in_text_browser) {
+                app.open_in_text_browser();
+            } else if key_matches(&key_event, &kb.edit_config) {
+                app.edit_config();
+            } else if key_matches(&key_event, &kb.open_mute_rules) {
+                app.clear_search();
+                app.open_mute_rules();
+            } else if key_matches(&key_event, &kb.open_settings) {
+                app.clear_search();
+                app.open_settings();
             } else if key_matches(&key_event, &kb.help) {
                 app.toggle_help();
             }
@@ -227,6 +364,8 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
                 // Enter vi-style command mode with ':'
                 if key_event.code == KeyCode::Char(':') {
                     app.start_command_mode();
+                } else if key_event.code == KeyCode::Esc && app.has_marked_feeds() {
+                    app.clear_feed_marks();
                 } else if key_matches(&key_event, &kb.quit) || key_event.code == KeyCode::Esc {
                     app.quit();
                 } else if key_matches(&key_event, &kb.open_feed_manager) {
@@ -238,8 +377,36 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
                     app.start_deleting();
                 } else if key_matches(&key_event, &kb.set_category) {
                     app.start_setting_category();
+                } else if key_matches(&key_event, &kb.set_feed_tags) {
+                    app.start_setting_feed_tags();
+                } else if key_matches(&key_event, &kb.rename_feed) {
+                    app.start_renaming_feed();
+                } else if key_matches(&key_event, &kb.edit_feed_url) {
+                    app.start_editing_feed_url();
+                } else if key_matches(&key_event, &kb.move_feed_up) {
+                    app.move_selected_feed(false);
+                } else if key_matches(&key_event, &kb.move_feed_down) {
+                    app.move_selected_feed(true);
+                } else if key_matches(&key_event, &kb.toggle_pin_feed) {
+                    app.toggle_pin_selected_feed();
+                } else if key_matches(&key_event, &kb.toggle_pause_feed) {
+                    app.toggle_pause_selected_feed();
+                } else if key_matches(&key_event, &kb.toggle_mark) {
+                    app.toggle_mark_selected_feed();
+                    app.ensure_selection_visible();
+                } else if key_matches(&key_event, &kb.visual_mode) {
+                    app.toggle_feed_visual();
+                } else if key_matches(&key_event, &kb.cycle_sort) {
+                    app.cycle_feed_sort();
                 } else if key_matches(&key_event, &kb.refresh) {
-                    app.cache_all_feeds().await;
+                    if app.has_marked_feeds() {
+                        if let Err(e) = app.refresh_marked_feeds().await {
+                            error!("Failed to refresh marked feeds: {}", e);
+                            app.error_message = Some(format!("Failed to refresh feeds: {}", e));
+                        }
+                    } else {
+                        app.cache_all_feeds().await;
+                    }
                 } else if key_matches(&key_event, &kb.export_clipboard) {
                     app.export_feeds_to_clipboard();
                 } else if key_matches(&key_event, &kb.export_opml) {
@@ -263,6 +430,11 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
                     }
                 } else if key_matches(&key_event, &kb.select) {
                     if let Some(index) = app.selected_index {
+                        // Category rows fold and unfold in place
+                        if app.category_at(index).is_some() {
+                            app.toggle_category_collapse(index);
+                            return Ok(());
+                        }
                         app.select_feed(index).await?;
                         app.toggle_feed_manager();
                         if !app.current_feed_content.is_empty() {
@@ -279,7 +451,11 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
                 } else if key_matches(&key_event, &kb.toggle_read) {
                     app.mark_as_read();
                 } else if key_matches(&key_event, &kb.mark_all_read) {
-                    app.mark_all_as_read();
+                    if app.has_marked_feeds() {
+                        app.mark_marked_feeds_read();
+                    } else {
+                        app.mark_all_as_read();
+                    }
                 } else if key_matches(&key_event, &kb.page_up) {
                     app.scroll_up();
                 } else if key_matches(&key_event, &kb.page_down) {
@@ -288,6 +464,12 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
                     app.scroll_to_top();
                 } else if key_matches(&key_event, &kb.scroll_to_bottom) {
                     app.scroll_to_bottom();
+                } else if key_matches(&key_event, &kb.edit_config) {
+                    app.edit_config();
+                } else if key_matches(&key_event, &kb.open_mute_rules) {
+                    app.open_mute_rules();
+                } else if key_matches(&key_event, &kb.open_settings) {
+                    app.open_settings();
                 } else if key_matches(&key_event, &kb.help) {
                     app.toggle_help();
                 }
@@ -310,7 +492,10 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
             },
             InputMode::Deleting => {
                 if key_event.code == KeyCode::Enter {
-                    if let Some(index) = app.selected_index {
+                    if app.has_marked_feeds() {
+                        app.delete_marked_feeds();
+                        app.cancel_deleting();
+                    } else if let Some(index) = app.selected_index {
                         app.delete_feed(index);
                         app.cancel_deleting();
                     }
@@ -354,6 +539,40 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
                 }
                 _ => {}
             },
+            InputMode::SettingFeedTags => match key_event.code {
+                KeyCode::Enter => {
+                    app.set_feed_tags();
+                }
+                KeyCode::Esc => {
+                    app.cancel_setting_category();
+                }
+                KeyCode::Char(c) => {
+                    app.input_buffer.push(c);
+                }
+                KeyCode::Backspace => {
+                    app.input_buffer.pop();
+                }
+                _ => {}
+            },
+            InputMode::RenamingFeed | InputMode::EditingFeedUrl => match key_event.code {
+                KeyCode::Enter => {
+                    if app.input_mode == InputMode::RenamingFeed {
+                        app.rename_selected_feed();
+                    } else {
+                        app.set_selected_feed_url();
+                    }
+                }
+                KeyCode::Esc => {
+                    app.cancel_setting_category();
+                }
+                KeyCode::Char(c) => {
+                    app.input_buffer.push(c);
+                }
+                KeyCode::Backspace => {
+                    app.input_buffer.pop();
+                }
+                _ => {}
+            },
             _ => {}
         },
         PageMode::Favorites => {
@@ -363,9 +582,11 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
             } else if key_matches(&key_event, &kb.quit) {
                 app.quit();
             } else if key_event.code == KeyCode::Esc {
-                // If there's an active search filter, clear it; otherwise quit
-                if app.filtered_indices.is_some() {
+                // Clear an active search filter first, then the category scope, then quit
+                if app.has_active_filter() {
                     app.clear_search();
+                } else if app.category_scope != CategoryScope::All {
+                    app.set_category_scope(CategoryScope::All);
                 } else {
                     app.quit();
                 }
@@ -373,6 +594,16 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
                 app.start_search();
             } else if key_matches(&key_event, &kb.toggle_unread_only) {
                 app.toggle_unread_only();
+            } else if key_matches(&key_event, &kb.toggle_show_muted) {
+                app.toggle_show_muted();
+            } else if key_matches(&key_event, &kb.next_category) {
+                app.cycle_category(true);
+            } else if key_matches(&key_event, &kb.prev_category) {
+                app.cycle_category(false);
+            } else if key_matches(&key_event, &kb.cycle_sort) {
+                app.cycle_sort_mode();
+            } else if key_matches(&key_event, &kb.cycle_group) {
+                app.cycle_group_by();
             } else if key_matches(&key_event, &kb.open_preview) {
                 app.open_preview();
             } else if key_matches(&key_event, &kb.open_in_browser) {
@@ -387,6 +618,8 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
                 app.ensure_selection_visible();
             } else if key_matches(&key_event, &kb.toggle_favorite) {
                 app.toggle_favorite();
+            } else if key_matches(&key_event, &kb.tag_article) {
+                app.start_tagging();
             } else if key_matches(&key_event, &kb.toggle_favorites_view) {
                 app.clear_search(); // Clear search when toggling favorites
                 app.toggle_favorites_page().await;
@@ -402,10 +635,129 @@ pub async fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<
                 app.export_article_to_clipboard();
             } else if key_event.code == KeyCode::Char('S') {
                 app.export_article_to_file();
+            } else if key_matches(&key_event, &kb.view_in_pager) {
+                app.view_in_pager();
+            } else if key_matches(&key_event, &kb.open_in_text_browser) {
+                app.open_in_text_browser();
+            } else if key_matches(&key_event, &kb.edit_config) {
+                app.edit_config();
             } else if key_matches(&key_event, &kb.help) {
                 app.toggle_help();
             }
         }
+        PageMode::MuteRules => match app.input_mode {
+            InputMode::Normal => {
+                if key_event.code == KeyCode::Char(':') {
+                    app.start_command_mode();
+                } else if key_matches(&key_event, &kb.quit)
+                    || key_event.code == KeyCode::Esc
+                    || key_matches(&key_event, &kb.open_mute_rules)
+                {
+                    app.close_mute_rules();
+                } else if key_matches(&key_event, &kb.open_feed_manager) {
+                    app.toggle_feed_manager();
+                } else if key_matches(&key_event, &kb.add_feed) {
+                    app.start_adding_mute_rule();
+                } else if key_matches(&key_event, &kb.delete_feed) {
+                    app.delete_mute_rule();
+                } else if key_event.code == KeyCode::Char('e') {
+                    app.start_editing_mute_rule();
+                } else if key_matches(&key_event, &kb.select) {
+                    app.toggle_mute_rule_action();
+                } else if key_matches(&key_event, &kb.move_up) {
+                    app.select_previous();
+                    app.ensure_selection_visible();
+                } else if key_matches(&key_event, &kb.move_down) {
+                    app.select_next();
+                    app.ensure_selection_visible();
+                } else if key_matches(&key_event, &kb.scroll_to_top) {
+                    app.scroll_to_top();
+                } else if key_matches(&key_event, &kb.scroll_to_bottom) {
+                    app.scroll_to_bottom();
+                } else if key_matches(&key_event, &kb.help) {
+                    app.toggle_help();
+                }
+            }
+            // Rule text input - not customizable
+            InputMode::Adding => match key_event.code {
+                KeyCode::Enter => {
+                    app.confirm_mute_rule();
+                }
+                KeyCode::Esc => {
+                    app.cancel_mute_rule_input();
+                }
+                KeyCode::Char(c) => {
+                    app.input_buffer.push(c);
+                }
+                KeyCode::Backspace => {
+                    app.input_buffer.pop();
+                }
+                _ => {}
+            },
+            _ => {}
+        },
+        PageMode::Settings => match app.input_mode {
+            InputMode::Normal => {
+                if key_event.code == KeyCode::Char(':') {
+                    app.start_command_mode();
+                } else if key_matches(&key_event, &kb.quit)
+                    || key_event.code == KeyCode::Esc
+                    || key_matches(&key_event, &kb.open_settings)
+                {
+                    app.close_settings();
+                } else if key_matches(&key_event, &kb.open_feed_manager) {
+                    app.toggle_feed_manager();
+                } else if key_matches(&key_event, &kb.select)
+                    || key_event.code == KeyCode::Char('e')
+                {
+                    app.start_editing_setting();
+                } else if key_matches(&key_event, &kb.delete_feed) {
+                    app.start_resetting_setting();
+                } else if key_matches(&key_event, &kb.move_up) {
+                    app.select_previous();
+                } else if key_matches(&key_event, &kb.move_down) {
+                    app.select_next();
+                } else if key_matches(&key_event, &kb.page_up) {
+                    app.page_up();
+                } else if key_matches(&key_event, &kb.page_down) {
+                    app.page_down();
+                } else if key_matches(&key_event, &kb.scroll_to_top) {
+                    app.scroll_to_top();
+                } else if key_matches(&key_event, &kb.scroll_to_bottom) {
+                    app.scroll_to_bottom();
+                } else if key_matches(&key_event, &kb.edit_config) {
+                    app.edit_config();
+                } else if key_matches(&key_event, &kb.help) {
+                    app.toggle_help();
+                }
+            }
+            // Value input - not customizable
+            InputMode::EditingSetting => match key_event.code {
+                KeyCode::Enter => {
+                    app.confirm_setting();
+                }
+                KeyCode::Esc => {
+                    app.cancel_setting_input();
+                }
+                KeyCode::Char(c) => {
+                    app.input_buffer.push(c);
+                }
+                KeyCode::Backspace => {
+                    app.input_buffer.pop();
+                }
+                _ => {}
+            },
+            InputMode::ResettingSetting => match key_event.code {
+                KeyCode::Enter => {
+                    app.reset_selected_setting();
+                }
+                KeyCode::Esc => {
+                    app.cancel_setting_input();
+                }
+                _ => {}
+            },
+            _ => {}
+        },
     }
     Ok(())
 }
@@ -418,7 +770,13 @@ pub async fn handle_mouse_events(mouse_event: MouseEvent, app: &mut App) -> AppR
         | InputMode::Importing
         | InputMode::Searching
         | InputMode::SettingCategory
-        | InputMode::Command => {
+        | InputMode::SettingFeedTags
+        | InputMode::RenamingFeed
+        | InputMode::EditingFeedUrl
+        | InputMode::EditingSetting
+        | InputMode::Palette
+        | InputMode::Command
+        | InputMode::Tagging => {
             return Ok(());
         }
         _ => {}
@@ -468,14 +826,8 @@ async fn handle_mouse_click(row: u16, app: &mut App) -> AppResult<()> {
         InputMode::Normal | InputMode::Deleting | InputMode::FeedManager => {
             match app.page_mode {
                 PageMode::FeedList | PageMode::Favorites => {
-                    // Each feed item takes 3 lines in the list view
-                    let item_height = 3;
-                    let clicked_item = (content_row / item_height) as usize;
-                    let actual_index = app.scroll as usize + clicked_item;
-
-                    // Check if the click is within the valid item range
-                    let item_count = app.visible_item_count();
-                    if actual_index < item_count {
+                    // Each feed item takes 3 lines in the list view, each group header 1
+                    if let Some(actual_index) = app.item_at_line(content_row as usize) {
                         if Some(actual_index) == app.selected_index {
                             // Double-click behavior: if already selected, open preview
                             app.open_preview();
@@ -486,49 +838,55 @@ async fn handle_mouse_click(row: u16, app: &mut App) -> AppResult<()> {
                     }
                 }
                 PageMode::FeedManager => {
-                    // In Feed Manager, calculate item index accounting for category headers
-                    // Each item takes 1 line, but we have category headers interspersed
-                    let feeds_by_category = app.get_feeds_by_category();
-                    let mut current_row: u16 = 0;
-                    let mut feed_index = 0;
-                    let scroll_offset = app.scroll;
-
-                    'outer: for (_, feeds) in &feeds_by_category {
-                        // Category header takes 1 line
-                        if current_row >= scroll_offset
-                            && current_row - scroll_offset == content_row
-                        {
-                            // Clicked on a category header - do nothing
+                    // One line per tree row, plus the header line above the saved searches
+                    let rows = app.feed_manager_rows();
+                    let mut line: u16 = 0;
+                    let mut header_shown = false;
+                    let mut clicked = None;
+                    for (row, entry) in rows.iter().enumerate().skip(app.scroll as usize) {
+                        if matches!(entry, FeedManagerRow::SavedSearch(_)) && !header_shown {
+                            header_shown = true;
+                            line += 1;
+                        }
+                        if line == content_row {
+                            clicked = Some(row);
                             break;
                         }
-                        current_row += 1;
+                        line += 1;
+                    }
 
-                        for _ in feeds {
-                            if current_row >= scroll_offset
-                                && current_row - scroll_offset == content_row
-                            {
-                                // Clicked on a feed item
-                                if feed_index < app.rss_feeds.len() {
-                                    if Some(feed_index) == app.selected_index {
-                                        // Double-click behavior: if already selected, select the feed
-                                        app.select_feed(feed_index).await?;
-                                        app.toggle_feed_manager();
-                                        if !app.current_feed_content.is_empty() {
-                                            app.selected_index = Some(0);
-                                            app.scroll = 0;
-                                        }
-                                    } else {
-                                        app.selected_index = Some(feed_index);
-                                        debug!("Mouse selected feed at index {}", feed_index);
-                                    }
-                                }
-                                break 'outer;
+                    if let Some(row) = clicked {
+                        if Some(row) != app.selected_index {
+                            app.selected_index = Some(row);
+                            debug!("Mouse selected Feed Manager row {}", row);
+                        } else if app.category_at(row).is_some() {
+                            // Double-click behavior: fold or unfold the category
+                            app.toggle_category_collapse(row);
+                        } else {
+                            // Double-click behavior: if already selected, select the feed
+                            app.select_feed(row).await?;
+                            app.toggle_feed_manager();
+                            if !app.current_feed_content.is_empty() {
+                                app.selected_index = Some(0);
+                                app.scroll = 0;
                             }
-                            current_row += 1;
-                            feed_index += 1;
                         }
                     }
                 }
+                PageMode::MuteRules => {
+                    // One line per rule
+                    let index = app.scroll as usize + content_row as usize;
+                    if index < app.config.mute_rules.len() {
+                        app.selected_index = Some(index);
+                    }
+                }
+                PageMode::Settings => {
+                    // One line per setting
+                    let index = app.scroll as usize + content_row as usize;
+                    if index < app.settings().len() {
+                        app.selected_index = Some(index);
+                    }
+                }
             }
         }
         _ => {}
@@ -0,0 +1,620 @@
+use log::{debug, error, info};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+
+use crate::app::{AppResult, FeedItem};
+
+/// Version tag at the start of the postings file
+const POSTINGS_MAGIC: &[u8; 5] = b"RDIX1";
+/// Positions skipped between fields so phrases never match across them
+const FIELD_GAP: u32 = 16;
+/// Score weight of a match in the title, description, link and feed title
+const FIELD_WEIGHTS: [f32; 4] = [3.0, 1.0, 0.5, 2.0];
+/// BM25 parameters
+const K1: f32 = 1.2;
+const B: f32 = 0.75;
+
+/// A full-text inverted index over every article Reedy has seen.
+///
+/// The index keeps a copy of each article, so old articles stay searchable after they drop
+/// out of their feed. It lives in two files: `docs.jsonl` with one article per line and
+/// `postings.bin` with the term table. Term positions are stored so phrases can be matched.
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    /// Directory the index is saved in; None keeps it in memory only
+    dir: Option<PathBuf>,
+    docs: Vec<IndexedDoc>,
+    ids: HashMap<String, u32>,
+    terms: BTreeMap<String, Vec<Posting>>,
+    total_tokens: u64,
+    dirty: bool,
+}
+
+/// An article stored in the index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedDoc {
+    pub item: FeedItem,
+    pub feed_title: String,
+    /// First position of the description, link and feed title fields
+    field_starts: [u32; 3],
+    /// Number of tokens in all fields
+    length: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Posting {
+    doc: u32,
+    positions: Vec<u32>,
+}
+
+/// A search result with its relevance score
+#[derive(Debug, Clone)]
+pub struct SearchHit<'a> {
+    pub item: &'a FeedItem,
+    pub score: f32,
+}
+
+/// One part of an index query
+#[derive(Debug, Clone, PartialEq)]
+enum Clause {
+    Term(String),
+    Prefix(String),
+    Phrase(Vec<String>),
+}
+
+/// A parsed index query: every clause must match and no excluded clause may match.
+///
+/// Words are matched as whole terms, `word*` matches any term starting with `word`,
+/// `"two words"` matches the words next to each other and `-word` excludes articles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexQuery {
+    required: Vec<Clause>,
+    excluded: Vec<Clause>,
+}
+
+impl IndexQuery {
+    /// Parses a query; returns None if it has no terms to look up
+    pub fn parse(input: &str) -> Option<IndexQuery> {
+        let mut query = IndexQuery {
+            required: Vec::new(),
+            excluded: Vec::new(),
+        };
+        let mut chars = input.chars().peekable();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                chars.next();
+                continue;
+            }
+            let negated = c == '-';
+            if negated {
+                chars.next();
+            }
+            let clause = if chars.peek() == Some(&'"') {
+                chars.next();
+                let phrase: String = chars.by_ref().take_while(|&c| c != '"').collect();
+                let words = tokenize(&phrase);
+                match words.len() {
+                    0 => None,
+                    1 => words.into_iter().next().map(Clause::Term),
+                    _ => Some(Clause::Phrase(words)),
+                }
+            } else {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                let prefix = word.ends_with('*');
+                let mut words = tokenize(&word);
+                match (words.len(), prefix) {
+                    (0, _) => None,
+                    (1, true) => words.pop().map(Clause::Prefix),
+                    (1, false) => words.pop().map(Clause::Term),
+                    // Punctuated words such as "async/await" match as a phrase
+                    _ => Some(Clause::Phrase(words)),
+                }
+            };
+            if let Some(clause) = clause {
+                if negated {
+                    query.excluded.push(clause);
+                } else {
+                    query.required.push(clause);
+                }
+            }
+        }
+        (!query.required.is_empty()).then_some(query)
+    }
+}
+
+/// Splits text into lower case alphanumeric terms
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+impl SearchIndex {
+    /// Default location of the index in the cache directory
+    pub fn default_dir() -> PathBuf {
+        let mut path = crate::paths::cache_dir();
+        path.push("search_index");
+        path
+    }
+
+    /// Loads the index saved in `dir`, starting empty if there is none.
+    /// The term table is rebuilt from the articles if it is missing or out of date.
+    pub fn load(dir: PathBuf) -> Self {
+        let mut index = SearchIndex {
+            dir: Some(dir.clone()),
+            ..Default::default()
+        };
+        let docs_path = dir.join("docs.jsonl");
+        let Ok(file) = fs::File::open(&docs_path) else {
+            return index;
+        };
+        for line in BufReader::new(file).lines().map_while(Result::ok) {
+            match serde_json::from_str::<IndexedDoc>(&line) {
+                Ok(doc) => {
+                    index
+                        .ids
+                        .insert(doc.item.id.clone(), index.docs.len() as u32);
+                    index.total_tokens += doc.length as u64;
+                    index.docs.push(doc);
+                }
+                Err(e) => error!("Skipping unreadable search index entry: {}", e),
+            }
+        }
+
+        match fs::read(dir.join("postings.bin"))
+            .ok()
+            .and_then(|bytes| decode_postings(&bytes, index.docs.len()))
+        {
+            Some(terms) => index.terms = terms,
+            None => {
+                info!(
+                    "Rebuilding search index terms for {} articles",
+                    index.docs.len()
+                );
+                let docs = std::mem::take(&mut index.docs);
+                for (doc_id, doc) in docs.iter().enumerate() {
+                    index.add_postings(doc_id as u32, doc);
+                }
+                index.docs = docs;
+                index.dirty = true;
+            }
+        }
+        debug!(
+            "Loaded search index with {} articles and {} terms",
+            index.docs.len(),
+            index.terms.len()
+        );
+        index
+    }
+
+    /// Number of articles in the index
+    pub fn len(&self) -> usize {
+        self.docs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.docs.is_empty()
+    }
+
+    /// Adds articles that are not indexed yet; returns how many were added
+    pub fn add_items<'a>(
+        &mut self,
+        items: impl IntoIterator<Item = (&'a FeedItem, &'a str)>,
+    ) -> usize {
+        let mut added = 0;
+        for (item, feed_title) in items {
+            if self.ids.contains_key(&item.id) {
+                continue;
+            }
+            let doc = IndexedDoc::new(item.clone(), feed_title.to_string());
+            let doc_id = self.docs.len() as u32;
+            self.add_postings(doc_id, &doc);
+            self.ids.insert(item.id.clone(), doc_id);
+            self.total_tokens += doc.length as u64;
+            self.docs.push(doc);
+            added += 1;
+        }
+        if added > 0 {
+            self.dirty = true;
+        }
+        added
+    }
+
+    /// Points the articles of a feed at its new URL, giving each the ID returned by
+    /// `new_id`; returns how many were moved
+    pub fn move_feed(
+        &mut self,
+        old_url: &str,
+        new_url: &str,
+        new_id: impl Fn(&str) -> String,
+    ) -> usize {
+        let mut moved = 0;
+        for (doc_id, doc) in self.docs.iter_mut().enumerate() {
+            if doc.item.feed_url != old_url {
+                continue;
+            }
+            self.ids.remove(&doc.item.id);
+            doc.item.id = new_id(&doc.item.id);
+            doc.item.feed_url = new_url.to_string();
+            self.ids.insert(doc.item.id.clone(), doc_id as u32);
+            moved += 1;
+        }
+        if moved > 0 {
+            self.dirty = true;
+        }
+        moved
+    }
+
+    /// Gives the articles of a feed a new feed title, passing each to `retitle` to update
+    /// its own title as well; returns how many were changed
+    pub fn retitle_feed(
+        &mut self,
+        url: &str,
+        feed_title: &str,
+        retitle: impl Fn(&mut FeedItem),
+    ) -> usize {
+        let doc_ids: Vec<u32> = (0..self.docs.len() as u32)
+            .filter(|&doc_id| self.docs[doc_id as usize].item.feed_url == url)
+            .collect();
+        for &doc_id in &doc_ids {
+            let old = &self.docs[doc_id as usize];
+            let old_terms: HashSet<String> = old.tokens().into_iter().map(|(_, t)| t).collect();
+            self.total_tokens -= old.length as u64;
+            for term in old_terms {
+                if let Some(postings) = self.terms.get_mut(&term) {
+                    postings.retain(|posting| posting.doc != doc_id);
+                    if postings.is_empty() {
+                        self.terms.remove(&term);
+                    }
+                }
+            }
+
+            let mut item = old.item.clone();
+            retitle(&mut item);
+            let doc = IndexedDoc::new(item, feed_title.to_string());
+            self.add_postings(doc_id, &doc);
+            self.total_tokens += doc.length as u64;
+            self.docs[doc_id as usize] = doc;
+        }
+        if !doc_ids.is_empty() {
+            self.dirty = true;
+        }
+        doc_ids.len()
+    }
+
+    fn add_postings(&mut self, doc_id: u32, doc: &IndexedDoc) {
+        let mut positions: HashMap<String, Vec<u32>> = HashMap::new();
+        for (position, term) in doc.tokens() {
+            positions.entry(term).or_default().push(position);
+        }
+        for (term, positions) in positions {
+            // Postings stay ordered by document, also when an older one is re-added
+            let postings = self.terms.entry(term).or_default();
+            let at = postings.partition_point(|posting| posting.doc < doc_id);
+            postings.insert(
+                at,
+                Posting {
+                    doc: doc_id,
+                    positions,
+                },
+            );
+        }
+    }
+
+    /// Writes the index to disk if it changed since it was loaded or last saved
+    pub fn save(&mut self) -> AppResult<()> {
+        let Some(dir) = &self.dir else {
+            return Ok(());
+        };
+        if !self.dirty {
+            return Ok(());
+        }
+        fs::create_dir_all(dir)?;
+
+        // Write to temporary files first so a crash never leaves a half-written index
+        let docs_tmp = dir.join("docs.jsonl.tmp");
+        let mut writer = BufWriter::new(fs::File::create(&docs_tmp)?);
+        for doc in &self.docs {
+            serde_json::to_writer(&mut writer, doc)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        let postings_tmp = dir.join("postings.bin.tmp");
+        fs::write(&postings_tmp, encode_postings(&self.terms, self.docs.len()))?;
+
+        fs::rename(&docs_tmp, dir.join("docs.jsonl"))?;
+        fs::rename(&postings_tmp, dir.join("postings.bin"))?;
+        self.dirty = false;
+        debug!("Saved search index with {} articles", self.docs.len());
+        Ok(())
+    }
+
+    /// Returns up to `limit` articles matching the query, best matches first
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchHit<'_>> {
+        let Some(query) = IndexQuery::parse(query) else {
+            return Vec::new();
+        };
+
+        // Start from the rarest clause and narrow down with the others
+        let mut clause_matches: Vec<HashMap<u32, f32>> = query
+            .required
+            .iter()
+            .map(|clause| self.clause_matches(clause))
+            .collect();
+        clause_matches.sort_by_key(HashMap::len);
+        let mut matches = clause_matches.remove(0);
+        for other in &clause_matches {
+            matches.retain(|doc, _| other.contains_key(doc));
+        }
+        for clause in &query.excluded {
+            let excluded = self.clause_matches(clause);
+            matches.retain(|doc, _| !excluded.contains_key(doc));
+        }
+
+        let mut hits: Vec<(u32, f32)> = matches
+            .into_iter()
+            .map(|(doc, score)| {
+                let score = score + clause_matches.iter().map(|m| m[&doc]).sum::<f32>();
+                (doc, score)
+            })
+            .collect();
+        hits.sort_by(|a, b| {
+            b.1.total_cmp(&a.1).then_with(|| {
+                let published = |doc: u32| self.docs[doc as usize].item.published;
+                published(b.0).cmp(&published(a.0))
+            })
+        });
+        hits.into_iter()
+            .take(limit)
+            .map(|(doc, score)| SearchHit {
+                item: &self.docs[doc as usize].item,
+                score,
+            })
+            .collect()
+    }
+
+    /// Returns the documents matching a clause with their BM25 scores
+    fn clause_matches(&self, clause: &Clause) -> HashMap<u32, f32> {
+        // Weighted term frequency per document
+        let mut frequencies: HashMap<u32, f32> = HashMap::new();
+        match clause {
+            Clause::Term(term) => {
+                for posting in self.terms.get(term).into_iter().flatten() {
+                    frequencies.insert(
+                        posting.doc,
+                        self.weighted_tf(posting.doc, &posting.positions),
+                    );
+                }
+            }
+            Clause::Prefix(prefix) => {
+                for (_, postings) in self
+                    .terms
+                    .range(prefix.clone()..)
+                    .take_while(|(term, _)| term.starts_with(prefix.as_str()))
+                {
+                    for posting in postings {
+                        *frequencies.entry(posting.doc).or_default() +=
+                            self.weighted_tf(posting.doc, &posting.positions);
+                    }
+                }
+            }
+            Clause::Phrase(words) => {
+                let Some(lists) = words
+                    .iter()
+                    .map(|word| self.terms.get(word))
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    return HashMap::new();
+                };
+                let rest: Vec<HashMap<u32, &Vec<u32>>> = lists[1..]
+                    .iter()
+                    .map(|postings| postings.iter().map(|p| (p.doc, &p.positions)).collect())
+                    .collect();
+                for first in lists[0] {
+                    let starts: Vec<u32> = first
+                        .positions
+                        .iter()
+                        .copied()
+                        .filter(|&start| {
+                            rest.iter().enumerate().all(|(offset, postings)| {
+                                postings.get(&first.doc).is_some_and(|positions| {
+                                    positions
+                                        .binary_search(&(start + offset as u32 + 1))
+                                        .is_ok()
+                                })
+                            })
+                        })
+                        .collect();
+                    if !starts.is_empty() {
+                        frequencies.insert(first.doc, self.weighted_tf(first.doc, &starts));
+                    }
+                }
+            }
+        }
+
+        let total = self.docs.len() as f32;
+        let idf = (1.0
+            + (total - frequencies.len() as f32 + 0.5) / (frequencies.len() as f32 + 0.5))
+            .ln();
+        let average_length = (self.total_tokens as f32 / total.max(1.0)).max(1.0);
+        frequencies
+            .into_iter()
+            .map(|(doc, tf)| {
+                let length = self.docs[doc as usize].length as f32;
+                let norm = K1 * (1.0 - B + B * length / average_length);
+                (doc, idf * tf * (K1 + 1.0) / (tf + norm))
+            })
+            .collect()
+    }
+
+    /// Term frequency with each occurrence weighted by the field it is in
+    fn weighted_tf(&self, doc: u32, positions: &[u32]) -> f32 {
+        let starts = &self.docs[doc as usize].field_starts;
+        positions
+            .iter()
+            .map(|&position| {
+                let field = starts
+                    .iter()
+                    .take_while(|&&start| position >= start)
+                    .count();
+                FIELD_WEIGHTS[field]
+            })
+            .sum()
+    }
+}
+
+impl IndexedDoc {
+    fn new(item: FeedItem, feed_title: String) -> Self {
+        let mut doc = IndexedDoc {
+            item,
+            feed_title,
+            field_starts: [0; 3],
+            length: 0,
+        };
+        let lengths = doc.fields().map(|field| tokenize(field).len() as u32);
+        let mut start = 0;
+        for (i, length) in lengths.iter().enumerate() {
+            if i > 0 {
+                doc.field_starts[i - 1] = start;
+            }
+            start += length + FIELD_GAP;
+        }
+        doc.length = lengths.iter().sum();
+        doc
+    }
+
+    fn fields(&self) -> [&str; 4] {
+        [
+            &self.item.title,
+            &self.item.description,
+            &self.item.link,
+            &self.feed_title,
+        ]
+    }
+
+    /// Terms of all fields with their positions; fields are separated by a gap
+    fn tokens(&self) -> Vec<(u32, String)> {
+        let mut tokens = Vec::new();
+        let mut start = 0;
+        for field in self.fields() {
+            let terms = tokenize(field);
+            let length = terms.len() as u32;
+            tokens.extend(
+                terms
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, t)| (start + i as u32, t)),
+            );
+            start += length + FIELD_GAP;
+        }
+        tokens
+    }
+}
+
+/// Writes an unsigned LEB128 varint
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Reads an unsigned LEB128 varint, advancing `pos`
+fn read_varint(bytes: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *bytes.get(*pos)?;
+        *pos += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Encodes the term table: the magic, the document count, then for each term its text
+/// and its postings, with document ids and positions stored as deltas
+fn encode_postings(terms: &BTreeMap<String, Vec<Posting>>, doc_count: usize) -> Vec<u8> {
+    let mut out = POSTINGS_MAGIC.to_vec();
+    write_varint(&mut out, doc_count as u64);
+    write_varint(&mut out, terms.len() as u64);
+    for (term, postings) in terms {
+        write_varint(&mut out, term.len() as u64);
+        out.extend_from_slice(term.as_bytes());
+        write_varint(&mut out, postings.len() as u64);
+        let mut previous_doc = 0;
+        for posting in postings {
+            write_varint(&mut out, u64::from(posting.doc - previous_doc));
+            previous_doc = posting.doc;
+            write_varint(&mut out, posting.positions.len() as u64);
+            let mut previous = 0;
+            for &position in &posting.positions {
+                write_varint(&mut out, u64::from(position - previous));
+                previous = position;
+            }
+        }
+    }
+    out
+}
+
+/// Decodes the term table; returns None if it is corrupt or was written for a different
+/// set of documents
+fn decode_postings(bytes: &[u8], doc_count: usize) -> Option<BTreeMap<String, Vec<Posting>>> {
+    let body = bytes.strip_prefix(POSTINGS_MAGIC.as_slice())?;
+    let mut pos = 0;
+    if read_varint(body, &mut pos)? != doc_count as u64 {
+        return None;
+    }
+    let term_count = read_varint(body, &mut pos)?;
+    let mut terms = BTreeMap::new();
+    for _ in 0..term_count {
+        let length = usize::try_from(read_varint(body, &mut pos)?).ok()?;
+        let end = pos.checked_add(length)?;
+        let term = std::str::from_utf8(body.get(pos..end)?).ok()?.to_string();
+        pos = end;
+        // Every entry takes at least one byte, so counts beyond the bytes left are corrupt
+        let posting_count = read_varint(body, &mut pos)?;
+        let mut postings = Vec::with_capacity(capacity(posting_count, body.len() - pos));
+        let mut doc = 0u32;
+        for _ in 0..posting_count {
+            let delta = u32::try_from(read_varint(body, &mut pos)?).ok()?;
+            doc = doc.checked_add(delta)?;
+            if doc as usize >= doc_count {
+                return None;
+            }
+            let position_count = read_varint(body, &mut pos)?;
+            let mut positions = Vec::with_capacity(capacity(position_count, body.len() - pos));
+            let mut position = 0u32;
+            for _ in 0..position_count {
+                let delta = u32::try_from(read_varint(body, &mut pos)?).ok()?;
+                position = position.checked_add(delta)?;
+                positions.push(position);
+            }
+            postings.push(Posting { doc, positions });
+        }
+        terms.insert(term, postings);
+    }
+    (pos == body.len()).then_some(terms)
+}
+
+/// Capacity to reserve for `count` entries with `remaining` bytes left to read them from
+fn capacity(count: u64, remaining: usize) -> usize {
+    usize::try_from(count).map_or(remaining, |count| count.min(remaining))
+}
@@ -1,5 +1,12 @@
+pub mod api;
 pub mod app;
+pub mod config;
+pub mod ctl;
 pub mod event;
 pub mod handler;
+pub mod index;
+pub mod palette;
+pub mod paths;
+pub mod query;
 pub mod tui;
 pub mod ui;
@@ -5,13 +5,18 @@ use log::LevelFilter;
 use std::env;
 use std::fs::File;
 use std::io;
+use tokio::task::JoinHandle;
 
 use ratatui::{backend::CrosstermBackend, Terminal};
 
 use reedy::{
+    api,
     app::{App, AppResult},
+    config::{check_config, config_toml, default_toml, parse_config, ConfigFormat},
+    ctl,
     event::{Event, EventHandler},
     handler::{handle_key_events, handle_mouse_events},
+    paths,
     tui::Tui,
 };
 
@@ -20,6 +25,9 @@ async fn main() -> AppResult<()> {
     // Load .env file
     dotenv().ok();
 
+    // Choose the profile and directories before anything touches the filesystem
+    let args = apply_dir_options(env::args().skip(1).collect());
+
     // Setup logging if in debug mode
     if env::var("REEDY_ENV").unwrap_or_default() == "DEBUG" {
         let log_path = App::get_log_path();
@@ -32,6 +40,21 @@ async fn main() -> AppResult<()> {
             .init();
     }
 
+    // Headless mode: refresh on an interval and regenerate published feeds
+    if args.first().map(String::as_str) == Some("daemon") {
+        return run_daemon().await;
+    }
+
+    // Remote control of a running instance: `reedy ctl <command> [args...]`
+    if args.first().map(String::as_str) == Some("ctl") {
+        return run_ctl(args[1..].to_vec());
+    }
+
+    // Config file helpers: `reedy config init|check`
+    if args.first().map(String::as_str) == Some("config") {
+        return run_config(args[1..].to_vec());
+    }
+
     // Create an application.
     let mut app = App::new().await;
 
@@ -42,6 +65,11 @@ async fn main() -> AppResult<()> {
     let mut tui = Tui::new(terminal, events);
     tui.init()?;
 
+    // Accept `reedy ctl` commands over the control socket, and start the local JSON API
+    #[cfg(unix)]
+    let mut socket_path = App::get_socket_path();
+    let mut services = start_services(&mut app, &tui).await;
+
     // Start the main loop.
     while app.running {
         // Render the user interface.
@@ -52,6 +80,7 @@ async fn main() -> AppResult<()> {
                 app.tick();
                 // Perform auto-refresh if pending
                 app.perform_auto_refresh().await;
+                app.perform_requested_refresh().await;
             }
             Event::Key(key_event) => handle_key_events(key_event, &mut app).await?,
             Event::Mouse(mouse_event) => handle_mouse_events(mouse_event, &mut app).await?,
@@ -59,10 +88,285 @@ async fn main() -> AppResult<()> {
                 app.terminal_width = width;
                 app.terminal_height = height;
             }
+            Event::Api(call) => {
+                let response = api::handle_request(&mut app, &call.request).await;
+                let _ = call.reply.send(response);
+            }
+            Event::Control(call) => {
+                let result =
+                    ctl::dispatch(&mut app, &call.command, call.feed_title.as_deref()).await;
+                let _ = call.reply.send(ctl::format_reply(result));
+            }
+        }
+        // Hand the terminal to programs requested by openers, the pager or the editor
+        if let Some(command) = app.pending_external.take() {
+            tui.suspend().await?;
+            app.run_external_command(command);
+            tui.resume()?;
+        }
+        // Another profile has its own control socket and API settings
+        if app.profile_switched {
+            app.profile_switched = false;
+            // Wait for the listeners to close so the API port can be bound again
+            for task in services {
+                task.abort();
+                let _ = task.await;
+            }
+            #[cfg(unix)]
+            {
+                let _ = std::fs::remove_file(&socket_path);
+                socket_path = App::get_socket_path();
+            }
+            services = start_services(&mut app, &tui).await;
+        }
+        // Ctrl-Z: stop like any other job until the shell resumes us with `fg`
+        if app.suspend_pending {
+            app.suspend_pending = false;
+            tui.suspend().await?;
+            #[cfg(unix)]
+            // SAFETY: raising a signal on the current process has no memory-safety requirements
+            unsafe {
+                libc::raise(libc::SIGTSTP);
+            }
+            tui.resume()?;
         }
     }
 
+    // Keep the command history and read state gathered since the last save
+    app.save_unsaved_state();
+
     // Exit the user interface.
     tui.exit()?;
+    #[cfg(unix)]
+    let _ = std::fs::remove_file(&socket_path);
+    Ok(())
+}
+
+/// Starts listening on the control socket of the current profile and, if enabled, the local
+/// JSON API. Returns their tasks so they can be stopped when switching profiles.
+async fn start_services(
+    app: &mut App,
+    tui: &Tui<CrosstermBackend<io::Stdout>>,
+) -> Vec<JoinHandle<()>> {
+    let mut tasks = Vec::new();
+    #[cfg(unix)]
+    match ctl::start_listener(
+        &App::get_socket_path(),
+        app.config.http_timeout_secs,
+        tui.events.sender(),
+    )
+    .await
+    {
+        Ok(task) => tasks.push(task),
+        Err(e) => log::warn!("Remote control disabled: {}", e),
+    }
+    if app.config.api.enabled {
+        match api::start_server(
+            &app.config.api,
+            app.config.http_timeout_secs,
+            tui.events.sender(),
+        )
+        .await
+        {
+            Ok(task) => tasks.push(task),
+            Err(e) => app.error_message = Some(format!("Failed to start API server: {}", e)),
+        }
+    }
+    tasks
+}
+
+/// Applies `--profile <name>` (default: `$REEDY_PROFILE`), `--config-dir <path>` and
+/// `--data-dir <path>`, which may also be written as `--option=value`, and returns the
+/// remaining arguments. Everything after `ctl` is passed on untouched.
+fn apply_dir_options(args: Vec<String>) -> Vec<String> {
+    let mut profile = env::var("REEDY_PROFILE").ok().filter(|p| !p.is_empty());
+    let mut rest = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        // Arguments of `reedy ctl` belong to the remote command
+        if rest.is_empty() && arg == "ctl" {
+            rest.push(arg);
+            rest.extend(args.by_ref());
+            break;
+        }
+        let (option, inline_value) = match arg.split_once('=') {
+            Some((option, value)) => (option.to_string(), Some(value.to_string())),
+            None => (arg.clone(), None),
+        };
+        if !matches!(option.as_str(), "--profile" | "--config-dir" | "--data-dir") {
+            rest.push(arg);
+            continue;
+        }
+        let Some(value) = inline_value.or_else(|| args.next()) else {
+            eprintln!("{} needs a value", option);
+            std::process::exit(2);
+        };
+        match option.as_str() {
+            "--profile" => profile = Some(value),
+            "--config-dir" => paths::set_config_dir(Some(value.into())),
+            _ => paths::set_data_dir(Some(value.into())),
+        }
+    }
+    if let Some(name) = &profile {
+        if !paths::valid_profile_name(name) {
+            eprintln!(
+                "Invalid profile name \"{}\" (use letters, digits, '-' and '_')",
+                name
+            );
+            std::process::exit(2);
+        }
+    }
+    paths::set_profile(profile);
+    rest
+}
+
+/// Sends a command to a running instance over the control socket and prints the reply.
+/// Arguments containing whitespace are quoted so they arrive as a single argument.
+fn run_ctl(args: Vec<String>) -> AppResult<()> {
+    if args.is_empty() {
+        eprintln!("Usage: reedy ctl <command> [args...]");
+        eprintln!("Commands: add <url> [category], refresh, open-next-unread, search <query>,");
+        eprintln!("          and any ':' command such as read, fav, feeds or q");
+        std::process::exit(2);
+    }
+
+    let command = args
+        .iter()
+        .map(|arg| {
+            if arg.contains(char::is_whitespace) {
+                format!("\"{}\"", arg)
+            } else {
+                arg.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    #[cfg(unix)]
+    {
+        let reply = ctl::send_command(&App::get_socket_path(), &command)?;
+        match reply.strip_prefix("ERR ") {
+            Some(message) => {
+                eprintln!("{}", message);
+                std::process::exit(1);
+            }
+            None => println!("{}", reply.strip_prefix("OK ").unwrap_or(&reply)),
+        }
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = command;
+        Err("reedy ctl is only supported on Unix platforms".into())
+    }
+}
+
+/// `reedy config init [--force]` writes a commented default config.toml;
+/// `reedy config check [path]` validates a config file and reports problems by line.
+fn run_config(args: Vec<String>) -> AppResult<()> {
+    match args.first().map(String::as_str) {
+        Some("init") => {
+            let path = App::get_config_path().with_file_name("config.toml");
+            let force = args.iter().any(|arg| arg == "--force");
+            if path.exists() && !force {
+                eprintln!(
+                    "{} already exists (use --force to replace it)",
+                    path.display()
+                );
+                std::process::exit(1);
+            }
+            // Settings from config.json carry over, as it is ignored once config.toml exists
+            let json = path.with_file_name("config.json");
+            let contents = match std::fs::read_to_string(&json) {
+                Ok(json_contents) => match parse_config(&json_contents, ConfigFormat::Json) {
+                    Ok(config) => Some(config_toml(&config)),
+                    Err(problem) if !force => {
+                        eprintln!("{}: {}", json.display(), problem);
+                        eprintln!(
+                            "Fix it first, or use --force to write the defaults and ignore it"
+                        );
+                        std::process::exit(1);
+                    }
+                    Err(_) => None,
+                },
+                Err(_) => None,
+            };
+            let seeded = contents.is_some();
+            std::fs::write(&path, contents.unwrap_or_else(default_toml))?;
+            if seeded {
+                println!(
+                    "Wrote {} with the settings of {}, which is now ignored",
+                    path.display(),
+                    json.display()
+                );
+            } else {
+                println!("Wrote {}", path.display());
+                if json.exists() {
+                    println!("{} is ignored while config.toml exists", json.display());
+                }
+            }
+            Ok(())
+        }
+        Some("check") => {
+            let path = args
+                .get(1)
+                .map(std::path::PathBuf::from)
+                .unwrap_or_else(App::get_config_path);
+            let contents = match std::fs::read_to_string(&path) {
+                Ok(contents) => contents,
+                Err(e) => {
+                    eprintln!("{}: {}", path.display(), e);
+                    std::process::exit(1);
+                }
+            };
+            let problems = check_config(&contents, ConfigFormat::of(&path));
+            if problems.is_empty() {
+                println!("{}: OK", path.display());
+                return Ok(());
+            }
+            for problem in &problems {
+                eprintln!("{}: {}", path.display(), problem);
+            }
+            std::process::exit(1);
+        }
+        _ => {
+            eprintln!("Usage: reedy config init [--force]");
+            eprintln!("       reedy config check [path]");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Runs reedy without a terminal interface, refreshing feeds on an interval.
+/// Each refresh regenerates the Atom feeds configured under `publish`.
+async fn run_daemon() -> AppResult<()> {
+    let mut app = App::load();
+    let interval = app.daemon_refresh_interval();
+    println!(
+        "reedy daemon: {} feeds, {} published, refreshing every {} minutes",
+        app.rss_feeds.len(),
+        app.config.publish.len(),
+        interval.as_secs() / 60
+    );
+
+    // Publish straight away rather than after the first interval; only expired feeds are fetched
+    if let Err(e) = app.refresh_all_feeds().await {
+        eprintln!("reedy daemon: refresh failed: {}", e);
+    }
+    app.last_refresh = Some(std::time::SystemTime::now());
+
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = tokio::time::sleep(interval) => {
+                if let Err(e) = app.force_refresh_all_feeds().await {
+                    eprintln!("reedy daemon: refresh failed: {}", e);
+                }
+                app.last_refresh = Some(std::time::SystemTime::now());
+            }
+        }
+    }
+
     Ok(())
 }
@@ -0,0 +1,149 @@
+use crate::app::FeedItem;
+
+/// Commands offered by the palette: the command line to run and what it does.
+/// Commands ending in a space take an argument and open command mode to type it.
+pub const PALETTE_COMMANDS: &[(&str, &str)] = &[
+    ("refresh", "Refresh all feeds"),
+    ("feeds", "Open the Feed Manager"),
+    ("favorites", "Toggle the favorites view"),
+    ("read", "Mark all articles as read"),
+    ("tags", "List tags"),
+    ("categories", "List categories with unread counts"),
+    ("rules", "Manage mute rules"),
+    ("find ", "Search every indexed article"),
+    ("cat ", "Show one category"),
+    ("tag ", "Show articles with a tag"),
+    (
+        "sort ",
+        "Sort this view by newest, oldest, feed, title, unread or favorites; empty shows it",
+    ),
+    ("group ", "Group this view by day, feed or category"),
+    ("mute ", "Hide articles matching a query"),
+    ("savesearch ", "Save the current search"),
+    (
+        "add ",
+        "Subscribe to a feed URL, optionally into a category",
+    ),
+    ("delete", "Delete the open feed"),
+    ("rename ", "Rename the open feed"),
+    ("filter ", "Filter the articles with a search query"),
+    ("open ", "Open the n-th article"),
+    ("set ", "Change a setting (key=value)"),
+    (
+        "export ",
+        "Write the articles as JSON or the feeds as OPML to a file",
+    ),
+    ("help", "Show keybindings"),
+    ("quit", "Quit Reedy"),
+];
+
+/// Where a palette entry leads
+#[derive(Debug, Clone)]
+pub enum PaletteTarget {
+    /// A subscribed feed, by URL
+    Feed(String),
+    /// A category path
+    Category(String),
+    /// A saved search, by name
+    SavedSearch(String),
+    /// A command line, without the leading ':'
+    Command(String),
+    /// A recent article
+    Article(Box<FeedItem>),
+}
+
+/// One candidate shown in the palette
+#[derive(Debug, Clone)]
+pub struct PaletteEntry {
+    /// Text the query is matched against
+    pub label: String,
+    /// Extra context shown dimmed after the label (category, feed title, ...)
+    pub detail: String,
+    pub target: PaletteTarget,
+}
+
+impl PaletteEntry {
+    /// Short name of the entry's kind, shown in front of the label
+    pub fn kind(&self) -> &'static str {
+        match self.target {
+            PaletteTarget::Feed(_) => "feed",
+            PaletteTarget::Category(_) => "cat",
+            PaletteTarget::SavedSearch(_) => "search",
+            PaletteTarget::Command(_) => "cmd",
+            PaletteTarget::Article(_) => "article",
+        }
+    }
+}
+
+/// Fuzzy-matches `pattern` against `text`: every pattern character must appear in order,
+/// ignoring case. Returns a score (higher is better) and the char indices of the matched
+/// characters, or None if the text does not match.
+///
+/// Matches score more when they are consecutive, start a word or start the text, and less
+/// the more characters they skip.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+    let pattern: Vec<char> = pattern
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if pattern.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    // Try every place the first character occurs and keep the best greedy match
+    let mut best: Option<(i64, Vec<usize>)> = None;
+    for start in (0..lower.len()).filter(|&i| lower[i] == pattern[0]) {
+        let mut positions = vec![start];
+        let mut next = start + 1;
+        for &wanted in &pattern[1..] {
+            match (next..lower.len()).find(|&i| lower[i] == wanted) {
+                Some(i) => {
+                    positions.push(i);
+                    next = i + 1;
+                }
+                None => break,
+            }
+        }
+        if positions.len() < pattern.len() {
+            // Later starts cannot find the rest either
+            break;
+        }
+        let score = score_positions(&chars, &positions);
+        if best.as_ref().is_none_or(|(b, _)| score > *b) {
+            best = Some((score, positions));
+        }
+    }
+    best
+}
+
+fn score_positions(chars: &[char], positions: &[usize]) -> i64 {
+    let mut score = 0;
+    for (n, &i) in positions.iter().enumerate() {
+        score += 16;
+        let word_start = i == 0
+            || !chars[i - 1].is_alphanumeric()
+            || (chars[i - 1].is_lowercase() && chars[i].is_uppercase());
+        if word_start {
+            score += 10;
+        }
+        if i == 0 {
+            score += 8;
+        }
+        if n > 0 {
+            let gap = i - positions[n - 1] - 1;
+            if gap == 0 {
+                score += 12;
+            } else {
+                score -= gap.min(8) as i64;
+            }
+        }
+    }
+    // Shorter texts are a closer match
+    score - (chars.len() as i64 / 8)
+}
@@ -0,0 +1,136 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use log::error;
+
+/// Name of the profile that uses the top-level directories
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Where reedy keeps its files, as chosen on the command line
+#[derive(Debug)]
+struct Dirs {
+    profile: Option<String>,
+    config_dir: Option<PathBuf>,
+    data_dir: Option<PathBuf>,
+}
+
+static DIRS: RwLock<Dirs> = RwLock::new(Dirs {
+    profile: None,
+    config_dir: None,
+    data_dir: None,
+});
+
+/// Profile names become directory names, so they are limited to letters, digits, '-' and '_'
+pub fn valid_profile_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Selects the profile whose files are used from now on ("default" or `None` for the
+/// top-level directories)
+pub fn set_profile(profile: Option<String>) {
+    let profile = profile.filter(|name| name != DEFAULT_PROFILE);
+    if let Ok(mut dirs) = DIRS.write() {
+        dirs.profile = profile;
+    }
+}
+
+/// Replaces `~/.config/reedy` as the directory holding the config, feeds and OPML files
+pub fn set_config_dir(path: Option<PathBuf>) {
+    if let Ok(mut dirs) = DIRS.write() {
+        dirs.config_dir = path;
+    }
+}
+
+/// Replaces the data and cache directories (exports, logs, feed cache and search index)
+pub fn set_data_dir(path: Option<PathBuf>) {
+    if let Ok(mut dirs) = DIRS.write() {
+        dirs.data_dir = path;
+    }
+}
+
+/// The active profile's name
+pub fn profile() -> String {
+    DIRS.read()
+        .ok()
+        .and_then(|dirs| dirs.profile.clone())
+        .unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+}
+
+/// Appends the active profile's subdirectory to a base directory
+fn with_profile(mut path: PathBuf) -> PathBuf {
+    if let Some(profile) = DIRS.read().ok().and_then(|dirs| dirs.profile.clone()) {
+        path.push("profiles");
+        path.push(profile);
+    }
+    path
+}
+
+/// Base config directory shared by all profiles
+fn config_base() -> PathBuf {
+    DIRS.read()
+        .ok()
+        .and_then(|dirs| dirs.config_dir.clone())
+        .unwrap_or_else(|| {
+            dirs::config_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("reedy")
+        })
+}
+
+/// Directory of the active profile's config file, feeds and OPML export (created if missing)
+pub fn config_dir() -> PathBuf {
+    let path = with_profile(config_base());
+    if let Err(e) = fs::create_dir_all(&path) {
+        error!("Failed to create config directory {:?}: {}", path, e);
+    }
+    path
+}
+
+/// Directory of the active profile's exports and logs (not created)
+pub fn data_dir() -> PathBuf {
+    let base = DIRS
+        .read()
+        .ok()
+        .and_then(|dirs| dirs.data_dir.clone())
+        .unwrap_or_else(|| {
+            dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("reedy")
+        });
+    with_profile(base)
+}
+
+/// Directory of the active profile's feed cache and search index (not created)
+pub fn cache_dir() -> PathBuf {
+    let base = DIRS
+        .read()
+        .ok()
+        .and_then(|dirs| dirs.data_dir.as_ref().map(|dir| dir.join("cache")))
+        .unwrap_or_else(|| {
+            dirs::cache_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("reedy")
+        });
+    with_profile(base)
+}
+
+/// Every profile with a config directory, plus the default one, sorted by name
+pub fn profiles() -> Vec<String> {
+    let mut profiles = vec![DEFAULT_PROFILE.to_string()];
+    if let Ok(entries) = fs::read_dir(config_base().join("profiles")) {
+        profiles.extend(
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|name| valid_profile_name(name)),
+        );
+    }
+    profiles.sort();
+    profiles.dedup();
+    profiles
+}
@@ -0,0 +1,456 @@
+use chrono::{Local, NaiveDate, TimeZone};
+use regex::{Regex, RegexBuilder};
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime};
+
+use crate::app::{category_in_path, App, FeedItem};
+
+/// A parsed search query such as `feed:"Hacker News" is:unread after:7d -sponsored`.
+///
+/// Terms next to each other are combined with AND; `OR`, `AND` and `NOT` (upper case)
+/// and parentheses can be used to build other combinations.
+#[derive(Debug, Clone)]
+pub enum Query {
+    /// Case-insensitive substring of the title or description
+    Text(String),
+    /// `title:word` - case-insensitive substring of the title
+    Title(String),
+    /// `title:/regex/` - case-insensitive regex on the title
+    TitleRegex(Regex),
+    /// `desc:word` - case-insensitive substring of the description
+    Description(String),
+    /// `desc:/regex/` - case-insensitive regex on the description
+    DescriptionRegex(Regex),
+    /// `domain:example.com` - link host is the domain or one of its subdomains
+    Domain(String),
+    /// `feed:name` - case-insensitive substring of the feed title
+    Feed(String),
+    /// `cat:name` - feed category or anything nested below it, or a feed tag (case-insensitive)
+    Category(String),
+    /// `tag:name` - article carries the user tag
+    Tag(String),
+    /// `is:unread`, `is:read`, `is:fav` or `is:tagged`
+    Is(ItemFlag),
+    /// `after:2026-01-01` or `after:7d` - published at or after the given time
+    After(SystemTime),
+    /// `before:2026-01-01` or `before:7d` - published before the given time
+    Before(SystemTime),
+    Not(Box<Query>),
+    And(Vec<Query>),
+    Or(Vec<Query>),
+}
+
+/// Per-item state that can be queried with `is:`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ItemFlag {
+    Read,
+    Unread,
+    Favorite,
+    Tagged,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Term {
+        negated: bool,
+        key: Option<String>,
+        value: String,
+        regex: bool,
+    },
+}
+
+/// Prefixes understood as `key:value` filters; anything else is searched as plain text
+const KEYS: &[&str] = &[
+    "feed",
+    "cat",
+    "category",
+    "is",
+    "after",
+    "before",
+    "title",
+    "desc",
+    "description",
+    "domain",
+    "tag",
+];
+
+impl Query {
+    /// Parses a query string. Returns `Ok(None)` for an empty query.
+    pub fn parse(input: &str) -> Result<Option<Query>, String> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            return Ok(None);
+        }
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            now: SystemTime::now(),
+        };
+        let query = parser.parse_or()?;
+        match parser.peek() {
+            None => Ok(Some(query)),
+            Some(Token::Close) => Err("Unexpected ')'".to_string()),
+            Some(token) => Err(format!("Unexpected {:?}", token)),
+        }
+    }
+
+    /// Returns true if the item matches this query
+    pub fn matches(&self, item: &FeedItem, app: &App) -> bool {
+        match self {
+            Query::Text(text) => {
+                item.title.to_lowercase().contains(text)
+                    || item.description.to_lowercase().contains(text)
+            }
+            Query::Title(text) => item.title.to_lowercase().contains(text),
+            Query::TitleRegex(re) => re.is_match(&item.title),
+            Query::Description(text) => item.description.to_lowercase().contains(text),
+            Query::DescriptionRegex(re) => re.is_match(&item.description),
+            Query::Domain(domain) => link_host(&item.link)
+                .is_some_and(|host| host == *domain || host.ends_with(&format!(".{}", domain))),
+            Query::Feed(name) => feed_title(item, app).to_lowercase().contains(name),
+            // Category paths are compared in lower case here, as searches ignore case
+            Query::Category(name) => {
+                app.rss_feeds
+                    .iter()
+                    .find(|feed| feed.url == item.feed_url)
+                    .is_some_and(|feed| {
+                        feed.category.as_deref().is_some_and(|category| {
+                            category_in_path(&category.to_lowercase(), name)
+                        }) || feed.tags.iter().any(|tag| tag == name)
+                    })
+            }
+            Query::Is(ItemFlag::Read) => app.is_item_read(item),
+            Query::Is(ItemFlag::Unread) => !app.is_item_read(item),
+            Query::Tag(tag) => app.item_tags(&item.id).any(|t| t == tag),
+            Query::Is(ItemFlag::Favorite) => app.favorites.contains(&item.id),
+            Query::Is(ItemFlag::Tagged) => app.item_tags(&item.id).next().is_some(),
+            Query::After(time) => item.published.is_some_and(|p| p >= *time),
+            Query::Before(time) => item.published.is_some_and(|p| p < *time),
+            Query::Not(query) => !query.matches(item, app),
+            Query::And(queries) => queries.iter().all(|q| q.matches(item, app)),
+            Query::Or(queries) => queries.iter().any(|q| q.matches(item, app)),
+        }
+    }
+}
+
+/// Title of the item's feed, falling back to the "Article | Feed" title suffix
+fn feed_title<'a>(item: &'a FeedItem, app: &'a App) -> &'a str {
+    app.rss_feeds
+        .iter()
+        .find(|f| f.url == item.feed_url)
+        .map(|f| f.title.as_str())
+        .or_else(|| item.title.rsplit_once(" | ").map(|(_, feed)| feed))
+        .unwrap_or("")
+}
+
+/// Lower-cased host part of a URL, without port or credentials
+fn link_host(link: &str) -> Option<String> {
+    let rest = link.split_once("://").map_or(link, |(_, rest)| rest);
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let host = authority.rsplit('@').next()?.split(':').next()?;
+    (!host.is_empty()).then(|| host.to_lowercase())
+}
+
+/// Splits a query into tokens, keeping quoted values and `/regex/` values intact
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if c == '(' {
+            tokens.push(Token::Open);
+            i += 1;
+            continue;
+        }
+        if c == ')' {
+            tokens.push(Token::Close);
+            i += 1;
+            continue;
+        }
+
+        let negated = c == '-' && chars.get(i + 1).is_some_and(|n| !n.is_whitespace());
+        if negated {
+            i += 1;
+        }
+        // "-(...)" negates a whole group
+        if negated && chars[i] == '(' {
+            tokens.push(Token::Not);
+            continue;
+        }
+
+        // Quoted phrase
+        if chars[i] == '"' {
+            let (value, next) = read_quoted(&chars, i)?;
+            tokens.push(Token::Term {
+                negated,
+                key: None,
+                value,
+                regex: false,
+            });
+            i = next;
+            continue;
+        }
+
+        // Read up to a ':' to check for a known key
+        let start = i;
+        while i < chars.len() && !is_boundary(chars[i]) && chars[i] != ':' && chars[i] != '"' {
+            i += 1;
+        }
+        let word: String = chars[start..i].iter().collect();
+        let key = word.to_lowercase();
+
+        if i < chars.len() && chars[i] == ':' && KEYS.contains(&key.as_str()) {
+            i += 1;
+            let (value, regex, next) = match chars.get(i) {
+                Some('"') => {
+                    let (value, next) = read_quoted(&chars, i)?;
+                    (value, false, next)
+                }
+                Some('/') if matches!(key.as_str(), "title" | "desc" | "description") => {
+                    let (value, next) = read_regex(&chars, i)?;
+                    (value, true, next)
+                }
+                _ => {
+                    let start = i;
+                    while i < chars.len() && !is_boundary(chars[i]) {
+                        i += 1;
+                    }
+                    (chars[start..i].iter().collect(), false, i)
+                }
+            };
+            if value.is_empty() {
+                return Err(format!("Missing value after '{}:'", key));
+            }
+            tokens.push(Token::Term {
+                negated,
+                key: Some(key),
+                value,
+                regex,
+            });
+            i = next;
+            continue;
+        }
+
+        // Plain word (which may itself contain ':' or quotes, e.g. a URL)
+        while i < chars.len() && !is_boundary(chars[i]) {
+            i += 1;
+        }
+        let word: String = chars[start..i].iter().collect();
+        match word.as_str() {
+            "OR" if !negated => tokens.push(Token::Or),
+            "AND" if !negated => tokens.push(Token::And),
+            "NOT" if !negated => tokens.push(Token::Not),
+            _ => tokens.push(Token::Term {
+                negated,
+                key: None,
+                value: word,
+                regex: false,
+            }),
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn is_boundary(c: char) -> bool {
+    c.is_whitespace() || c == '(' || c == ')'
+}
+
+/// Reads a double-quoted string starting at `start`, returning it and the index after it
+fn read_quoted(chars: &[char], start: usize) -> Result<(String, usize), String> {
+    let mut value = String::new();
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if chars.get(i + 1) == Some(&'"') => {
+                value.push('"');
+                i += 2;
+            }
+            '"' => return Ok((value, i + 1)),
+            c => {
+                value.push(c);
+                i += 1;
+            }
+        }
+    }
+    Err("Unterminated quote".to_string())
+}
+
+/// Reads a `/regex/` starting at `start`; `\/` escapes a slash inside the pattern
+fn read_regex(chars: &[char], start: usize) -> Result<(String, usize), String> {
+    let mut value = String::new();
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if chars.get(i + 1) == Some(&'/') => {
+                value.push('/');
+                i += 2;
+            }
+            '/' => return Ok((value, i + 1)),
+            c => {
+                value.push(c);
+                i += 1;
+            }
+        }
+    }
+    Err("Unterminated regex (missing closing '/')".to_string())
+}
+
+/// Recursive-descent parser: OR binds loosest, then AND (explicit or implied), then NOT
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    now: SystemTime,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<Query, String> {
+        let mut terms = vec![self.parse_and()?];
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            terms.push(self.parse_and()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Query::Or(terms)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Query, String> {
+        let mut terms = vec![self.parse_unary()?];
+        loop {
+            match self.peek() {
+                None | Some(Token::Close) | Some(Token::Or) => break,
+                Some(Token::And) => {
+                    self.pos += 1;
+                    terms.push(self.parse_unary()?);
+                }
+                Some(_) => terms.push(self.parse_unary()?),
+            }
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Query::And(terms)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<Query, String> {
+        match self.next() {
+            Some(Token::Not) => Ok(Query::Not(Box::new(self.parse_unary()?))),
+            Some(Token::Open) => {
+                let query = self.parse_or()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(query),
+                    _ => Err("Missing ')'".to_string()),
+                }
+            }
+            Some(Token::Term {
+                negated,
+                key,
+                value,
+                regex,
+            }) => {
+                let query = self.build_term(key.as_deref(), &value, regex)?;
+                Ok(if negated {
+                    Query::Not(Box::new(query))
+                } else {
+                    query
+                })
+            }
+            Some(Token::Close) => Err("Unexpected ')'".to_string()),
+            Some(Token::And) | Some(Token::Or) => {
+                Err("AND/OR needs a term on both sides".to_string())
+            }
+            None => Err("Query ends unexpectedly".to_string()),
+        }
+    }
+
+    fn build_term(&self, key: Option<&str>, value: &str, regex: bool) -> Result<Query, String> {
+        let lower = value.to_lowercase();
+        match key {
+            None => Ok(Query::Text(lower)),
+            Some("title") if regex => RegexBuilder::new(value)
+                .case_insensitive(true)
+                .build()
+                .map(Query::TitleRegex)
+                .map_err(|e| format!("Invalid regex /{}/: {}", value, e)),
+            Some("title") => Ok(Query::Title(lower)),
+            Some("desc") | Some("description") if regex => RegexBuilder::new(value)
+                .case_insensitive(true)
+                .build()
+                .map(Query::DescriptionRegex)
+                .map_err(|e| format!("Invalid regex /{}/: {}", value, e)),
+            Some("desc") | Some("description") => Ok(Query::Description(lower)),
+            Some("domain") => Ok(Query::Domain(lower.trim_start_matches("www.").to_string())),
+            Some("feed") => Ok(Query::Feed(lower)),
+            Some("cat") | Some("category") => Ok(Query::Category(lower)),
+            Some("tag") => Ok(Query::Tag(lower)),
+            Some("is") => match lower.as_str() {
+                "unread" | "new" => Ok(Query::Is(ItemFlag::Unread)),
+                "read" => Ok(Query::Is(ItemFlag::Read)),
+                "fav" | "favorite" | "favourite" | "starred" => Ok(Query::Is(ItemFlag::Favorite)),
+                "tagged" => Ok(Query::Is(ItemFlag::Tagged)),
+                _ => Err(format!(
+                    "Unknown 'is:{}' (use unread, read, fav or tagged)",
+                    value
+                )),
+            },
+            Some("after") => parse_time(value, self.now).map(Query::After),
+            Some("before") => parse_time(value, self.now).map(Query::Before),
+            Some(other) => Err(format!("Unknown filter '{}:'", other)),
+        }
+    }
+}
+
+/// Parses an absolute date (`2026-01-01`, local midnight) or a relative age
+/// (`12h`, `7d`, `2w`, `3m`, `1y`) counted back from `now`
+fn parse_time(value: &str, now: SystemTime) -> Result<SystemTime, String> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return date
+            .and_hms_opt(0, 0, 0)
+            .and_then(|dt| Local.from_local_datetime(&dt).earliest())
+            .map(SystemTime::from)
+            .ok_or_else(|| format!("Invalid date '{}'", value));
+    }
+
+    static RELATIVE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^(\d+)([hdwmy])$").expect("valid relative date pattern"));
+    let caps = RELATIVE
+        .captures(value)
+        .ok_or_else(|| format!("Invalid date '{}' (use YYYY-MM-DD or e.g. 7d)", value))?;
+    let amount: u64 = caps[1]
+        .parse()
+        .map_err(|_| format!("Invalid date '{}'", value))?;
+    let hours = match &caps[2] {
+        "h" => 1,
+        "d" => 24,
+        "w" => 24 * 7,
+        "m" => 24 * 30,
+        _ => 24 * 365,
+    };
+    now.checked_sub(Duration::from_secs(amount.saturating_mul(hours * 3600)))
+        .ok_or_else(|| format!("Date '{}' is out of range", value))
+}
@@ -65,6 +65,26 @@ impl<B: Backend> Tui<B> {
         Ok(())
     }
 
+    /// Hands the terminal over to another program.
+    ///
+    /// Input handling is paused and the terminal properties are reverted until [`Tui::resume`].
+    pub async fn suspend(&mut self) -> AppResult<()> {
+        self.events.pause().await;
+        Self::reset()?;
+        self.terminal.show_cursor()?;
+        Ok(())
+    }
+
+    /// Takes the terminal back after [`Tui::suspend`] and forces a full redraw.
+    pub fn resume(&mut self) -> AppResult<()> {
+        terminal::enable_raw_mode()?;
+        crossterm::execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+        self.terminal.hide_cursor()?;
+        self.terminal.clear()?;
+        self.events.resume();
+        Ok(())
+    }
+
     /// Exits the terminal interface.
     ///
     /// It disables the raw mode and reverts back the terminal properties.
@@ -3,16 +3,25 @@ use ratatui::{
     prelude::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
     Frame,
 };
 
-use crate::app::{App, FeedStatus, InputMode, PageMode, Theme};
+use crate::app::{
+    App, CategoryScope, FeedManagerRow, FeedSort, FeedStatus, InputMode, MuteAction, PageMode,
+    Theme, ViewOrder,
+};
 use chrono::{DateTime, Local};
 
-/// Converts a color name string to a ratatui Color
+/// Converts a color name string to a ratatui Color, falling back to white
 pub fn parse_color(color_name: &str) -> Color {
-    match color_name.to_lowercase().as_str() {
+    try_parse_color(color_name).unwrap_or(Color::White)
+}
+
+/// Converts a color name (e.g. "dark_gray") or hex code ("#ff5500" or "ff5500") to a
+/// ratatui Color, or None if it is not a known color
+pub fn try_parse_color(color_name: &str) -> Option<Color> {
+    Some(match color_name.to_lowercase().as_str() {
         "black" => Color::Black,
         "red" => Color::Red,
         "green" => Color::Green,
@@ -29,31 +38,18 @@ pub fn parse_color(color_name: &str) -> Color {
         "light_magenta" | "lightmagenta" => Color::LightMagenta,
         "light_cyan" | "lightcyan" => Color::LightCyan,
         "white" => Color::White,
-        // Support hex colors like "#ff5500" or "ff5500"
-        s if s.starts_with('#') && s.len() == 7 => {
-            if let (Ok(r), Ok(g), Ok(b)) = (
-                u8::from_str_radix(&s[1..3], 16),
-                u8::from_str_radix(&s[3..5], 16),
-                u8::from_str_radix(&s[5..7], 16),
-            ) {
-                Color::Rgb(r, g, b)
-            } else {
-                Color::White // fallback
-            }
-        }
-        s if s.len() == 6 => {
-            if let (Ok(r), Ok(g), Ok(b)) = (
-                u8::from_str_radix(&s[0..2], 16),
-                u8::from_str_radix(&s[2..4], 16),
-                u8::from_str_radix(&s[4..6], 16),
-            ) {
-                Color::Rgb(r, g, b)
-            } else {
-                Color::White // fallback
+        s => {
+            let hex = s.strip_prefix('#').unwrap_or(s);
+            if hex.len() != 6 || !hex.is_ascii() {
+                return None;
             }
+            Color::Rgb(
+                u8::from_str_radix(&hex[0..2], 16).ok()?,
+                u8::from_str_radix(&hex[2..4], 16).ok()?,
+                u8::from_str_radix(&hex[4..6], 16).ok()?,
+            )
         }
-        _ => Color::White, // default fallback
-    }
+    })
 }
 
 /// Helper struct to hold resolved theme colors for rendering
@@ -66,6 +62,7 @@ struct ThemeColors {
     highlight: Color,
     description: Color,
     category: Color,
+    alert: Color,
 }
 
 impl ThemeColors {
@@ -79,6 +76,7 @@ impl ThemeColors {
             highlight: parse_color(&theme.highlight),
             description: parse_color(&theme.description),
             category: parse_color(&theme.category),
+            alert: parse_color(&theme.alert),
         }
     }
 }
@@ -101,6 +99,8 @@ pub fn render(app: &App, frame: &mut Frame) {
         PageMode::FeedList => "Reedy",
         PageMode::FeedManager => "Feed Manager",
         PageMode::Favorites => "Favorites",
+        PageMode::MuteRules => "Mute Rules",
+        PageMode::Settings => "Settings",
     };
 
     // Add indicators for active filters and auto-refresh
@@ -162,23 +162,111 @@ pub fn render(app: &App, frame: &mut Frame) {
     // If we're in help mode, render the help menu instead of the regular content
     if app.input_mode == InputMode::Help {
         render_help_menu(app, frame, chunks[1], &colors);
-    } else if app.input_mode == InputMode::Preview {
+    } else if app.input_mode == InputMode::Preview
+        || (app.input_mode == InputMode::Tagging && app.tagging_from_preview)
+    {
         render_article_preview(app, frame, chunks[1], &colors);
     } else {
         match app.page_mode {
             PageMode::FeedList => render_feed_content(app, frame, chunks[1], &colors),
             PageMode::FeedManager => render_feed_manager(app, frame, chunks[1], &colors),
             PageMode::Favorites => render_feed_content(app, frame, chunks[1], &colors),
+            PageMode::MuteRules => render_mute_rules(app, frame, chunks[1], &colors),
+            PageMode::Settings => render_settings(app, frame, chunks[1], &colors),
         }
     }
 
+    // The jump palette floats over the page
+    if app.input_mode == InputMode::Palette {
+        render_palette(app, frame, chunks[1], &colors);
+    }
+
     // Render the command bar with our new function
     render_command_bar(app, frame, chunks[2], &colors);
 }
 
+/// Renders the jump palette: the query on top, then the ranked matches with the matched
+/// characters highlighted
+fn render_palette(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColors) {
+    let width = (area.width * 3 / 4).max(40).min(area.width);
+    let height = area.height.saturating_sub(2).clamp(3, 20);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + 1,
+        width,
+        height,
+    };
+
+    let matches = app.palette_matches();
+    let visible_rows = popup.height.saturating_sub(3) as usize;
+    // Keep the selection in view
+    let skip = app
+        .palette_selected
+        .saturating_sub(visible_rows.saturating_sub(1));
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled("> ", Style::default().fg(colors.highlight)),
+        Span::styled(
+            format!("{}█", app.palette_query),
+            Style::default().fg(colors.text),
+        ),
+    ])];
+    for (i, (entry, positions)) in matches.iter().enumerate().skip(skip).take(visible_rows) {
+        let selected = i == app.palette_selected;
+        let base = if selected {
+            Style::default()
+                .fg(colors.secondary)
+                .add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().fg(colors.text)
+        };
+        let mut spans = vec![Span::styled(
+            format!("{:<8}", entry.kind()),
+            base.fg(colors.muted),
+        )];
+        let label_width = (popup.width as usize).saturating_sub(12);
+        for (n, c) in entry.label.chars().take(label_width).enumerate() {
+            let style = if positions.contains(&n) {
+                base.fg(colors.highlight).add_modifier(Modifier::BOLD)
+            } else {
+                base
+            };
+            spans.push(Span::styled(c.to_string(), style));
+        }
+        if !entry.detail.is_empty() {
+            let room = label_width.saturating_sub(entry.label.chars().count() + 2);
+            if room > 3 {
+                spans.push(Span::styled(
+                    format!("  {}", truncate_text(&entry.detail, room as u16)),
+                    base.fg(colors.muted),
+                ));
+            }
+        }
+        lines.push(Line::from(spans));
+    }
+    if matches.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No matches",
+            Style::default().fg(colors.muted),
+        )));
+    }
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .title(format!("Jump to ({} matches)", matches.len()))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.primary)),
+        ),
+        popup,
+    );
+}
+
 fn render_feed_content(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColors) {
-    // Calculate how many items can fit per page (each item takes 3 lines plus a separator)
-    let items_per_page = ((area.height as usize).saturating_sub(2) / 3).max(1); // Ensure at least 1 item per page
+    // Calculate how many items can fit per page (each item takes 3 lines, each group header 1)
+    let start_idx = app.scroll as usize;
+    let items_per_page = app.items_fitting(start_idx, (area.height as usize).saturating_sub(2));
 
     // Get visible items (filtered or all)
     let visible_items = app.get_visible_items();
@@ -186,27 +274,45 @@ fn render_feed_content(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeC
     let total_items = app.current_feed_content.len();
 
     // Calculate the visible range for items
-    let start_idx = app.scroll as usize;
     let end_idx = (start_idx + items_per_page).min(total_visible);
+    let highlight_rules = app.compiled_highlight_rules();
+    let marking = app.has_marked_items();
+    let visual_items = app.visual_item_ids();
 
     let items: Vec<ListItem> = visible_items
         .iter()
         .enumerate()
         .skip(start_idx)
         .take(items_per_page)
-        .map(|(visible_idx, (_actual_idx, item))| {
+        .flat_map(|(visible_idx, (_actual_idx, item))| {
+            let header = app.group_header(visible_idx).map(|label| {
+                ListItem::new(Line::from(Span::styled(
+                    format!("── {} ──", label),
+                    Style::default()
+                        .fg(colors.category)
+                        .add_modifier(Modifier::BOLD),
+                )))
+            });
+            let highlight = app.highlight_rule_for(item, &highlight_rules);
             let style = if Some(visible_idx) == app.selected_index {
                 Style::default()
                     .fg(colors.secondary)
                     .add_modifier(Modifier::REVERSED)
             } else if app.is_item_read(item) {
                 Style::default().fg(colors.muted)
+            } else if let Some(rule) = highlight {
+                let color = rule.color.as_deref().map_or(colors.alert, parse_color);
+                Style::default().fg(color)
             } else {
                 Style::default().fg(colors.text)
             };
 
             let favorite_indicator = if app.is_item_favorite(item) {
                 "★ "
+            } else if highlight.is_some_and(|rule| rule.pin) {
+                "▲ "
+            } else if highlight.is_some() {
+                "! "
             } else {
                 "  "
             };
@@ -223,19 +329,41 @@ fn render_feed_content(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeC
             let title_max_width = area.width.saturating_sub(10) as usize; // Account for favorite icon, read status and spacing
             let desc_max_width = area.width.saturating_sub(6) as usize; // Account for indentation and borders
 
+            // Tags are shown as chips after the title
+            let tags: Vec<&str> = app.item_tags(&item.id).collect();
+            let chips_width: usize = tags.iter().map(|tag| tag.chars().count() + 3).sum();
+
             // Truncate title and description
-            let truncated_title = truncate_text(&item.title, title_max_width as u16);
+            let truncated_title = truncate_text(
+                &item.title,
+                title_max_width.saturating_sub(chips_width) as u16,
+            );
             let truncated_desc = truncate_text(&item.description, desc_max_width as u16);
 
-            ListItem::new(vec![
-                Line::from(vec![
-                    Span::styled(favorite_indicator, style),
-                    Span::styled(
-                        format!("[{}] ", if app.is_item_read(item) { "✓" } else { " " }),
-                        style,
-                    ),
-                    Span::styled(truncated_title, style.add_modifier(Modifier::BOLD)),
-                ]),
+            let mut title_line = Vec::new();
+            if marking {
+                // Marked articles (and those in the visual range) get a bullet for bulk actions
+                title_line.push(Span::styled(
+                    if app.is_item_marked(&item.id, &visual_items) {
+                        "● "
+                    } else {
+                        "  "
+                    },
+                    Style::default().fg(colors.highlight),
+                ));
+            }
+            title_line.extend([
+                Span::styled(favorite_indicator, style),
+                Span::styled(
+                    format!("[{}] ", if app.is_item_read(item) { "✓" } else { " " }),
+                    style,
+                ),
+                Span::styled(truncated_title, style.add_modifier(Modifier::BOLD)),
+            ]);
+            title_line.extend(tag_chips(&tags, colors));
+
+            let entry = ListItem::new(vec![
+                Line::from(title_line),
                 Line::from(vec![
                     Span::raw("   "),
                     Span::styled(date_str, Style::default().fg(colors.secondary)),
@@ -244,7 +372,8 @@ fn render_feed_content(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeC
                     Span::raw("   "),
                     Span::styled(truncated_desc, Style::default().fg(colors.description)),
                 ]),
-            ])
+            ]);
+            header.into_iter().chain(std::iter::once(entry))
         })
         .collect();
 
@@ -263,7 +392,40 @@ fn render_feed_content(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeC
     };
 
     // Build title with filter indicator if active
-    let title = if app.filtered_indices.is_some() {
+    let title = if let Some(tag) = &app.active_tag {
+        format!(
+            "Feed Content [Tag: {}] (Page {}/{}, Items {}-{}/{} of {})",
+            tag,
+            current_page,
+            page_count,
+            if total_visible == 0 { 0 } else { start_idx + 1 },
+            end_idx,
+            total_visible,
+            total_items
+        )
+    } else if app.search_everything {
+        format!(
+            "Feed Content [Everything: \"{}\"] (Page {}/{}, Items {}-{}/{} of {} indexed)",
+            app.search_query,
+            current_page,
+            page_count,
+            if total_visible == 0 { 0 } else { start_idx + 1 },
+            end_idx,
+            total_visible,
+            app.search_index.len()
+        )
+    } else if let Some(name) = &app.active_saved_search {
+        format!(
+            "Feed Content [Saved: {}] (Page {}/{}, Items {}-{}/{} of {})",
+            name,
+            current_page,
+            page_count,
+            if total_visible == 0 { 0 } else { start_idx + 1 },
+            end_idx,
+            total_visible,
+            total_items
+        )
+    } else if app.has_active_filter() {
         format!(
             "Feed Content [Filter: \"{}\"] (Page {}/{}, Items {}-{}/{} of {})",
             app.search_query,
@@ -284,6 +446,45 @@ fn render_feed_content(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeC
             total_visible
         )
     };
+    let title = if app.category_scope != CategoryScope::All {
+        let (unread, total) = app.count_for_scope(&app.category_scope);
+        format!(
+            "{} [Category: {} {}/{} unread]",
+            title,
+            app.category_scope.label(),
+            unread,
+            total
+        )
+    } else {
+        title
+    };
+    let title = if app.muted_count > 0 {
+        format!("{} [+{} muted]", title, app.muted_count)
+    } else {
+        title
+    };
+    let title = if marking {
+        let marked = app.marked_item_count();
+        let mode = if app.item_visual_start.is_some() {
+            "VISUAL, "
+        } else {
+            ""
+        };
+        format!("{} [{}{} marked]", title, mode, marked)
+    } else {
+        title
+    };
+    let order = app.view_order();
+    let title = if order != ViewOrder::default() {
+        format!(
+            "{} [Sort: {}, group: {}]",
+            title,
+            order.sort.label(),
+            order.group.label()
+        )
+    } else {
+        title
+    };
 
     let list = List::new(items)
         .block(Block::default().title(title).borders(Borders::ALL))
@@ -301,86 +502,194 @@ fn render_feed_manager(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeC
         ])
         .split(area);
 
-    // Get feeds grouped by category
-    let feeds_by_category = app.get_feeds_by_category();
-
-    // Build list items with category headers
+    // Build list items from the Feed Manager tree; rows above the scroll offset are skipped
     let mut items: Vec<ListItem> = Vec::new();
-    let mut feed_index = 0;
-
-    for (category, feeds) in &feeds_by_category {
-        // Add category header
-        let header_text = match category {
-            Some(cat) => format!("── {} ──", cat),
-            None => "── Uncategorized ──".to_string(),
-        };
-        items.push(ListItem::new(Line::from(Span::styled(
-            header_text,
+    let mut feed_number = 0;
+    let mut saved_search_header = false;
+    let marking = app.has_marked_feeds();
+    // Built once per frame: sorting the feeds is not free
+    let rows = app.feed_manager_rows();
+    let visual_feeds = app.visual_feed_indices_in(&rows);
+
+    for (row, entry) in rows.iter().enumerate() {
+        let visible = row >= app.scroll as usize;
+        let style = if Some(row) == app.selected_index {
             Style::default()
-                .fg(colors.category)
-                .add_modifier(Modifier::BOLD),
-        ))));
-
-        // Add feeds in this category
-        for feed_info in feeds {
-            let style = if Some(feed_index) == app.selected_index {
-                Style::default()
-                    .fg(colors.secondary)
-                    .add_modifier(Modifier::REVERSED)
-            } else {
-                Style::default().fg(colors.text)
-            };
-
-            // Get unread count for this feed
-            let unread_count = app.count_unread_for_feed(&feed_info.url);
-            let total_count = app.count_total_for_feed(&feed_info.url);
-
-            // Get feed health status
-            let health = app.get_feed_health(&feed_info.url);
-            let health_indicator = health.status_indicator();
-            let health_style = match health.status {
-                FeedStatus::Healthy => Style::default().fg(Color::Green),
-                FeedStatus::Slow => Style::default().fg(Color::Yellow),
-                FeedStatus::Broken => Style::default().fg(Color::Red),
-                FeedStatus::Unknown => Style::default().fg(colors.muted),
-            };
-
-            // Format the count display
-            let count_display = if total_count > 0 {
-                format!(" ({}/{})", unread_count, total_count)
-            } else {
-                String::new()
-            };
-
-            // Calculate max width for title, accounting for health indicator, count display and indent
-            let count_len = count_display.len();
-            let title_max_width = chunks[0].width.saturating_sub(15 + count_len as u16) as usize; // Account for index, health, spacing, indent, and count
-            let truncated_title = truncate_text(&feed_info.title, title_max_width as u16);
-
-            // Style for unread count - highlight if there are unread items
-            let count_style = if unread_count > 0 {
-                Style::default()
-                    .fg(colors.highlight)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(colors.muted)
-            };
+                .fg(colors.secondary)
+                .add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().fg(colors.text)
+        };
 
-            items.push(
-                ListItem::new(Line::from(vec![
-                    Span::styled(format!("{} ", health_indicator), health_style),
-                    Span::raw(format!("{}. ", feed_index + 1)),
-                    Span::raw(truncated_title),
-                    Span::styled(count_display, count_style),
-                ]))
-                .style(style),
-            );
-            feed_index += 1;
+        match entry {
+            FeedManagerRow::Category {
+                path,
+                depth,
+                collapsed,
+            } => {
+                if !visible {
+                    continue;
+                }
+                let name = path.rsplit('/').next().unwrap_or(path);
+                let marker = if *collapsed { "▸" } else { "▾" };
+                let (feeds, unread) = app.count_for_category(path);
+                items.push(
+                    ListItem::new(Line::from(vec![
+                        Span::raw("  ".repeat(*depth)),
+                        Span::styled(
+                            format!("{} {}", marker, name),
+                            Style::default()
+                                .fg(colors.category)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            format!(" [{} feeds, {} unread]", feeds, unread),
+                            Style::default().fg(colors.muted),
+                        ),
+                    ]))
+                    .style(style),
+                );
+            }
+            FeedManagerRow::Feed { index, depth } => {
+                feed_number += 1;
+                if !visible {
+                    continue;
+                }
+                let feed_info = &app.rss_feeds[*index];
+
+                // Get unread count for this feed
+                let unread_count = app.count_unread_for_feed(&feed_info.url);
+                let total_count = app.count_total_for_feed(&feed_info.url);
+
+                // Get feed health status
+                let health = app.get_feed_health(&feed_info.url);
+                let health_indicator = health.status_indicator();
+                let health_style = match health.status {
+                    FeedStatus::Healthy => Style::default().fg(Color::Green),
+                    FeedStatus::Slow => Style::default().fg(Color::Yellow),
+                    FeedStatus::Broken => Style::default().fg(Color::Red),
+                    FeedStatus::Unknown => Style::default().fg(colors.muted),
+                };
+
+                // Format the count display; paused feeds are not counted
+                let count_display = if feed_info.paused {
+                    " (paused)".to_string()
+                } else if total_count > 0 {
+                    format!(" ({}/{})", unread_count, total_count)
+                } else {
+                    String::new()
+                };
+
+                // Calculate max width for title, accounting for health indicator, count display and indent
+                let indent = "  ".repeat(*depth);
+                let count_len = count_display.len() + indent.len();
+                let title_max_width =
+                    chunks[0].width.saturating_sub(15 + count_len as u16) as usize; // Account for index, health, spacing, indent, and count
+                let truncated_title = truncate_text(&feed_info.title, title_max_width as u16);
+
+                // Style for unread count - highlight if there are unread items
+                let count_style = if unread_count > 0 && !feed_info.paused {
+                    Style::default()
+                        .fg(colors.highlight)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(colors.muted)
+                };
+
+                let mut spans = vec![Span::raw(indent)];
+                if marking {
+                    // Marked feeds (and those in the visual range) get a bullet for bulk actions
+                    let marked =
+                        app.marked_feeds.contains(&feed_info.url) || visual_feeds.contains(index);
+                    spans.push(Span::styled(
+                        if marked { "● " } else { "  " },
+                        Style::default().fg(colors.highlight),
+                    ));
+                }
+                if feed_info.pinned {
+                    spans.push(Span::styled("⚑ ", Style::default().fg(colors.highlight)));
+                }
+                if feed_info.paused {
+                    // Paused feeds are dimmed and show a pause sign instead of their health
+                    let dimmed = Style::default().fg(colors.muted);
+                    spans.extend([
+                        Span::styled("⏸ ", dimmed),
+                        Span::styled(format!("{}. ", feed_number), dimmed),
+                        Span::styled(truncated_title, dimmed),
+                    ]);
+                } else {
+                    spans.extend([
+                        Span::styled(format!("{} ", health_indicator), health_style),
+                        Span::raw(format!("{}. ", feed_number)),
+                        Span::raw(truncated_title),
+                    ]);
+                }
+                spans.push(Span::styled(count_display, count_style));
+                let tags: Vec<&str> = feed_info.tags.iter().map(String::as_str).collect();
+                spans.extend(tag_chips(&tags, colors));
+                items.push(ListItem::new(Line::from(spans)).style(style));
+            }
+            FeedManagerRow::SavedSearch(i) => {
+                // Saved searches are listed after the feeds as virtual feeds
+                if !saved_search_header && visible {
+                    saved_search_header = true;
+                    items.push(ListItem::new(Line::from(Span::styled(
+                        "── Saved Searches ──",
+                        Style::default()
+                            .fg(colors.category)
+                            .add_modifier(Modifier::BOLD),
+                    ))));
+                }
+                if !visible {
+                    continue;
+                }
+                let search = &app.config.saved_searches[*i];
+                let (unread_count, total_count) = app.saved_search_count(search);
+                let count_display = format!(" ({}/{})", unread_count, total_count);
+                let title_max_width = chunks[0]
+                    .width
+                    .saturating_sub(15 + count_display.len() as u16);
+                let count_style = if unread_count > 0 {
+                    Style::default()
+                        .fg(colors.highlight)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(colors.muted)
+                };
+
+                items.push(
+                    ListItem::new(Line::from(vec![
+                        Span::styled("⌕ ", Style::default().fg(colors.highlight)),
+                        Span::raw(format!("{}. ", i + 1)),
+                        Span::raw(truncate_text(&search.name, title_max_width)),
+                        Span::styled(count_display, count_style),
+                    ]))
+                    .style(style),
+                );
+            }
         }
     }
 
+    let title = match app.feed_sort {
+        FeedSort::Manual => "RSS Feeds".to_string(),
+        sort => format!("RSS Feeds (by {})", sort.label()),
+    };
+    let title = if marking {
+        let mut marked = visual_feeds;
+        marked.extend(
+            (0..app.rss_feeds.len()).filter(|&i| app.marked_feeds.contains(&app.rss_feeds[i].url)),
+        );
+        let mode = if app.feed_visual_start.is_some() {
+            "VISUAL, "
+        } else {
+            ""
+        };
+        format!("{} [{}{} marked]", title, mode, marked.len())
+    } else {
+        title
+    };
     let list = List::new(items)
-        .block(Block::default().title("RSS Feeds").borders(Borders::ALL))
+        .block(Block::default().title(title).borders(Borders::ALL))
         .style(Style::default().fg(colors.text));
     frame.render_widget(list, chunks[0]);
 
@@ -392,9 +701,21 @@ fn render_feed_manager(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeC
         ]);
         let paragraph = Paragraph::new(error_text).style(Style::default().fg(colors.error));
         frame.render_widget(paragraph, chunks[1]);
-    } else if let Some(selected_index) = app.selected_index {
+    } else if let Some(selected) = app.selected_index.and_then(|row| rows.get(row)) {
         // Show health status for selected feed
-        if let Some(feed_info) = app.rss_feeds.get(selected_index) {
+        if let FeedManagerRow::Feed { index, .. } = selected {
+            let feed_info = &app.rss_feeds[*index];
+            if feed_info.paused {
+                let status_text = Line::from(Span::styled(
+                    format!(
+                        "Paused: not refreshed or counted as unread  [{}] Resume",
+                        format_keybinding(&app.config.keybindings.toggle_pause_feed)
+                    ),
+                    Style::default().fg(colors.muted),
+                ));
+                frame.render_widget(Paragraph::new(status_text), chunks[1]);
+                return;
+            }
             let health = app.get_feed_health(&feed_info.url);
             let status_color = match health.status {
                 FeedStatus::Healthy => Color::Green,
@@ -415,10 +736,194 @@ fn render_feed_manager(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeC
             ]);
             let paragraph = Paragraph::new(status_text);
             frame.render_widget(paragraph, chunks[1]);
+        } else if let FeedManagerRow::Category { path, .. } = selected {
+            let status_text = Line::from(vec![
+                Span::styled("Category: ", Style::default().fg(colors.muted)),
+                Span::styled(path, Style::default().fg(colors.category)),
+            ]);
+            frame.render_widget(Paragraph::new(status_text), chunks[1]);
+        } else if let Some(search) = match selected {
+            FeedManagerRow::SavedSearch(i) => app.config.saved_searches.get(*i),
+            _ => None,
+        } {
+            let unread = if search.unread_only {
+                " (unread only)"
+            } else {
+                ""
+            };
+            let status_text = Line::from(vec![
+                Span::styled("Search: ", Style::default().fg(colors.muted)),
+                Span::styled(
+                    format!("{}{}", search.query, unread),
+                    Style::default().fg(colors.highlight),
+                ),
+            ]);
+            frame.render_widget(Paragraph::new(status_text), chunks[1]);
         }
     }
 }
 
+fn render_mute_rules(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColors) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),    // Rule list takes most space
+            Constraint::Length(1), // Status line
+        ])
+        .split(area);
+
+    let items: Vec<ListItem> = app
+        .config
+        .mute_rules
+        .iter()
+        .enumerate()
+        .skip(app.scroll as usize)
+        .map(|(index, rule)| {
+            let style = if Some(index) == app.selected_index {
+                Style::default()
+                    .fg(colors.secondary)
+                    .add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default().fg(colors.text)
+            };
+            let action = match rule.action {
+                MuteAction::Hide => "[hide]     ",
+                MuteAction::MarkRead => "[mark read]",
+            };
+            let count_display = format!(" ({})", app.count_matches_for_rule(rule));
+            let query_max_width = chunks[0]
+                .width
+                .saturating_sub(20 + count_display.len() as u16);
+
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{}. ", index + 1)),
+                Span::styled(action, Style::default().fg(colors.highlight)),
+                Span::raw(" "),
+                Span::raw(truncate_text(&rule.query, query_max_width)),
+                Span::styled(count_display, Style::default().fg(colors.muted)),
+            ]))
+            .style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!("Mute Rules ({})", app.config.mute_rules.len()))
+                .borders(Borders::ALL),
+        )
+        .style(Style::default().fg(colors.text));
+    frame.render_widget(list, chunks[0]);
+
+    let status_text = if let Some(error) = &app.error_message {
+        Line::from(Span::styled(
+            format!("Error: {}", error),
+            Style::default().fg(colors.error),
+        ))
+    } else if app.config.mute_rules.is_empty() {
+        Line::from(Span::styled(
+            "No mute rules. Press 'a' to add one, e.g. title:/sponsored/",
+            Style::default().fg(colors.muted),
+        ))
+    } else {
+        Line::from(Span::styled(
+            "Counts are articles in the current view matching each rule",
+            Style::default().fg(colors.muted),
+        ))
+    };
+    frame.render_widget(Paragraph::new(status_text), chunks[1]);
+}
+
+fn render_settings(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColors) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),    // Setting list takes most space
+            Constraint::Length(1), // Description of the selected setting
+        ])
+        .split(area);
+
+    let settings = app.settings();
+    let key_width = settings.iter().map(|s| s.key.len()).max().unwrap_or(0);
+    let value_width = (chunks[0].width as usize / 3).max(10);
+    let items: Vec<ListItem> = settings
+        .iter()
+        .enumerate()
+        .skip(app.scroll as usize)
+        .map(|(index, setting)| {
+            let style = if Some(index) == app.selected_index {
+                Style::default()
+                    .fg(colors.secondary)
+                    .add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default().fg(colors.text)
+            };
+            let value_style = if setting.editable {
+                Style::default().fg(colors.highlight)
+            } else {
+                Style::default().fg(colors.muted)
+            };
+            let description_width =
+                (chunks[0].width as usize).saturating_sub(key_width + value_width + 6) as u16;
+
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{:<width$}  ", setting.key, width = key_width)),
+                Span::styled(
+                    format!(
+                        "{:<width$}  ",
+                        truncate_text(&setting.value, value_width as u16),
+                        width = value_width
+                    ),
+                    value_style,
+                ),
+                Span::styled(
+                    truncate_text(&setting.description, description_width),
+                    Style::default().fg(colors.muted),
+                ),
+            ]))
+            .style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().title("Settings").borders(Borders::ALL))
+        .style(Style::default().fg(colors.text));
+    frame.render_widget(list, chunks[0]);
+
+    let selected = app.selected_index.and_then(|i| settings.get(i));
+    let status_text = if let Some(error) = &app.error_message {
+        Line::from(Span::styled(
+            format!("Error: {}", error),
+            Style::default().fg(colors.error),
+        ))
+    } else if let Some(setting) = selected {
+        Line::from(Span::styled(
+            format!("{}: {}", setting.key, setting.description),
+            Style::default().fg(colors.muted),
+        ))
+    } else {
+        Line::from("")
+    };
+    frame.render_widget(Paragraph::new(status_text), chunks[1]);
+}
+
+/// Renders tags as " tag " chips separated by spaces
+fn tag_chips<'a>(tags: &[&'a str], colors: &ThemeColors) -> Vec<Span<'a>> {
+    tags.iter()
+        .flat_map(|tag| {
+            [
+                Span::raw(" "),
+                Span::styled(
+                    format!(" {} ", tag),
+                    Style::default()
+                        .fg(colors.category)
+                        .add_modifier(Modifier::REVERSED),
+                ),
+            ]
+        })
+        .collect()
+}
+
 /// Formats a keybinding string for display in the help menu.
 /// Converts "k,Up" to "↑/k", "PageUp" to "PgUp", etc.
 fn format_keybinding(keybinding: &str) -> String {
@@ -496,11 +1001,34 @@ fn render_help_menu(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColo
                 "{:<14} - Start search/filter",
                 format_keybinding(&kb.start_search)
             )),
+            Line::from("Tab (search)   - Search every indexed article (or :find <words>)"),
             Line::from(format!(
                 "{:<14} - Toggle unread-only filter",
                 format_keybinding(&kb.toggle_unread_only)
             )),
+            Line::from(format!(
+                "{:<14} - Show/hide articles hidden by mute rules",
+                format_keybinding(&kb.toggle_show_muted)
+            )),
+            Line::from(format!(
+                "{:<14} - Next/previous category (or all feeds)",
+                format_keybinding(&kb.next_category) + ", " + &format_keybinding(&kb.prev_category)
+            )),
+            Line::from(format!(
+                "{:<14} - Sort: newest/oldest/feed/title/unread/favorites (:sort <mode>)",
+                format_keybinding(&kb.cycle_sort)
+            )),
+            Line::from(format!(
+                "{:<14} - Group by: none/day/feed/category (:group <mode>)",
+                format_keybinding(&kb.cycle_group)
+            )),
             Line::from("Esc            - Clear all filters"),
+            Line::from("Ctrl-P         - Jump to a feed, category, search, command or article"),
+            Line::from("Query syntax   - feed:\"Name\" cat:rust is:unread|read|fav"),
+            Line::from("                 after:2026-01-01 before:7d title:/regex/"),
+            Line::from("                 desc:/regex/ domain:example.com tag:name is:tagged"),
+            Line::from("                 -term, NOT, OR, ( ) (terms are ANDed)"),
+            Line::from("Everything     - words, \"exact phrase\", prefix*, -exclude (ranked)"),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "Actions",
@@ -536,6 +1064,22 @@ fn render_help_menu(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColo
                 "{:<14} - Toggle favorites view",
                 format_keybinding(&kb.toggle_favorites_view)
             )),
+            Line::from(format!(
+                "{:<14} - Tag selected item (Tab completes, -tag removes)",
+                format_keybinding(&kb.tag_article)
+            )),
+            Line::from(format!(
+                "{:<14} - Mark item for bulk actions",
+                format_keybinding(&kb.toggle_mark)
+            )),
+            Line::from(format!(
+                "{:<14} - Start/end a visual range of items to mark",
+                format_keybinding(&kb.visual_mode)
+            )),
+            Line::from(
+                "               With items marked, read, favorite, tag, open and export apply",
+            ),
+            Line::from("               to all of them; Esc unmarks"),
             Line::from(format!(
                 "{:<14} - Open feed manager",
                 format_keybinding(&kb.open_feed_manager)
@@ -544,6 +1088,14 @@ fn render_help_menu(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColo
                 "{:<14} - Refresh feed cache",
                 format_keybinding(&kb.refresh)
             )),
+            Line::from(format!(
+                "{:<14} - Manage mute rules",
+                format_keybinding(&kb.open_mute_rules)
+            )),
+            Line::from(format!(
+                "{:<14} - Settings",
+                format_keybinding(&kb.open_settings)
+            )),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "Export",
@@ -557,6 +1109,26 @@ fn render_help_menu(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColo
             )),
             Line::from("S              - Save article to file"),
             Line::from(""),
+            Line::from(vec![Span::styled(
+                "External Programs",
+                Style::default()
+                    .add_modifier(Modifier::UNDERLINED)
+                    .fg(colors.secondary),
+            )]),
+            Line::from(format!(
+                "{:<14} - View article in $PAGER",
+                format_keybinding(&kb.view_in_pager)
+            )),
+            Line::from(format!(
+                "{:<14} - Open link in terminal browser",
+                format_keybinding(&kb.open_in_text_browser)
+            )),
+            Line::from(format!(
+                "{:<14} - Edit config in $EDITOR",
+                format_keybinding(&kb.edit_config)
+            )),
+            Line::from("Ctrl-Z         - Suspend to shell (resume with fg)"),
+            Line::from(""),
             Line::from(vec![Span::styled(
                 "UI",
                 Style::default()
@@ -586,6 +1158,22 @@ fn render_help_menu(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColo
             Line::from(":feeds         - Open feed manager"),
             Line::from(":fav           - Toggle favorites view"),
             Line::from(":read          - Mark all as read"),
+            Line::from(":ss <name>     - Save current search as a virtual feed"),
+            Line::from(":mute          - Manage mute rules"),
+            Line::from(
+                ":cat [name]    - Show a category (\"all\" for every feed) or list unread counts",
+            ),
+            Line::from(":tags          - List tags"),
+            Line::from(":tag <name>    - Show articles with a tag"),
+            Line::from(":add <url> [category] - Subscribe to a feed"),
+            Line::from(":delete [title] - Delete the open feed or the one named"),
+            Line::from(":rename <title> - Rename the open feed"),
+            Line::from(":filter <query> - Filter articles (no query clears)"),
+            Line::from(":open <n>      - Open the n-th article"),
+            Line::from(":set key=value - Change a setting (:set opens Settings)"),
+            Line::from(":export json|opml <path> - Write articles or feeds to a file"),
+            Line::from(":profile [name] - Switch profile (no name lists them)"),
+            Line::from("Tab / ↑↓       - Complete / recall earlier commands"),
         ],
         PageMode::FeedManager => vec![
             Line::from(vec![Span::styled(
@@ -629,13 +1217,58 @@ fn render_help_menu(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColo
                 format_keybinding(&kb.add_feed)
             )),
             Line::from(format!(
-                "{:<14} - Delete selected feed",
+                "{:<14} - Delete selected feed or saved search",
                 format_keybinding(&kb.delete_feed)
             )),
             Line::from(format!(
-                "{:<14} - Set category/tag for selected feed",
+                "{:<14} - Set category path (e.g. Tech/Rust), or rename a category",
                 format_keybinding(&kb.set_category)
             )),
+            Line::from(format!(
+                "{:<14} - Set tags for selected feed",
+                format_keybinding(&kb.set_feed_tags)
+            )),
+            Line::from(format!(
+                "{:<14} - Rename selected feed",
+                format_keybinding(&kb.rename_feed)
+            )),
+            Line::from(format!(
+                "{:<14} - Change URL of selected feed (keeps read state)",
+                format_keybinding(&kb.edit_feed_url)
+            )),
+            Line::from(format!(
+                "{:<14} - Move selected feed or category up",
+                format_keybinding(&kb.move_feed_up)
+            )),
+            Line::from(format!(
+                "{:<14} - Move selected feed or category down",
+                format_keybinding(&kb.move_feed_down)
+            )),
+            Line::from(format!(
+                "{:<14} - Pin selected feed to the top",
+                format_keybinding(&kb.toggle_pin_feed)
+            )),
+            Line::from(format!(
+                "{:<14} - Pause or resume selected feed",
+                format_keybinding(&kb.toggle_pause_feed)
+            )),
+            Line::from(format!(
+                "{:<14} - Mark feed (or a category's feeds) for bulk actions",
+                format_keybinding(&kb.toggle_mark)
+            )),
+            Line::from(format!(
+                "{:<14} - Start/end a visual range of feeds to mark",
+                format_keybinding(&kb.visual_mode)
+            )),
+            Line::from(
+                "               With feeds marked, delete, category, pause, mark read, refresh",
+            ),
+            Line::from("               and OPML export apply to all of them; Esc unmarks"),
+            Line::from(format!(
+                "{:<14} - Order feeds: manual, unread, updated, health, name",
+                format_keybinding(&kb.cycle_sort)
+            )),
+            Line::from("Enter          - Open feed, or fold/unfold a category"),
             Line::from(format!(
                 "{:<14} - Export feeds to clipboard",
                 format_keybinding(&kb.export_clipboard)
@@ -660,6 +1293,18 @@ fn render_help_menu(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColo
                 "{:<14} - Return to feed list",
                 format_keybinding(&kb.open_feed_manager)
             )),
+            Line::from(format!(
+                "{:<14} - Edit config in $EDITOR",
+                format_keybinding(&kb.edit_config)
+            )),
+            Line::from(format!(
+                "{:<14} - Manage mute rules",
+                format_keybinding(&kb.open_mute_rules)
+            )),
+            Line::from(format!(
+                "{:<14} - Settings",
+                format_keybinding(&kb.open_settings)
+            )),
             Line::from(format!(
                 "{:<14} - Toggle this help menu",
                 format_keybinding(&kb.help)
@@ -680,6 +1325,11 @@ fn render_help_menu(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColo
             Line::from(":w             - Save state"),
             Line::from(":wq            - Save and quit"),
             Line::from(":help          - Show help"),
+            Line::from(":add <url> [category] - Subscribe to a feed"),
+            Line::from(":delete [title] - Delete the selected feed or the one named"),
+            Line::from(":rename <title> - Rename the selected feed"),
+            Line::from(":set key=value - Change a setting (:set opens Settings)"),
+            Line::from("Tab / ↑↓       - Complete / recall earlier commands"),
         ],
         PageMode::Favorites => vec![
             Line::from(vec![Span::styled(
@@ -722,11 +1372,34 @@ fn render_help_menu(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColo
                 "{:<14} - Start search/filter",
                 format_keybinding(&kb.start_search)
             )),
+            Line::from("Tab (search)   - Search every indexed article (or :find <words>)"),
             Line::from(format!(
                 "{:<14} - Toggle unread-only filter",
                 format_keybinding(&kb.toggle_unread_only)
             )),
+            Line::from(format!(
+                "{:<14} - Show/hide articles hidden by mute rules",
+                format_keybinding(&kb.toggle_show_muted)
+            )),
+            Line::from(format!(
+                "{:<14} - Next/previous category (or all feeds)",
+                format_keybinding(&kb.next_category) + ", " + &format_keybinding(&kb.prev_category)
+            )),
+            Line::from(format!(
+                "{:<14} - Sort: newest/oldest/feed/title/unread/favorites (:sort <mode>)",
+                format_keybinding(&kb.cycle_sort)
+            )),
+            Line::from(format!(
+                "{:<14} - Group by: none/day/feed/category (:group <mode>)",
+                format_keybinding(&kb.cycle_group)
+            )),
             Line::from("Esc            - Clear all filters"),
+            Line::from("Ctrl-P         - Jump to a feed, category, search, command or article"),
+            Line::from("Query syntax   - feed:\"Name\" cat:rust is:unread|read|fav"),
+            Line::from("                 after:2026-01-01 before:7d title:/regex/"),
+            Line::from("                 desc:/regex/ domain:example.com tag:name is:tagged"),
+            Line::from("                 -term, NOT, OR, ( ) (terms are ANDed)"),
+            Line::from("Everything     - words, \"exact phrase\", prefix*, -exclude (ranked)"),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "Actions",
@@ -754,6 +1427,22 @@ fn render_help_menu(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColo
                 "{:<14} - Return to all feeds view",
                 format_keybinding(&kb.toggle_favorites_view)
             )),
+            Line::from(format!(
+                "{:<14} - Tag selected item (Tab completes, -tag removes)",
+                format_keybinding(&kb.tag_article)
+            )),
+            Line::from(format!(
+                "{:<14} - Mark item for bulk actions",
+                format_keybinding(&kb.toggle_mark)
+            )),
+            Line::from(format!(
+                "{:<14} - Start/end a visual range of items to mark",
+                format_keybinding(&kb.visual_mode)
+            )),
+            Line::from(
+                "               With items marked, read, favorite, tag, open and export apply",
+            ),
+            Line::from("               to all of them; Esc unmarks"),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "Export",
@@ -767,6 +1456,26 @@ fn render_help_menu(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColo
             )),
             Line::from("S              - Save article to file"),
             Line::from(""),
+            Line::from(vec![Span::styled(
+                "External Programs",
+                Style::default()
+                    .add_modifier(Modifier::UNDERLINED)
+                    .fg(colors.secondary),
+            )]),
+            Line::from(format!(
+                "{:<14} - View article in $PAGER",
+                format_keybinding(&kb.view_in_pager)
+            )),
+            Line::from(format!(
+                "{:<14} - Open link in terminal browser",
+                format_keybinding(&kb.open_in_text_browser)
+            )),
+            Line::from(format!(
+                "{:<14} - Edit config in $EDITOR",
+                format_keybinding(&kb.edit_config)
+            )),
+            Line::from("Ctrl-Z         - Suspend to shell (resume with fg)"),
+            Line::from(""),
             Line::from(vec![Span::styled(
                 "UI",
                 Style::default()
@@ -795,6 +1504,79 @@ fn render_help_menu(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeColo
             Line::from(":help          - Show help"),
             Line::from(":fav           - Return to all feeds view"),
         ],
+        PageMode::MuteRules => vec![
+            Line::from(vec![Span::styled(
+                "Mute Rules Commands",
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(colors.primary),
+            )]),
+            Line::from(""),
+            Line::from("Rules use the search query syntax; matching articles are hidden"),
+            Line::from("from the feed list, or marked as read when feeds are refreshed."),
+            Line::from(""),
+            Line::from(format!(
+                "{:<14} - Navigate between rules",
+                format_keybinding(&kb.move_up) + ", " + &format_keybinding(&kb.move_down)
+            )),
+            Line::from(format!(
+                "{:<14} - Add rule",
+                format_keybinding(&kb.add_feed)
+            )),
+            Line::from("e              - Edit selected rule"),
+            Line::from(format!(
+                "{:<14} - Delete selected rule",
+                format_keybinding(&kb.delete_feed)
+            )),
+            Line::from(format!(
+                "{:<14} - Switch action between hide and mark read",
+                format_keybinding(&kb.select)
+            )),
+            Line::from(format!(
+                "{:<14} - Return to feed list",
+                format_keybinding(&kb.open_mute_rules) + "/Esc"
+            )),
+            Line::from(format!(
+                "{:<14} - Toggle this help menu",
+                format_keybinding(&kb.help)
+            )),
+        ],
+        PageMode::Settings => vec![
+            Line::from(vec![Span::styled(
+                "Settings Commands",
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(colors.primary),
+            )]),
+            Line::from(""),
+            Line::from("Changes apply immediately and are saved to the config file."),
+            Line::from(""),
+            Line::from(format!(
+                "{:<14} - Navigate between settings",
+                format_keybinding(&kb.move_up) + ", " + &format_keybinding(&kb.move_down)
+            )),
+            Line::from(format!(
+                "{:<14} - Edit value (switches true/false)",
+                format_keybinding(&kb.select) + "/e"
+            )),
+            Line::from(format!(
+                "{:<14} - Reset to default (asks first)",
+                format_keybinding(&kb.delete_feed)
+            )),
+            Line::from(format!(
+                "{:<14} - Edit config in $EDITOR",
+                format_keybinding(&kb.edit_config)
+            )),
+            Line::from(":set key=value - Change a setting from any page"),
+            Line::from(format!(
+                "{:<14} - Return to feed list",
+                format_keybinding(&kb.open_settings) + "/Esc"
+            )),
+            Line::from(format!(
+                "{:<14} - Toggle this help menu",
+                format_keybinding(&kb.help)
+            )),
+        ],
     };
 
     let help_paragraph = Paragraph::new(help_text)
@@ -881,6 +1663,13 @@ fn render_article_preview(app: &App, frame: &mut Frame, area: Rect, colors: &The
             ""
         }
     );
+    let tags: Vec<&str> = app.item_tags(&item.id).collect();
+    if !tags.is_empty() {
+        let mut tag_line = vec![Span::styled("Tags:", Style::default().fg(colors.secondary))];
+        tag_line.extend(tag_chips(&tags, colors));
+        lines.push(Line::from(tag_line));
+    }
+
     lines.push(Line::from(vec![Span::styled(
         status,
         Style::default().fg(colors.description),
@@ -1007,20 +1796,74 @@ fn render_command_bar(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeCo
     let commands = if app.input_mode == InputMode::Help {
         "[q/Esc/?] Exit Help".to_string()
     } else if app.input_mode == InputMode::Preview {
-        "[↑↓/jk] Scroll  [PgUp/PgDn] Page  [o/O] Open/Copy  [r] Read  [f] Fav  [s] Copy  [S] Save  [Esc/q/p] Close".to_string()
-    } else if app.input_mode == InputMode::Searching {
+        "[↑↓/jk] Scroll  [PgUp/PgDn] Page  [o/O] Open/Copy  [r] Read  [f] Fav  [t] Tag  [s] Copy  [S] Save  [Esc/q/p] Close".to_string()
+    } else if app.input_mode == InputMode::Searching && app.search_everything {
         format!(
-            "Search: {}█  [Enter] Confirm  [Esc] Cancel",
+            "Search everything: {}█  [Tab] This view  [Enter] Confirm  [Esc] Cancel",
             app.search_query
         )
+    } else if app.input_mode == InputMode::Searching {
+        match &app.search_error {
+            Some(e) => format!(
+                "Search: {}█  ⚠ {}  [Tab] Everything  [Enter] Confirm  [Esc] Cancel",
+                app.search_query, e
+            ),
+            None => format!(
+                "Search: {}█  [Tab] Everything  [Enter] Confirm  [Esc] Cancel",
+                app.search_query
+            ),
+        }
     } else if app.input_mode == InputMode::Command {
-        format!(":{}█  [Enter] Execute  [Esc] Cancel", app.command_buffer)
+        let suggestions = app.command_suggestions();
+        if app.command_buffer.is_empty() || suggestions.is_empty() {
+            format!(
+                ":{}█  [Enter] Execute  [Tab] Complete  [↑↓] History  [Esc] Cancel",
+                app.command_buffer
+            )
+        } else {
+            let shown: Vec<&str> = suggestions.iter().take(8).map(String::as_str).collect();
+            format!(
+                ":{}█  [Tab] {}{}  [Enter] Execute  [Esc] Cancel",
+                app.command_buffer,
+                shown.join(" | "),
+                if suggestions.len() > shown.len() {
+                    " ..."
+                } else {
+                    ""
+                }
+            )
+        }
+    } else if app.input_mode == InputMode::Palette {
+        "[↑↓/Ctrl-N/Ctrl-P] Select  [Enter] Go  [Esc] Close".to_string()
+    } else if app.input_mode == InputMode::Tagging {
+        let suggestions = app.tag_suggestions();
+        if app.has_marked_items() && !app.tagging_from_preview {
+            format!(
+                "Tags for {} marked articles: {}█  [Enter] Apply  [Esc] Cancel  (-tag removes)",
+                app.marked_item_count(),
+                app.input_buffer
+            )
+        } else if suggestions.is_empty() {
+            format!(
+                "Tags: {}█  [Enter] Apply  [Esc] Cancel  (-tag removes)",
+                app.input_buffer
+            )
+        } else {
+            format!(
+                "Tags: {}█  [Tab] {}  [Enter] Apply  [Esc] Cancel",
+                app.input_buffer,
+                suggestions.join(" ")
+            )
+        }
     } else {
         match app.page_mode {
+            PageMode::FeedList | PageMode::Favorites if app.has_marked_items() => {
+                "[Space] Mark  [v] Range  [r] Read/Unread  [R] Read  [f] Fav  [t] Tag  [o] Open  [s/S] Export  [Esc] Unmark".to_string()
+            }
             PageMode::FeedList => {
                 if app.current_feed_content.is_empty() {
                     "[m] Manage Feeds  [c] Refresh Cache  [F] Favorites  [?] Help  [q] Quit".to_string()
-                } else if app.filtered_indices.is_some() {
+                } else if app.has_active_filter() {
                     "[↑↓] Navigate  [/] Search  [u] Unread  [Esc] Clear  [p] Preview  [o/O] Open/Copy  [f] Fav  [?] Help".to_string()
                 } else {
                     "[↑↓] Navigate  [/] Search  [u] Unread  [p] Preview  [o/O] Open/Copy  [m] Manage  [r] Read  [f] Fav  [?] Help".to_string()
@@ -1029,17 +1872,23 @@ fn render_command_bar(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeCo
             PageMode::Favorites => {
                 if app.current_feed_content.is_empty() {
                     "[F] Back to Feeds  [?] Help  [q] Quit".to_string()
-                } else if app.filtered_indices.is_some() {
+                } else if app.has_active_filter() {
                     "[↑↓] Navigate  [/] Search  [u] Unread  [Esc] Clear  [p] Preview  [o/O] Open/Copy  [f] Fav  [F] Back  [?] Help".to_string()
                 } else {
                     "[↑↓] Navigate  [/] Search  [u] Unread  [p] Preview  [o/O] Open/Copy  [f] Fav  [F] Back  [?] Help".to_string()
                 }
             }
             PageMode::FeedManager => match app.input_mode {
+                InputMode::Normal if app.has_marked_feeds() => {
+                    "[Space] Mark  [v] Range  [d] Delete  [t] Category  [z] Pause  [R] Read  [c] Refresh  [e/E] Export  [Esc] Unmark".to_string()
+                }
                 InputMode::Normal => {
-                    "[↑↓] Navigate  [a] Add  [d] Delete  [t] Tag  [e/E] Export  [i/I] Import  [m] Back  [?] Help  [q] Quit".to_string()
+                    "[↑↓] Navigate  [Enter] Open/Fold  [Space/v] Mark  [a] Add  [d] Delete  [n] Rename  [U] URL  [J/K] Move  [p] Pin  [z] Pause  [.] Order  [t] Category  [T] Tags  [e/E] Export  [i/I] Import  [m] Back  [?] Help".to_string()
                 }
                 InputMode::Adding => format!("Enter RSS URL: {}", app.input_buffer),
+                InputMode::Deleting if app.has_marked_feeds() => {
+                    format!("Delete {} marked feeds? Enter to delete, Esc to cancel", app.marked_feeds.len())
+                }
                 InputMode::Deleting => {
                     "Use ↑↓ to select feed, Enter to delete, Esc to cancel".to_string()
                 }
@@ -1048,10 +1897,48 @@ fn render_command_bar(app: &App, frame: &mut Frame, area: Rect, colors: &ThemeCo
                     format!("Import feeds ({} URLs pasted) - [Enter] Import  [Esc] Cancel", line_count)
                 }
                 InputMode::SettingCategory => {
-                    format!("Set category: {}█  [Enter] Save  [Esc] Cancel  (empty to remove)", app.input_buffer)
+                    format!("Set category (Parent/Child): {}█  [Enter] Save  [Esc] Cancel  (empty to remove)", app.input_buffer)
+                }
+                InputMode::SettingFeedTags => {
+                    format!("Feed tags (comma separated): {}█  [Enter] Save  [Esc] Cancel", app.input_buffer)
+                }
+                InputMode::RenamingFeed => {
+                    format!("Feed title: {}█  [Enter] Save  [Esc] Cancel", app.input_buffer)
+                }
+                InputMode::EditingFeedUrl => {
+                    format!("Feed URL: {}█  [Enter] Save (keeps read state)  [Esc] Cancel", app.input_buffer)
                 }
                 InputMode::FeedManager => "[m] Back to Feeds  [?] Help".to_string(),
-                InputMode::Help | InputMode::Searching | InputMode::Preview | InputMode::Command => unreachable!(), // These cases are already handled above
+                InputMode::EditingSetting | InputMode::ResettingSetting => unreachable!(), // Only used on the Settings page
+                InputMode::Help | InputMode::Searching | InputMode::Preview | InputMode::Command | InputMode::Tagging | InputMode::Palette => unreachable!(), // These cases are already handled above
+            },
+            PageMode::MuteRules => match app.input_mode {
+                InputMode::Adding if app.editing_rule.is_some() => {
+                    format!("Edit rule: {}█  [Enter] Save  [Esc] Cancel", app.input_buffer)
+                }
+                InputMode::Adding => {
+                    format!("New rule: {}█  [Enter] Save  [Esc] Cancel", app.input_buffer)
+                }
+                _ => "[↑↓] Navigate  [a] Add  [e] Edit  [d] Delete  [Enter] Hide/Read  [x/Esc] Back  [?] Help".to_string(),
+            },
+            PageMode::Settings => match app.input_mode {
+                InputMode::EditingSetting => {
+                    let key = app
+                        .selected_index
+                        .and_then(|i| app.settings().into_iter().nth(i))
+                        .map(|setting| setting.key)
+                        .unwrap_or_default();
+                    format!("{} = {}█  [Enter] Save  [Esc] Cancel", key, app.input_buffer)
+                }
+                InputMode::ResettingSetting => {
+                    let key = app
+                        .selected_index
+                        .and_then(|i| app.settings().into_iter().nth(i))
+                        .map(|setting| setting.key)
+                        .unwrap_or_default();
+                    format!("Reset {} to its default? Enter to reset, Esc to cancel", key)
+                }
+                _ => "[↑↓] Navigate  [Enter] Edit/Toggle  [d] Default  [C] Edit file  [=/Esc] Back  [?] Help".to_string(),
             },
         }
     };
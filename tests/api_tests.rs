@@ -0,0 +1,167 @@
+use reedy::api::{handle_request, is_authorized, parse_request, HttpRequest};
+use reedy::app::{ApiConfig, App, Config, FeedInfo, FeedItem};
+
+fn request(method: &str, path: &str) -> HttpRequest {
+    parse_request(&format!(
+        "{} {} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        method, path
+    ))
+    .unwrap()
+}
+
+#[test]
+fn test_api_disabled_by_default() {
+    let config = Config::default();
+    assert_eq!(config.api, ApiConfig::default());
+    assert!(!config.api.enabled);
+    assert_eq!(config.api.port, 7878);
+    assert!(config.api.token.is_empty());
+}
+
+#[test]
+fn test_parse_request_with_query_headers_and_body() {
+    let raw = "POST /api/feeds?feed=https%3A%2F%2Fexample.com%2Frss&unread=true HTTP/1.1\r\n\
+               Authorization: Bearer secret\r\n\
+               Content-Type: application/json\r\n\
+               \r\n\
+               {\"url\": \"https://example.com/rss\"}";
+    let req = parse_request(raw).unwrap();
+
+    assert_eq!(req.method, "POST");
+    assert_eq!(req.path, "/api/feeds");
+    assert_eq!(req.query.get("feed").unwrap(), "https://example.com/rss");
+    assert_eq!(req.query.get("unread").unwrap(), "true");
+    assert_eq!(req.headers.get("authorization").unwrap(), "Bearer secret");
+    assert_eq!(req.body, "{\"url\": \"https://example.com/rss\"}");
+}
+
+#[test]
+fn test_parse_request_malformed() {
+    assert!(parse_request("").is_none());
+    assert!(parse_request("GET\r\n\r\n").is_none());
+}
+
+#[test]
+fn test_is_authorized() {
+    let mut req = request("GET", "/api/feeds");
+    assert!(!is_authorized(&req, "secret"));
+
+    req.headers
+        .insert("authorization".to_string(), "Bearer secret".to_string());
+    assert!(is_authorized(&req, "secret"));
+    assert!(!is_authorized(&req, "other"));
+    // An empty configured token never authorizes
+    assert!(!is_authorized(&req, ""));
+
+    // Tokens in the URL end up in logs and history, so they are not accepted
+    let req = request("GET", "/api/feeds?token=secret");
+    assert!(!is_authorized(&req, "secret"));
+}
+
+#[tokio::test]
+async fn test_handle_request_feeds_and_unread() {
+    let mut app = App::default();
+    app.rss_feeds.push(FeedInfo {
+        url: "https://api-test.invalid/feed.xml".to_string(),
+        title: "API Test Feed".to_string(),
+        category: Some("Testing".to_string()),
+        ..Default::default()
+    });
+
+    let response = handle_request(&mut app, &request("GET", "/api/feeds")).await;
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body[0]["title"], "API Test Feed");
+    assert_eq!(response.body[0]["category"], "Testing");
+    assert_eq!(response.body[0]["unread"], 0);
+
+    let response = handle_request(&mut app, &request("GET", "/api/unread")).await;
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body["total"], 0);
+
+    // Unread articles of a paused feed are not counted
+    app.rss_feeds.push(FeedInfo {
+        url: "https://api-paused.invalid/feed.xml".to_string(),
+        title: "Paused Feed".to_string(),
+        paused: true,
+        ..Default::default()
+    });
+    app.set_cached_items(
+        "https://api-paused.invalid/feed.xml",
+        vec![FeedItem {
+            title: "Unread".to_string(),
+            description: String::new(),
+            link: String::new(),
+            published: None,
+            id: "api-paused-1".to_string(),
+            feed_url: "https://api-paused.invalid/feed.xml".to_string(),
+            enclosure: None,
+        }],
+    );
+    let response = handle_request(&mut app, &request("GET", "/api/feeds")).await;
+    let paused = |title: &str| {
+        response
+            .body
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|feed| feed["title"] == title)
+            .unwrap()["paused"]
+            .clone()
+    };
+    assert_eq!(paused("API Test Feed"), false);
+    assert_eq!(paused("Paused Feed"), true);
+    let response = handle_request(&mut app, &request("GET", "/api/unread")).await;
+    assert_eq!(response.body["total"], 0);
+    assert_eq!(
+        response.body["feeds"]["https://api-paused.invalid/feed.xml"],
+        0
+    );
+
+    let response = handle_request(&mut app, &request("GET", "/api/health")).await;
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body["feeds"][0]["status"], "Not checked");
+}
+
+#[tokio::test]
+async fn test_handle_request_item_actions() {
+    let mut app = App::default();
+
+    let response = handle_request(&mut app, &request("POST", "/api/items/item-1/read")).await;
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body["read"], true);
+
+    // Repeating a request leaves the same state rather than toggling it
+    for _ in 0..2 {
+        let response =
+            handle_request(&mut app, &request("POST", "/api/items/item-1/favorite")).await;
+        assert_eq!(response.body["favorite"], true);
+        assert!(app.favorites.contains("item-1"));
+    }
+
+    let response = handle_request(&mut app, &request("POST", "/api/items/item-1/unfavorite")).await;
+    assert_eq!(response.body["favorite"], false);
+    assert!(!app.favorites.contains("item-1"));
+}
+
+#[tokio::test]
+async fn test_handle_request_errors() {
+    let mut app = App::default();
+
+    let response = handle_request(&mut app, &request("GET", "/api/nope")).await;
+    assert_eq!(response.status, 404);
+
+    let mut req = request("POST", "/api/feeds");
+    req.body = "not json".to_string();
+    let response = handle_request(&mut app, &req).await;
+    assert_eq!(response.status, 400);
+
+    req.body = "{}".to_string();
+    let response = handle_request(&mut app, &req).await;
+    assert_eq!(response.status, 400);
+
+    // The server fetches the feed's title before the request reaches the app
+    req.body = "{\"url\": \"https://api-test.invalid/new.xml\"}".to_string();
+    let response = handle_request(&mut app, &req).await;
+    assert_eq!(response.status, 422);
+    assert!(app.rss_feeds.is_empty());
+}
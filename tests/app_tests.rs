@@ -48,6 +48,7 @@ fn test_app_item_favorite() {
         published: Some(SystemTime::now()),
         id: "test-id".to_string(),
         feed_url: String::new(),
+        enclosure: None,
     };
 
     // Initially not a favorite
@@ -128,6 +129,7 @@ fn test_unfavorite_removes_from_favorites_view() {
         published: Some(SystemTime::now()),
         id: "id-1".to_string(),
         feed_url: String::new(),
+        enclosure: None,
     };
     let item2 = FeedItem {
         title: "Item 2".to_string(),
@@ -136,6 +138,7 @@ fn test_unfavorite_removes_from_favorites_view() {
         published: Some(SystemTime::now()),
         id: "id-2".to_string(),
         feed_url: String::new(),
+        enclosure: None,
     };
 
     // Add items to current feed content and mark them as favorites
@@ -172,6 +175,7 @@ fn test_unfavorite_last_item_in_favorites_view() {
         published: Some(SystemTime::now()),
         id: "only-id".to_string(),
         feed_url: String::new(),
+        enclosure: None,
     };
 
     // Add item to current feed content and mark as favorite
@@ -203,6 +207,7 @@ fn test_unfavorite_does_not_remove_in_feedlist_view() {
         published: Some(SystemTime::now()),
         id: "test-id".to_string(),
         feed_url: String::new(),
+        enclosure: None,
     };
 
     // Add item to current feed content and mark as favorite
@@ -279,6 +284,7 @@ fn test_search_filter_functionality() {
             published: Some(SystemTime::now()),
             id: "id-1".to_string(),
             feed_url: String::new(),
+            enclosure: None,
         },
         FeedItem {
             title: "Python Tutorial".to_string(),
@@ -287,6 +293,7 @@ fn test_search_filter_functionality() {
             published: Some(SystemTime::now()),
             id: "id-2".to_string(),
             feed_url: String::new(),
+            enclosure: None,
         },
         FeedItem {
             title: "JavaScript Guide".to_string(),
@@ -295,6 +302,7 @@ fn test_search_filter_functionality() {
             published: Some(SystemTime::now()),
             id: "id-3".to_string(),
             feed_url: String::new(),
+            enclosure: None,
         },
     ];
 
@@ -356,6 +364,7 @@ fn test_search_with_no_matches() {
         published: Some(SystemTime::now()),
         id: "id-1".to_string(),
         feed_url: String::new(),
+        enclosure: None,
     });
     app.selected_index = Some(0);
 
@@ -381,6 +390,7 @@ fn test_get_actual_index_with_filter() {
             published: Some(SystemTime::now()),
             id: format!("id-{}", i),
             feed_url: String::new(),
+            enclosure: None,
         });
     }
 
@@ -447,7 +457,7 @@ fn test_category_setting_mode() {
     app.rss_feeds.push(FeedInfo {
         url: "https://example.com/feed.xml".to_string(),
         title: "Test Feed".to_string(),
-        category: None,
+        ..Default::default()
     });
     app.selected_index = Some(0);
     app.page_mode = PageMode::FeedManager;
@@ -475,6 +485,7 @@ fn test_category_prefill_existing() {
         url: "https://example.com/feed.xml".to_string(),
         title: "Test Feed".to_string(),
         category: Some("News".to_string()),
+        ..Default::default()
     });
     app.selected_index = Some(0);
     app.page_mode = PageMode::FeedManager;
@@ -494,6 +505,7 @@ fn test_clear_category() {
         url: "https://example.com/feed.xml".to_string(),
         title: "Test Feed".to_string(),
         category: Some("Tech".to_string()),
+        ..Default::default()
     });
     app.selected_index = Some(0);
     app.page_mode = PageMode::FeedManager;
@@ -515,6 +527,7 @@ fn test_cancel_category_setting() {
         url: "https://example.com/feed.xml".to_string(),
         title: "Test Feed".to_string(),
         category: Some("Original".to_string()),
+        ..Default::default()
     });
     app.selected_index = Some(0);
     app.page_mode = PageMode::FeedManager;
@@ -539,21 +552,25 @@ fn test_get_categories() {
         url: "https://example.com/1.xml".to_string(),
         title: "Feed 1".to_string(),
         category: Some("Tech".to_string()),
+        ..Default::default()
     });
     app.rss_feeds.push(FeedInfo {
         url: "https://example.com/2.xml".to_string(),
         title: "Feed 2".to_string(),
         category: Some("News".to_string()),
+        ..Default::default()
     });
     app.rss_feeds.push(FeedInfo {
         url: "https://example.com/3.xml".to_string(),
         title: "Feed 3".to_string(),
         category: Some("Tech".to_string()), // Duplicate category
+        ..Default::default()
     });
     app.rss_feeds.push(FeedInfo {
         url: "https://example.com/4.xml".to_string(),
         title: "Feed 4".to_string(),
         category: None, // Uncategorized
+        ..Default::default()
     });
 
     let categories = app.get_categories();
@@ -571,21 +588,24 @@ fn test_get_feeds_by_category() {
         url: "https://example.com/1.xml".to_string(),
         title: "Tech Feed 1".to_string(),
         category: Some("Tech".to_string()),
+        ..Default::default()
     });
     app.rss_feeds.push(FeedInfo {
         url: "https://example.com/2.xml".to_string(),
         title: "Uncategorized Feed".to_string(),
-        category: None,
+        ..Default::default()
     });
     app.rss_feeds.push(FeedInfo {
         url: "https://example.com/3.xml".to_string(),
         title: "News Feed".to_string(),
         category: Some("News".to_string()),
+        ..Default::default()
     });
     app.rss_feeds.push(FeedInfo {
         url: "https://example.com/4.xml".to_string(),
         title: "Tech Feed 2".to_string(),
         category: Some("Tech".to_string()),
+        ..Default::default()
     });
 
     let grouped = app.get_feeds_by_category();
@@ -620,6 +640,7 @@ fn test_scroll_to_bottom() {
             published: Some(SystemTime::now()),
             id: format!("id-{}", i),
             feed_url: String::new(),
+            enclosure: None,
         });
     }
 
@@ -659,7 +680,7 @@ fn test_scroll_to_bottom_feed_manager() {
         app.rss_feeds.push(FeedInfo {
             url: format!("https://example.com/{}.xml", i),
             title: format!("Feed {}", i),
-            category: None,
+            ..Default::default()
         });
     }
 
@@ -688,6 +709,7 @@ fn test_scroll_to_bottom_with_filter() {
             published: Some(SystemTime::now()),
             id: format!("id-{}", i),
             feed_url: String::new(),
+            enclosure: None,
         });
     }
 
@@ -714,16 +736,18 @@ fn test_opml_generate() {
         url: "https://example.com/feed1.xml".to_string(),
         title: "Tech News".to_string(),
         category: Some("Technology".to_string()),
+        ..Default::default()
     });
     app.rss_feeds.push(FeedInfo {
         url: "https://example.com/feed2.xml".to_string(),
         title: "Science Daily".to_string(),
         category: Some("Science".to_string()),
+        ..Default::default()
     });
     app.rss_feeds.push(FeedInfo {
         url: "https://example.com/feed3.xml".to_string(),
         title: "Uncategorized Feed".to_string(),
-        category: None,
+        ..Default::default()
     });
 
     // Generate OPML - using the private method via reflection is not possible,
@@ -811,7 +835,7 @@ async fn test_opml_import_skips_duplicates() {
     app.rss_feeds.push(FeedInfo {
         url: "https://example.com/existing.xml".to_string(),
         title: "Existing Feed".to_string(),
-        category: None,
+        ..Default::default()
     });
 
     let opml_content = r#"<?xml version="1.0" encoding="UTF-8"?>
@@ -881,6 +905,14 @@ fn test_keybindings_default() {
     assert_eq!(kb.export_opml, "E");
     assert_eq!(kb.import_clipboard, "i");
     assert_eq!(kb.import_opml, "I");
+    assert_eq!(kb.rename_feed, "n");
+    assert_eq!(kb.edit_feed_url, "U");
+    assert_eq!(kb.move_feed_up, "K");
+    assert_eq!(kb.move_feed_down, "J");
+    assert_eq!(kb.toggle_pin_feed, "p");
+    assert_eq!(kb.toggle_pause_feed, "z");
+    assert_eq!(kb.toggle_mark, "Space");
+    assert_eq!(kb.visual_mode, "v");
     assert_eq!(kb.help, "?");
     assert_eq!(kb.quit, "q");
 }
@@ -1037,6 +1069,151 @@ fn test_command_scroll_to_top() {
     assert_eq!(app.scroll, 0);
 }
 
+#[test]
+fn test_command_completion() {
+    let mut app = App::default();
+    app.rss_feeds.push(FeedInfo {
+        url: "https://hn.example/rss".to_string(),
+        title: "Hacker News".to_string(),
+        category: Some("Tech".to_string()),
+        ..Default::default()
+    });
+
+    // Command names complete to the common prefix, then fully with a space
+    app.command_buffer = "re".to_string();
+    assert_eq!(app.command_suggestions(), vec!["read", "refresh", "rename"]);
+    app.complete_command();
+    assert_eq!(app.command_buffer, "re");
+    app.command_buffer = "ren".to_string();
+    app.complete_command();
+    assert_eq!(app.command_buffer, "rename ");
+
+    // Feed titles keep their case
+    app.command_buffer = "delete hack".to_string();
+    app.complete_command();
+    assert_eq!(app.command_buffer, "delete Hacker News");
+
+    // Config keys end with '='
+    app.command_buffer = "set notif".to_string();
+    app.complete_command();
+    assert_eq!(app.command_buffer, "set notifications_enabled=");
+
+    app.command_buffer = "sort t".to_string();
+    assert_eq!(app.command_suggestions(), vec!["title"]);
+}
+
+#[test]
+fn test_command_history_navigation() {
+    let mut app = App::default();
+    app.command_history = vec!["refresh".to_string(), "cat Tech".to_string()];
+    app.start_command_mode();
+
+    app.command_history_next();
+    assert_eq!(app.command_buffer, "");
+    app.command_history_previous();
+    assert_eq!(app.command_buffer, "cat Tech");
+    app.command_history_previous();
+    assert_eq!(app.command_buffer, "refresh");
+    app.command_history_previous();
+    assert_eq!(app.command_buffer, "refresh");
+    app.command_history_next();
+    assert_eq!(app.command_buffer, "cat Tech");
+    app.command_history_next();
+    assert_eq!(app.command_buffer, "");
+}
+
+#[test]
+fn test_commands_with_arguments() {
+    let mut app = App::default();
+    app.current_feed_content = (0..3)
+        .map(|i| FeedItem {
+            title: format!("Article {}", i),
+            description: String::new(),
+            link: String::new(),
+            published: None,
+            id: format!("command-{}", i),
+            feed_url: "https://example.com/feed".to_string(),
+            enclosure: None,
+        })
+        .collect();
+
+    app.command_buffer = "filter title:\"Article 1\"".to_string();
+    assert!(app.execute_command().unwrap());
+    assert_eq!(app.visible_item_count(), 1);
+    app.command_buffer = "filter".to_string();
+    assert!(app.execute_command().unwrap());
+    assert_eq!(app.visible_item_count(), 3);
+
+    app.command_buffer = "open 4".to_string();
+    assert!(!app.execute_command().unwrap());
+    app.command_buffer = "open 2".to_string();
+    assert!(app.execute_command().unwrap());
+    assert_eq!(app.selected_index, Some(1));
+
+    // The URL keeps its case and the category may contain spaces
+    app.command_buffer = "add https://Example.com/RSS My News".to_string();
+    assert!(app.execute_command().unwrap());
+    assert_eq!(
+        app.pending_subscription,
+        Some((
+            "https://Example.com/RSS".to_string(),
+            Some("My News".to_string())
+        ))
+    );
+
+    app.command_buffer = "rename New title".to_string();
+    assert!(!app.execute_command().unwrap());
+    assert_eq!(app.error_message.as_deref(), Some("No feed selected"));
+    app.command_buffer = "delete Nothing".to_string();
+    assert!(!app.execute_command().unwrap());
+
+    app.command_buffer = "set http_timeout_secs=soon".to_string();
+    assert!(!app.execute_command().unwrap());
+    app.command_buffer = "set bogus=1".to_string();
+    assert!(!app.execute_command().unwrap());
+    app.command_buffer = "set mark_read_on_scroll".to_string();
+    assert!(app.execute_command().unwrap());
+    assert_eq!(
+        app.status_message.as_deref(),
+        Some("mark_read_on_scroll=false")
+    );
+
+    let path = std::env::temp_dir().join(format!("reedy-export-{}.json", std::process::id()));
+    app.command_buffer = format!("export json {}", path.display());
+    assert!(app.execute_command().unwrap());
+    let exported: Vec<FeedItem> =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(exported.len(), 3);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_delete_command_asks_for_confirmation() {
+    let mut app = App::default();
+    for (title, category) in [("Alpha", None), ("Rust Blog", Some("Tech"))] {
+        app.rss_feeds.push(FeedInfo {
+            url: format!("https://{}.example/feed", title.replace(' ', "-")),
+            title: title.to_string(),
+            category: category.map(str::to_string),
+            ..Default::default()
+        });
+    }
+    app.collapsed_categories.insert("Tech".to_string());
+
+    // The feed is shown and selected in the Feed Manager, but only deleted on Enter
+    app.command_buffer = "delete rust blog".to_string();
+    assert!(app.execute_command().unwrap());
+    assert_eq!(app.rss_feeds.len(), 2);
+    assert_eq!(app.page_mode, PageMode::FeedManager);
+    assert_eq!(app.input_mode, InputMode::Deleting);
+    let row = app.selected_index.unwrap();
+    assert_eq!(app.feed_index_at(row), Some(1));
+
+    app.delete_feed(row);
+    let titles: Vec<&str> = app.rss_feeds.iter().map(|f| f.title.as_str()).collect();
+    assert_eq!(titles, ["Alpha"]);
+}
+
 #[test]
 fn test_export_article_no_selection() {
     let mut app = App::default();
@@ -1077,9 +1254,10 @@ fn test_feed_health_default() {
 fn test_feed_health_status_indicator() {
     use reedy::app::{FeedHealth, FeedStatus};
 
-    let mut health = FeedHealth::default();
-
-    health.status = FeedStatus::Healthy;
+    let mut health = FeedHealth {
+        status: FeedStatus::Healthy,
+        ..Default::default()
+    };
     assert_eq!(health.status_indicator(), "●");
 
     health.status = FeedStatus::Slow;
@@ -1096,11 +1274,12 @@ fn test_feed_health_status_indicator() {
 fn test_feed_health_status_description() {
     use reedy::app::{FeedHealth, FeedStatus};
 
-    let mut health = FeedHealth::default();
-
     // Healthy with response time
-    health.status = FeedStatus::Healthy;
-    health.last_response_time_ms = Some(250);
+    let mut health = FeedHealth {
+        status: FeedStatus::Healthy,
+        last_response_time_ms: Some(250),
+        ..Default::default()
+    };
     assert_eq!(health.status_description(), "OK (250ms)");
 
     // Healthy without response time
@@ -1197,6 +1376,7 @@ fn test_select_next_marks_read_when_enabled() {
         published: Some(SystemTime::now()),
         id: "item-1".to_string(),
         feed_url: String::new(),
+        enclosure: None,
     };
     let item2 = FeedItem {
         title: "Item 2".to_string(),
@@ -1205,6 +1385,7 @@ fn test_select_next_marks_read_when_enabled() {
         published: Some(SystemTime::now()),
         id: "item-2".to_string(),
         feed_url: String::new(),
+        enclosure: None,
     };
 
     app.current_feed_content = vec![item1.clone(), item2.clone()];
@@ -1238,6 +1419,7 @@ fn test_select_next_does_not_mark_read_when_disabled() {
         published: Some(SystemTime::now()),
         id: "item-1".to_string(),
         feed_url: String::new(),
+        enclosure: None,
     };
     let item2 = FeedItem {
         title: "Item 2".to_string(),
@@ -1246,6 +1428,7 @@ fn test_select_next_does_not_mark_read_when_disabled() {
         published: Some(SystemTime::now()),
         id: "item-2".to_string(),
         feed_url: String::new(),
+        enclosure: None,
     };
 
     app.current_feed_content = vec![item1.clone(), item2];
@@ -1270,12 +1453,12 @@ fn test_select_next_does_not_mark_read_in_feed_manager() {
     app.rss_feeds.push(FeedInfo {
         url: "https://example.com/feed1".to_string(),
         title: "Feed 1".to_string(),
-        category: None,
+        ..Default::default()
     });
     app.rss_feeds.push(FeedInfo {
         url: "https://example.com/feed2".to_string(),
         title: "Feed 2".to_string(),
-        category: None,
+        ..Default::default()
     });
 
     // Also add feed items (for read tracking test)
@@ -1286,6 +1469,7 @@ fn test_select_next_does_not_mark_read_in_feed_manager() {
         published: Some(SystemTime::now()),
         id: "item-1".to_string(),
         feed_url: String::new(),
+        enclosure: None,
     };
     app.current_feed_content = vec![item1.clone()];
 
@@ -1297,3 +1481,1698 @@ fn test_select_next_does_not_mark_read_in_feed_manager() {
     // No items should be marked as read (we're in FeedManager mode)
     assert!(!app.is_item_read(&item1));
 }
+
+#[test]
+fn test_publish_config_defaults() {
+    use reedy::app::{Config, PublishSource, PublishedFeed};
+
+    // Publishing is off unless configured
+    assert!(Config::default().publish.is_empty());
+
+    let json = r#"{"publish": [
+        {"title": "Picks", "path": "/tmp/picks.xml", "source": "favorites"},
+        {"title": "Rust", "path": "~/rust.xml", "source": {"category": "Rust"}, "max_items": 5}
+    ]}"#;
+    let config: Config = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        config.publish[0],
+        PublishedFeed {
+            title: "Picks".to_string(),
+            path: "/tmp/picks.xml".to_string(),
+            source: PublishSource::Favorites,
+            max_items: 50,
+        }
+    );
+    assert_eq!(
+        config.publish[1].source,
+        PublishSource::Category("Rust".to_string())
+    );
+    assert_eq!(config.publish[1].max_items, 5);
+}
+
+#[test]
+fn test_items_for_published_feed_sources() {
+    use reedy::app::{PublishSource, PublishedFeed};
+    use std::time::Duration;
+
+    let mut app = App::default();
+    app.rss_feeds.push(FeedInfo {
+        url: "https://rust.example/feed".to_string(),
+        title: "Rust Blog".to_string(),
+        category: Some("Rust".to_string()),
+        ..Default::default()
+    });
+    app.rss_feeds.push(FeedInfo {
+        url: "https://news.example/feed".to_string(),
+        title: "News".to_string(),
+        ..Default::default()
+    });
+
+    let base = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let items = vec![
+        FeedItem {
+            title: "Older Rust post | Rust Blog".to_string(),
+            description: "Borrow checker".to_string(),
+            link: "https://rust.example/1".to_string(),
+            published: Some(base),
+            id: "rust-1".to_string(),
+            feed_url: "https://rust.example/feed".to_string(),
+            enclosure: None,
+        },
+        FeedItem {
+            title: "Newer Rust post | Rust Blog".to_string(),
+            description: "Async traits".to_string(),
+            link: "https://rust.example/2".to_string(),
+            published: Some(base + Duration::from_secs(60)),
+            id: "rust-2".to_string(),
+            feed_url: "https://rust.example/feed".to_string(),
+            enclosure: None,
+        },
+        FeedItem {
+            title: "Headline | News".to_string(),
+            description: "Something about async runtimes".to_string(),
+            link: "https://news.example/1".to_string(),
+            published: Some(base + Duration::from_secs(30)),
+            id: "news-1".to_string(),
+            feed_url: "https://news.example/feed".to_string(),
+            enclosure: None,
+        },
+    ];
+    app.favorites.insert("news-1".to_string());
+
+    let mut feed = PublishedFeed {
+        title: "Picks".to_string(),
+        path: String::new(),
+        source: PublishSource::Favorites,
+        max_items: 50,
+    };
+    let ids = |selected: Vec<&FeedItem>| -> Vec<String> {
+        selected.iter().map(|i| i.id.clone()).collect()
+    };
+    assert_eq!(ids(app.items_for_published_feed(&feed, &items)), ["news-1"]);
+
+    // Category selection is newest first
+    feed.source = PublishSource::Category("Rust".to_string());
+    assert_eq!(
+        ids(app.items_for_published_feed(&feed, &items)),
+        ["rust-2", "rust-1"]
+    );
+
+    // Search matches descriptions case-insensitively and respects max_items
+    feed.source = PublishSource::Search("ASYNC".to_string());
+    feed.max_items = 1;
+    assert_eq!(ids(app.items_for_published_feed(&feed, &items)), ["rust-2"]);
+}
+
+#[test]
+fn test_generate_atom_feed() {
+    use reedy::app::{PublishSource, PublishedFeed};
+
+    let mut app = App::default();
+    app.rss_feeds.push(FeedInfo {
+        url: "https://rust.example/feed".to_string(),
+        title: "Rust Blog".to_string(),
+        ..Default::default()
+    });
+    let items = vec![FeedItem {
+        title: "Fish & Chips | Rust Blog".to_string(),
+        description: "A <tasty> post".to_string(),
+        link: "https://rust.example/fish".to_string(),
+        published: Some(SystemTime::now()),
+        id: "fish".to_string(),
+        feed_url: "https://rust.example/feed".to_string(),
+        enclosure: None,
+    }];
+    app.favorites.insert("fish".to_string());
+
+    let feed = PublishedFeed {
+        title: "Team Picks".to_string(),
+        path: String::new(),
+        source: PublishSource::Favorites,
+        max_items: 50,
+    };
+    let xml = app.generate_atom_feed(&feed, &items).unwrap();
+
+    assert!(xml.contains(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#));
+    assert!(xml.contains("<title>Team Picks</title>"));
+    // Feed suffix is stripped from the entry title and special characters are escaped
+    assert!(xml.contains("<title>Fish &amp; Chips</title>"));
+    assert!(xml.contains(r#"<link href="https://rust.example/fish"/>"#));
+    assert!(xml.contains("<name>Rust Blog</name>"));
+    assert!(xml.contains("A &lt;tasty&gt; post"));
+
+    // The generated feed parses as valid Atom
+    let parsed = atom_syndication::Feed::read_from(xml.as_bytes()).unwrap();
+    assert_eq!(parsed.entries().len(), 1);
+}
+
+#[test]
+fn test_favorite_items_include_saved_copies() {
+    let mut app = App::default();
+    for id in ["kept-favorite", "kept-tagged"] {
+        let item = FeedItem {
+            title: id.to_string(),
+            description: String::new(),
+            link: String::new(),
+            published: None,
+            id: id.to_string(),
+            feed_url: "https://gone.example/feed".to_string(),
+            enclosure: None,
+        };
+        app.tagged_items.insert(
+            item.id.clone(),
+            reedy::app::TaggedItem {
+                item,
+                tags: ["keep".to_string()].into_iter().collect(),
+            },
+        );
+    }
+    app.favorites.insert("kept-favorite".to_string());
+
+    // Favorites that left their feed are still listed and published from the saved copy
+    let ids: Vec<String> = app.favorite_items().into_iter().map(|i| i.id).collect();
+    assert_eq!(ids, ["kept-favorite"]);
+}
+
+#[tokio::test]
+async fn test_auto_refresh_keeps_saved_favorites_listed() {
+    let mut app = App::default();
+    let item = |id: &str, feed_url: &str| FeedItem {
+        title: id.to_string(),
+        description: String::new(),
+        link: String::new(),
+        published: None,
+        id: id.to_string(),
+        feed_url: feed_url.to_string(),
+        enclosure: None,
+    };
+    // A paused feed is read from the cache, so the refresh needs no network
+    app.rss_feeds.push(FeedInfo {
+        url: "https://paused.example/feed".to_string(),
+        title: "Paused".to_string(),
+        paused: true,
+        ..Default::default()
+    });
+    app.set_cached_items(
+        "https://paused.example/feed",
+        vec![
+            item("in-feed", "https://paused.example/feed"),
+            item("not-favorite", "https://paused.example/feed"),
+        ],
+    );
+    app.tagged_items.insert(
+        "saved".to_string(),
+        reedy::app::TaggedItem {
+            item: item("saved", "https://gone.example/feed"),
+            tags: ["keep".to_string()].into_iter().collect(),
+        },
+    );
+    app.favorites.insert("in-feed".to_string());
+    app.favorites.insert("saved".to_string());
+    app.page_mode = PageMode::Favorites;
+
+    app.auto_refresh_pending = true;
+    app.perform_auto_refresh().await;
+
+    let mut ids: Vec<&str> = app
+        .current_feed_content
+        .iter()
+        .map(|i| i.id.as_str())
+        .collect();
+    ids.sort();
+    assert_eq!(ids, ["in-feed", "saved"]);
+}
+
+#[test]
+fn test_opener_build_command() {
+    use reedy::app::{ExternalCommand, Opener};
+
+    let opener = Opener::new("youtube\\.com", "mpv --no-terminal {url}", false);
+    assert!(opener.matches("https://www.youtube.com/watch?v=1"));
+    assert!(!opener.matches("https://example.com"));
+    assert_eq!(
+        opener.build_command("https://youtu.be/1"),
+        Some(ExternalCommand {
+            program: "mpv".to_string(),
+            args: vec![
+                "--no-terminal".to_string(),
+                "https://youtu.be/1".to_string()
+            ],
+            ..Default::default()
+        })
+    );
+
+    // The URL is appended when there is no placeholder
+    let opener = Opener::new(".*", "'my viewer'", true);
+    let command = opener.build_command("https://example.com").unwrap();
+    assert_eq!(command.program, "my viewer");
+    assert_eq!(command.args, vec!["https://example.com".to_string()]);
+
+    // An unset variable expands to nothing, so the opener is skipped
+    let opener = Opener::new(".*", "$REEDY_TEST_UNSET_OPENER_VAR", false);
+    assert_eq!(opener.build_command("https://example.com"), None);
+
+    // Invalid patterns never match
+    let opener = Opener::new("(", "true", false);
+    assert!(!opener.matches("("));
+}
+
+#[test]
+fn test_resolve_opener_checks_link_and_enclosure() {
+    let mut app = App::default();
+    app.config.openers = serde_json::from_str(
+        r#"[
+            {"pattern": "\\.pdf$", "command": "zathura"},
+            {"pattern": "^https?://", "command": "w3m {url}", "foreground": true}
+        ]"#,
+    )
+    .unwrap();
+
+    let mut item = FeedItem {
+        title: "Paper | Journal".to_string(),
+        description: String::new(),
+        link: "https://journal.example/paper".to_string(),
+        published: None,
+        id: "paper".to_string(),
+        feed_url: String::new(),
+        enclosure: Some("https://journal.example/paper.pdf".to_string()),
+    };
+
+    // First matching opener wins, even when it matches the enclosure rather than the link
+    let (command, foreground) = app.resolve_opener(&item).unwrap();
+    assert_eq!(command.program, "zathura");
+    assert_eq!(
+        command.args,
+        vec!["https://journal.example/paper.pdf".to_string()]
+    );
+    assert!(!foreground);
+
+    item.enclosure = None;
+    let (command, foreground) = app.resolve_opener(&item).unwrap();
+    assert_eq!(command.program, "w3m");
+    assert!(foreground);
+
+    // Without a match the system browser is used
+    app.config.openers.clear();
+    assert!(app.resolve_opener(&item).is_none());
+}
+
+#[test]
+fn test_open_selected_feed_queues_foreground_opener() {
+    use reedy::app::Opener;
+
+    let mut app = App::default();
+    app.config
+        .openers
+        .push(Opener::new(".*", "lynx {url}", true));
+    app.current_feed_content.push(FeedItem {
+        title: "Post | Blog".to_string(),
+        description: String::new(),
+        link: "https://blog.example/post".to_string(),
+        published: None,
+        id: "post".to_string(),
+        feed_url: String::new(),
+        enclosure: None,
+    });
+    app.selected_index = Some(0);
+
+    app.open_selected_feed();
+    let command = app.pending_external.take().unwrap();
+    assert_eq!(command.program, "lynx");
+    assert_eq!(command.args, vec!["https://blog.example/post".to_string()]);
+}
+
+#[test]
+fn test_config_validate() {
+    use reedy::app::{Config, Opener};
+
+    assert!(Config::default().validate().is_ok());
+
+    let mut config = Config {
+        http_timeout_secs: 0,
+        ..Default::default()
+    };
+    config.keybindings.quit = "q,NotAKey".to_string();
+    config.openers.push(Opener::new("[unclosed", "mpv", false));
+    config.api.enabled = true;
+    config.theme.primary = "not-a-color".to_string();
+
+    let problems = config.validate().unwrap_err();
+    assert_eq!(problems.len(), 5);
+    assert!(problems.iter().any(|p| p.starts_with("theme.primary")));
+    assert!(problems.iter().any(|p| p.starts_with("http_timeout_secs")));
+    assert!(problems.iter().any(|p| p.starts_with("keybindings.quit")));
+    assert!(problems.iter().any(|p| p.contains("[unclosed")));
+    assert!(problems.iter().any(|p| p.starts_with("api.token")));
+
+    let mut config = Config {
+        http_timeout_secs: 601,
+        ..Default::default()
+    };
+    config.api.port = 0;
+    let problems = config.validate().unwrap_err();
+    assert_eq!(problems.len(), 2);
+    assert!(problems.iter().any(|p| p.starts_with("http_timeout_secs")));
+    assert!(problems.iter().any(|p| p.starts_with("api.port")));
+}
+
+#[test]
+fn test_settings_cover_config() {
+    let mut app = App::default();
+    let settings = app.settings();
+
+    // Every config field is listed, objects one field at a time
+    let config = serde_json::to_value(&app.config).unwrap();
+    for field in config.as_object().unwrap().keys() {
+        assert!(
+            settings
+                .iter()
+                .any(|s| s.key == *field || s.key.starts_with(&format!("{}.", field))),
+            "{} missing from settings",
+            field
+        );
+    }
+    let quit = settings
+        .iter()
+        .find(|s| s.key == "keybindings.quit")
+        .unwrap();
+    assert_eq!(quit.value, "q");
+    assert!(quit.editable);
+    let rules = settings.iter().find(|s| s.key == "mute_rules").unwrap();
+    assert_eq!(rules.value, "0 entries");
+    assert!(!rules.editable);
+
+    // Invalid values are rejected and leave the config unchanged
+    assert!(app.set_config_value("http_timeout_secs", "soon").is_err());
+    assert!(app.set_config_value("http_timeout_secs", "0").is_err());
+    assert!(app.set_config_value("theme.text", "plaid").is_err());
+    assert!(app.set_config_value("keybindings.quit", "NotAKey").is_err());
+    assert!(app.set_config_value("api.port", "99999").is_err());
+    assert!(app.set_config_value("mute_rules", "x").is_err());
+    assert!(app.set_config_value("no_such_key", "1").is_err());
+    assert_eq!(app.config.http_timeout_secs, 30);
+    assert_eq!(app.config_value("theme.text").as_deref(), Some("white"));
+
+    // The page edits in place, keeping the input open on errors
+    app.open_settings();
+    assert_eq!(app.page_mode, PageMode::Settings);
+    app.start_editing_setting();
+    assert_eq!(app.input_mode, InputMode::EditingSetting);
+    assert_eq!(app.input_buffer, "30");
+    app.input_buffer = "-1".to_string();
+    app.confirm_setting();
+    assert_eq!(app.input_mode, InputMode::EditingSetting);
+    assert!(app.error_message.is_some());
+    app.cancel_setting_input();
+
+    // Resetting a value asks first
+    app.config.http_timeout_secs = 45;
+    app.start_resetting_setting();
+    assert_eq!(app.input_mode, InputMode::ResettingSetting);
+    app.cancel_setting_input();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(app.config.http_timeout_secs, 45);
+    app.close_settings();
+    assert_eq!(app.page_mode, PageMode::FeedList);
+}
+
+#[test]
+fn test_read_config_reports_problems() {
+    let path = std::env::temp_dir().join(format!("reedy-config-{}.json", std::process::id()));
+
+    std::fs::write(
+        &path,
+        r#"{"auto_refresh_mins": 15, "theme": {"primary": "blue"}}"#,
+    )
+    .unwrap();
+    let config = App::read_config(&path).unwrap();
+    assert_eq!(config.auto_refresh_mins, 15);
+    assert_eq!(config.theme.primary, "blue");
+    assert_eq!(config.theme.text, "white");
+
+    std::fs::write(&path, r#"{"auto_refresh_mins": "soon""#).unwrap();
+    assert!(App::read_config(&path).is_err());
+
+    std::fs::write(
+        &path,
+        r#"{"http_timeout_secs": 0, "theme": {"text": "plaid"}}"#,
+    )
+    .unwrap();
+    let error = App::read_config(&path).unwrap_err();
+    assert!(error.contains("http_timeout_secs"));
+    assert!(error.contains("theme.text"));
+
+    std::fs::remove_file(&path).unwrap();
+    assert!(App::read_config(&path).is_err());
+}
+
+#[test]
+fn test_view_in_pager_queues_article() {
+    let mut app = App::default();
+    app.view_in_pager();
+    assert!(app.pending_external.is_none());
+    assert_eq!(app.error_message, Some("No article selected".to_string()));
+
+    app.current_feed_content.push(FeedItem {
+        title: "Paged | Blog".to_string(),
+        description: "Body text".to_string(),
+        link: "https://blog.example/paged".to_string(),
+        published: None,
+        id: "paged".to_string(),
+        feed_url: String::new(),
+        enclosure: None,
+    });
+    app.selected_index = Some(0);
+
+    app.view_in_pager();
+    let command = app.pending_external.take().unwrap();
+    let input = command.input.unwrap();
+    assert!(input.starts_with("# Paged | Blog"));
+    assert!(input.contains("Body text"));
+    assert!(!command.reload_config);
+
+    app.config.text_browser = "lynx -dump".to_string();
+    app.open_in_text_browser();
+    let command = app.pending_external.take().unwrap();
+    assert_eq!(command.program, "lynx");
+    assert_eq!(
+        command.args,
+        vec![
+            "-dump".to_string(),
+            "https://blog.example/paged".to_string()
+        ]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_external_command() {
+    use reedy::app::ExternalCommand;
+
+    let mut app = App::default();
+    let out = std::env::temp_dir().join(format!("reedy-external-{}.txt", std::process::id()));
+
+    // Input is piped to the program's stdin
+    app.run_external_command(ExternalCommand {
+        program: "sh".to_string(),
+        args: vec![
+            "-c".to_string(),
+            "cat > \"$0\"".to_string(),
+            out.to_string_lossy().to_string(),
+        ],
+        input: Some("piped article".to_string()),
+        reload_config: false,
+    });
+    assert_eq!(app.error_message, None);
+    assert_eq!(std::fs::read_to_string(&out).unwrap(), "piped article");
+    let _ = std::fs::remove_file(&out);
+
+    app.run_external_command(ExternalCommand {
+        program: "false".to_string(),
+        ..Default::default()
+    });
+    assert!(app
+        .error_message
+        .take()
+        .unwrap()
+        .starts_with("false exited with"));
+
+    app.run_external_command(ExternalCommand {
+        program: "reedy-no-such-program".to_string(),
+        ..Default::default()
+    });
+    assert!(app
+        .error_message
+        .unwrap()
+        .starts_with("Failed to run reedy-no-such-program"));
+}
+
+#[test]
+fn test_saved_search_counts_and_publishing() {
+    use reedy::app::{PublishSource, PublishedFeed, SavedSearch};
+
+    let mut app = App::default();
+    app.config.saved_searches.push(SavedSearch::new(
+        "Rust jobs",
+        "rust title:/hiring|job/",
+        false,
+    ));
+    let items: Vec<FeedItem> = ["Rust job at Acme", "Hiring Rust devs", "Go job"]
+        .iter()
+        .map(|title| FeedItem {
+            title: format!("{} | Jobs", title),
+            description: String::new(),
+            link: String::new(),
+            published: None,
+            id: title.to_string(),
+            feed_url: String::new(),
+            enclosure: None,
+        })
+        .collect();
+    app.set_item_read("Rust job at Acme", true);
+
+    let search = app.config.saved_searches[0].clone();
+    assert_eq!(app.count_for_saved_search(&search, &items), (1, 2));
+
+    let feed = PublishedFeed {
+        title: "Jobs".to_string(),
+        path: String::new(),
+        source: PublishSource::SavedSearch("Rust jobs".to_string()),
+        max_items: 50,
+    };
+    assert_eq!(app.items_for_published_feed(&feed, &items).len(), 2);
+
+    app.config.saved_searches[0].unread_only = true;
+    let search = app.config.saved_searches[0].clone();
+    assert_eq!(app.count_for_saved_search(&search, &items), (1, 1));
+    assert_eq!(app.items_for_published_feed(&feed, &items).len(), 1);
+}
+
+#[test]
+fn test_saved_searches_in_feed_manager() {
+    use reedy::app::SavedSearch;
+
+    let mut app = App::default();
+    app.rss_feeds.push(FeedInfo {
+        url: "https://example.com/feed".to_string(),
+        title: "Example".to_string(),
+        ..Default::default()
+    });
+    app.config.saved_searches.push(SavedSearch::new(
+        "Security advisories",
+        "title:advisory OR cve",
+        true,
+    ));
+
+    app.toggle_feed_manager();
+    assert_eq!(app.feed_manager_len(), 2);
+    assert!(app.saved_search_at(0).is_none());
+    assert_eq!(
+        app.saved_search_at(1).map(|s| s.name.as_str()),
+        Some("Security advisories")
+    );
+
+    // Navigation covers the saved search rows
+    app.select_next();
+    assert_eq!(app.selected_index, Some(1));
+    app.select_next();
+    assert_eq!(app.selected_index, Some(0));
+    app.scroll_to_bottom();
+    assert_eq!(app.selected_index, Some(1));
+}
+
+#[test]
+fn test_read_changes_recount_their_feed_and_later_saved_searches() {
+    use reedy::app::SavedSearch;
+
+    let mut app = App::default();
+    app.config
+        .saved_searches
+        .push(SavedSearch::new("Releases", "release", false));
+    for host in ["a", "b"] {
+        let url = format!("https://{}.example/feed", host);
+        app.rss_feeds.push(FeedInfo {
+            url: url.clone(),
+            title: host.to_string(),
+            ..Default::default()
+        });
+        let items = (0..2)
+            .map(|i| FeedItem {
+                title: format!("Release {} | {}", i, host),
+                description: String::new(),
+                link: String::new(),
+                published: None,
+                id: format!("recount-{}-{}", host, i),
+                feed_url: url.clone(),
+                enclosure: None,
+            })
+            .collect();
+        app.set_cached_items(&url, items);
+    }
+    app.tick();
+    let search = app.config.saved_searches[0].clone();
+    assert_eq!(app.saved_search_count(&search), (4, 4));
+
+    app.set_item_read("recount-a-0", true);
+    assert_eq!(app.count_unread_for_feed("https://a.example/feed"), 1);
+    assert_eq!(app.count_unread_for_feed("https://b.example/feed"), 2);
+    // Saved searches are recounted on the next tick rather than on every keypress
+    assert_eq!(app.saved_search_count(&search), (4, 4));
+    app.tick();
+    assert_eq!(app.saved_search_count(&search), (3, 4));
+}
+
+#[test]
+fn test_save_current_search_requires_active_search() {
+    let mut app = App::default();
+
+    app.command_buffer = "ss Rust jobs".to_string();
+    assert!(!app.execute_command().unwrap());
+    assert_eq!(
+        app.error_message,
+        Some("No active search to save".to_string())
+    );
+
+    app.command_buffer = "savesearch".to_string();
+    assert!(!app.execute_command().unwrap());
+    assert_eq!(
+        app.error_message,
+        Some("Usage: :savesearch <name>".to_string())
+    );
+
+    app.search_query = "(unbalanced".to_string();
+    app.update_search_filter();
+    assert!(!app.save_current_search("Broken"));
+    assert!(app.config.saved_searches.is_empty());
+}
+
+fn muted_test_items() -> Vec<FeedItem> {
+    ["Weekly deals", "Sponsored: buy now", "Release notes"]
+        .iter()
+        .map(|title| FeedItem {
+            title: title.to_string(),
+            description: String::new(),
+            link: String::new(),
+            published: None,
+            id: format!("mute-test-{}", title),
+            feed_url: String::new(),
+            enclosure: None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_mute_rules_hide_until_show_muted() {
+    use reedy::app::{MuteAction, MuteRule};
+
+    let mut app = App::default();
+    app.config
+        .mute_rules
+        .push(MuteRule::new("title:/^sponsored/", MuteAction::Hide));
+    app.current_feed_content = muted_test_items();
+    app.update_search_filter();
+
+    assert_eq!(app.filtered_indices, Some(vec![0, 2]));
+    assert_eq!(app.muted_count, 1);
+    // Muted items are not a user filter, so Esc still quits
+    assert!(!app.has_active_filter());
+
+    app.toggle_show_muted();
+    assert_eq!(app.filtered_indices, None);
+    assert_eq!(app.visible_item_count(), 3);
+
+    app.toggle_show_muted();
+    assert_eq!(app.visible_item_count(), 2);
+
+    // Searching only looks at unmuted items
+    app.search_query = "deals OR sponsored".to_string();
+    app.update_search_filter();
+    assert_eq!(app.filtered_indices, Some(vec![0]));
+    assert!(app.has_active_filter());
+}
+
+#[test]
+fn test_mute_rules_mark_read_and_validation() {
+    use reedy::app::{Config, MuteAction, MuteRule};
+
+    let mut app = App::default();
+    app.config
+        .mute_rules
+        .push(MuteRule::new("deals", MuteAction::MarkRead));
+    let items = muted_test_items();
+
+    assert_eq!(app.apply_mute_rules(&items), 1);
+    assert!(app.is_item_read(&items[0]));
+    assert!(!app.is_item_read(&items[1]));
+    assert!(app.is_item_muted(&items[0]));
+    // Already read items aren't counted again
+    assert_eq!(app.apply_mute_rules(&items), 0);
+    // "mark read" rules don't hide anything
+    app.current_feed_content = items;
+    app.update_search_filter();
+    assert_eq!(app.visible_item_count(), 3);
+    app.set_item_read("mute-test-Weekly deals", false);
+
+    let mut config = Config::default();
+    config
+        .mute_rules
+        .push(MuteRule::new("title:/[/", MuteAction::Hide));
+    let problems = config.validate().unwrap_err();
+    assert!(problems[0].starts_with("mute_rules"));
+}
+
+#[test]
+fn test_mute_rules_screen_navigation() {
+    use reedy::app::{MuteAction, MuteRule};
+
+    let mut app = App::default();
+    for query in ["feed:spam", "domain:ads.example.com"] {
+        app.config
+            .mute_rules
+            .push(MuteRule::new(query, MuteAction::Hide));
+    }
+
+    app.open_mute_rules();
+    assert_eq!(app.page_mode, PageMode::MuteRules);
+    assert_eq!(app.selected_index, Some(0));
+    app.select_next();
+    assert_eq!(app.selected_index, Some(1));
+    app.select_next();
+    assert_eq!(app.selected_index, Some(0));
+
+    // Editing prefills the query; invalid queries are rejected
+    app.start_editing_mute_rule();
+    assert_eq!(app.input_mode, InputMode::Adding);
+    assert_eq!(app.input_buffer, "feed:spam");
+    app.input_buffer = "feed:(".to_string();
+    app.confirm_mute_rule();
+    assert!(app.error_message.is_some());
+    assert_eq!(app.config.mute_rules[0].query, "feed:spam");
+    app.cancel_mute_rule_input();
+    assert_eq!(app.input_mode, InputMode::Normal);
+
+    app.close_mute_rules();
+    assert_eq!(app.page_mode, PageMode::FeedList);
+}
+
+fn highlight_test_items() -> Vec<FeedItem> {
+    [
+        "Weekly roundup",
+        "CVE-2026-1234 in libfoo",
+        "Reedy 1.0 released",
+        "CVE-2026-9999 patched",
+    ]
+    .iter()
+    .map(|title| FeedItem {
+        title: title.to_string(),
+        description: String::new(),
+        link: String::new(),
+        published: None,
+        id: format!("highlight-test-{}", title),
+        feed_url: String::new(),
+        enclosure: None,
+    })
+    .collect()
+}
+
+#[test]
+fn test_highlight_rules_match_and_pin_unread_items() {
+    use reedy::app::HighlightRule;
+
+    let mut app = App::default();
+    app.config.highlight_rules = vec![
+        HighlightRule {
+            query: "title:/CVE-\\d{4}-\\d+/".to_string(),
+            name: Some("CVEs".to_string()),
+            color: None,
+            pin: true,
+            notify: true,
+        },
+        HighlightRule {
+            query: "reedy".to_string(),
+            name: None,
+            color: Some("yellow".to_string()),
+            pin: false,
+            notify: false,
+        },
+    ];
+    app.current_feed_content = highlight_test_items();
+    app.set_item_read("highlight-test-CVE-2026-9999 patched", true);
+    app.update_search_filter();
+
+    // Only the unread CVE is pinned; the rest keep their order
+    assert_eq!(app.filtered_indices, Some(vec![1, 0, 2, 3]));
+    assert_eq!(app.get_actual_index(0), Some(1));
+    assert!(!app.has_active_filter());
+
+    let rules = app.compiled_highlight_rules();
+    let items = app.current_feed_content.clone();
+    assert_eq!(
+        app.highlight_rule_for(&items[1], &rules)
+            .map(|r| r.display_name()),
+        Some("CVEs")
+    );
+    assert_eq!(
+        app.highlight_rule_for(&items[2], &rules)
+            .map(|r| r.display_name()),
+        Some("reedy")
+    );
+    assert!(app.highlight_rule_for(&items[0], &rules).is_none());
+    // Read items are not highlighted
+    assert!(app.highlight_rule_for(&items[3], &rules).is_none());
+
+    // Alerts are grouped per notifying rule
+    let new_items: Vec<&FeedItem> = items.iter().collect();
+    let alerts = app.alerts_for_new_items(&new_items);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].0.display_name(), "CVEs");
+    assert_eq!(alerts[0].1.len(), 2);
+
+    app.set_item_read("highlight-test-CVE-2026-9999 patched", false);
+}
+
+#[test]
+fn test_article_tags_persist_after_leaving_feed() {
+    let mut app = App::default();
+    let items: Vec<FeedItem> = ["Tagged article", "Other article"]
+        .iter()
+        .map(|title| FeedItem {
+            title: title.to_string(),
+            description: String::new(),
+            link: String::new(),
+            published: None,
+            id: format!("tag-test-{}", title),
+            feed_url: String::new(),
+            enclosure: None,
+        })
+        .collect();
+    app.current_feed_content = items.clone();
+    app.selected_index = Some(0);
+
+    // The picker completes known tags and applies add/remove lists
+    app.start_tagging();
+    assert_eq!(app.input_mode, InputMode::Tagging);
+    app.input_buffer = "Research, #to-share".to_string();
+    app.confirm_tagging();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(
+        app.item_tags(&items[0].id).collect::<Vec<_>>(),
+        vec!["research", "to-share"]
+    );
+
+    app.selected_index = Some(1);
+    app.start_tagging();
+    app.input_buffer = "to".to_string();
+    app.complete_tag();
+    assert_eq!(app.input_buffer, "to-share, ");
+    app.input_buffer = "re".to_string();
+    assert_eq!(app.tag_suggestions(), vec!["research"]);
+    app.cancel_tagging();
+    assert!(!app.tagged_items.contains_key(&items[1].id));
+
+    // The article drops out of the feed but stays in the tag view
+    app.current_feed_content = vec![items[1].clone()];
+    assert!(app.open_tag_view("Research"));
+    assert_eq!(app.active_tag.as_deref(), Some("research"));
+    assert_eq!(app.current_feed_content.len(), 1);
+    assert_eq!(app.current_feed_content[0].id, items[0].id);
+    assert!(app.has_active_filter());
+    assert!(!app.open_tag_view("nope"));
+
+    assert!(app.apply_tag_input(&items[0], "-research -to-share"));
+    assert!(app.tagged_items.is_empty());
+}
+
+#[test]
+fn test_tag_query_and_publishing() {
+    use reedy::app::{PublishSource, PublishedFeed};
+    use reedy::query::Query;
+
+    let mut app = App::default();
+    let item = FeedItem {
+        title: "Old post | Blog".to_string(),
+        description: String::new(),
+        link: "https://blog.example/old".to_string(),
+        published: None,
+        id: "tag-test-old-post".to_string(),
+        feed_url: String::new(),
+        enclosure: None,
+    };
+    app.tagged_items.insert(
+        item.id.clone(),
+        reedy::app::TaggedItem {
+            item: item.clone(),
+            tags: ["weekly-digest".to_string()].into_iter().collect(),
+        },
+    );
+
+    let query = Query::parse("tag:Weekly-Digest").unwrap().unwrap();
+    assert!(query.matches(&item, &app));
+    assert!(Query::parse("is:tagged")
+        .unwrap()
+        .unwrap()
+        .matches(&item, &app));
+
+    let feed = PublishedFeed {
+        title: "Digest".to_string(),
+        path: String::new(),
+        source: PublishSource::Tag("weekly-digest".to_string()),
+        max_items: 50,
+    };
+    let snapshots = app.tagged_snapshots();
+    assert_eq!(app.items_for_published_feed(&feed, &snapshots).len(), 1);
+    let xml = app.generate_atom_feed(&feed, &snapshots).unwrap();
+    assert!(xml.contains("https://blog.example/old"));
+}
+
+/// Puts the loaded articles into the in-memory cache of their feeds
+fn cache_loaded_items(app: &mut App) {
+    let mut by_feed: std::collections::HashMap<String, Vec<FeedItem>> = Default::default();
+    for item in &app.current_feed_content {
+        by_feed
+            .entry(item.feed_url.clone())
+            .or_default()
+            .push(item.clone());
+    }
+    for (url, items) in by_feed {
+        app.set_cached_items(&url, items);
+    }
+}
+
+#[test]
+fn test_category_scope_cycles_and_filters() {
+    use reedy::app::CategoryScope;
+
+    let mut app = App::default();
+    for (url, category) in [
+        ("https://a.example/feed", Some("Tech")),
+        ("https://b.example/feed", Some("News")),
+        ("https://c.example/feed", None),
+    ] {
+        app.rss_feeds.push(FeedInfo {
+            url: url.to_string(),
+            title: url.to_string(),
+            category: category.map(str::to_string),
+            ..Default::default()
+        });
+    }
+    app.current_feed_content = ["a", "b", "c", "a"]
+        .iter()
+        .enumerate()
+        .map(|(i, host)| FeedItem {
+            title: format!("Item {}", i),
+            description: String::new(),
+            link: String::new(),
+            published: None,
+            id: format!("scope-test-{}", i),
+            feed_url: format!("https://{}.example/feed", host),
+            enclosure: None,
+        })
+        .collect();
+    cache_loaded_items(&mut app);
+
+    // Order follows the Feed Manager: all, uncategorized, then alphabetical
+    assert_eq!(
+        app.category_scopes(),
+        vec![
+            CategoryScope::All,
+            CategoryScope::Uncategorized,
+            CategoryScope::Category("News".to_string()),
+            CategoryScope::Category("Tech".to_string()),
+        ]
+    );
+
+    app.cycle_category(false);
+    assert_eq!(
+        app.category_scope,
+        CategoryScope::Category("Tech".to_string())
+    );
+    assert_eq!(app.filtered_indices, Some(vec![0, 3]));
+    assert_eq!(app.count_for_scope(&app.category_scope), (2, 2));
+    assert!(!app.has_active_filter());
+
+    app.cycle_category(true);
+    assert_eq!(app.category_scope, CategoryScope::All);
+    assert_eq!(app.visible_item_count(), 4);
+
+    app.cycle_category(true);
+    assert_eq!(app.category_scope, CategoryScope::Uncategorized);
+    assert_eq!(app.filtered_indices, Some(vec![2]));
+
+    // Search combines with the scope and clearing it keeps the scope
+    assert!(app.set_category_scope_by_name("tech"));
+    app.search_query = "item 3".to_string();
+    app.update_search_filter();
+    assert_eq!(app.filtered_indices, Some(vec![3]));
+    app.clear_search();
+    assert_eq!(app.filtered_indices, Some(vec![0, 3]));
+
+    assert!(!app.set_category_scope_by_name("Sports"));
+    assert_eq!(
+        app.category_scope,
+        CategoryScope::Category("Tech".to_string())
+    );
+}
+
+#[test]
+fn test_feed_manager_category_tree() {
+    use reedy::app::{CategoryScope, FeedManagerRow};
+
+    let mut app = App::default();
+    for (url, category, tags) in [
+        ("https://a.example/feed", Some("Tech/Rust/Blogs"), vec![]),
+        ("https://b.example/feed", Some("Tech"), vec!["rust"]),
+        ("https://c.example/feed", None, vec![]),
+    ] {
+        app.rss_feeds.push(FeedInfo {
+            url: url.to_string(),
+            title: url.to_string(),
+            category: category.map(str::to_string),
+            tags: tags.into_iter().map(str::to_string).collect(),
+            ..Default::default()
+        });
+    }
+
+    // Uncategorized feeds first, then each category followed by its feeds and sub-categories
+    let tech = |depth, collapsed| FeedManagerRow::Category {
+        path: "Tech".to_string(),
+        depth,
+        collapsed,
+    };
+    assert_eq!(
+        app.feed_manager_rows(),
+        vec![
+            FeedManagerRow::Feed { index: 2, depth: 0 },
+            tech(0, false),
+            FeedManagerRow::Feed { index: 1, depth: 1 },
+            FeedManagerRow::Category {
+                path: "Tech/Rust".to_string(),
+                depth: 1,
+                collapsed: false,
+            },
+            FeedManagerRow::Category {
+                path: "Tech/Rust/Blogs".to_string(),
+                depth: 2,
+                collapsed: false,
+            },
+            FeedManagerRow::Feed { index: 0, depth: 3 },
+        ]
+    );
+    assert_eq!(app.feed_index_at(5), Some(0));
+    assert_eq!(app.count_for_category("Tech"), (2, 0));
+
+    app.toggle_category_collapse(1);
+    assert_eq!(
+        app.feed_manager_rows(),
+        vec![FeedManagerRow::Feed { index: 2, depth: 0 }, tech(0, true)]
+    );
+    app.toggle_category_collapse(1);
+    assert_eq!(app.feed_manager_len(), 6);
+
+    // A category scope includes nested categories and feeds tagged with its name
+    let item = |host: &str| FeedItem {
+        title: String::new(),
+        description: String::new(),
+        link: String::new(),
+        published: None,
+        id: host.to_string(),
+        feed_url: format!("https://{}.example/feed", host),
+        enclosure: None,
+    };
+    let tech_scope = CategoryScope::Category("Tech".to_string());
+    let rust_scope = CategoryScope::Category("Tech/Rust".to_string());
+    assert!(app.item_in_scope(&item("a"), &tech_scope));
+    // Category paths are compared as written, like the rows of the tree
+    assert!(!app.item_in_scope(&item("a"), &CategoryScope::Category("tech".to_string())));
+    assert!(app.item_in_scope(&item("b"), &tech_scope));
+    assert!(app.item_in_scope(&item("a"), &rust_scope));
+    assert!(!app.item_in_scope(&item("b"), &rust_scope));
+    assert!(app.item_in_scope(&item("b"), &CategoryScope::Category("rust".to_string())));
+    assert!(app
+        .category_scopes()
+        .contains(&CategoryScope::Category("rust".to_string())));
+
+    // Renaming a category moves everything below it, but not a category spelled differently
+    app.rss_feeds[2].category = Some("tech/rust/Ünïcode".to_string());
+    app.selected_index = (0..app.feed_manager_len())
+        .find(|&row| app.category_at(row).as_deref() == Some("Tech/Rust"));
+    app.start_setting_category();
+    assert_eq!(app.input_buffer, "Tech/Rust");
+    app.input_buffer = " Lang / Rust ".to_string();
+    app.set_category();
+    assert_eq!(
+        app.rss_feeds[0].category.as_deref(),
+        Some("Lang/Rust/Blogs")
+    );
+    assert_eq!(app.rss_feeds[1].category.as_deref(), Some("Tech"));
+    assert_eq!(
+        app.rss_feeds[2].category.as_deref(),
+        Some("tech/rust/Ünïcode")
+    );
+    app.rss_feeds[2].category = None;
+
+    // Deleting a category row is refused
+    app.delete_feed(1);
+    assert_eq!(app.rss_feeds.len(), 3);
+    assert!(app.error_message.is_some());
+}
+
+#[tokio::test]
+async fn test_opml_round_trips_nested_categories_and_tags() {
+    let mut app = App::default();
+    for (url, category, tags) in [
+        (
+            "https://a.example/feed",
+            Some("Tech/Rust/Blogs"),
+            vec!["rust", "weekly"],
+        ),
+        ("https://b.example/feed", Some("Tech"), vec![]),
+        ("https://c.example/feed", None, vec!["news"]),
+    ] {
+        app.rss_feeds.push(FeedInfo {
+            url: url.to_string(),
+            title: format!("Feed {}", url),
+            category: category.map(str::to_string),
+            tags: tags.into_iter().map(str::to_string).collect(),
+            ..Default::default()
+        });
+    }
+    let opml = app.generate_opml().unwrap();
+
+    let mut imported = App::default();
+    imported.import_opml_content(&opml).await.unwrap();
+    assert_eq!(imported.rss_feeds.len(), 3);
+    for feed in &app.rss_feeds {
+        let copy = imported
+            .rss_feeds
+            .iter()
+            .find(|f| f.url == feed.url)
+            .unwrap();
+        assert_eq!(copy.category, feed.category);
+        assert_eq!(copy.tags, feed.tags);
+    }
+
+    // Outlines without names do not break the path of their siblings
+    let mut app = App::default();
+    let opml_content = r#"<opml version="2.0"><body>
+    <outline text="Tech">
+      <outline text="Rust">
+        <outline>
+          <outline type="rss" text="Deep" xmlUrl="https://d.example/feed"/>
+        </outline>
+        <outline type="rss" text="Blog" xmlUrl="https://e.example/feed" category="/Ignored,Rust"/>
+      </outline>
+      <outline type="rss" text="Other" xmlUrl="https://f.example/feed"/>
+    </outline>
+    <outline type="rss" text="Flat" xmlUrl="https://g.example/feed" category="/News/World"/>
+  </body></opml>"#;
+    app.import_opml_content(opml_content).await.unwrap();
+    let categories: Vec<Option<&str>> = app
+        .rss_feeds
+        .iter()
+        .map(|f| f.category.as_deref())
+        .collect();
+    assert_eq!(
+        categories,
+        vec![
+            Some("Tech/Rust"),
+            Some("Tech/Rust"),
+            Some("Tech"),
+            Some("News/World")
+        ]
+    );
+    assert_eq!(app.rss_feeds[1].tags, vec!["rust".to_string()]);
+}
+
+#[test]
+fn test_sort_modes_and_group_headers() {
+    use reedy::app::{GroupBy, SortMode, ViewOrder};
+    use std::time::Duration;
+
+    let mut app = App::default();
+    for (url, title) in [
+        ("https://a.example/feed", "Alpha"),
+        ("https://b.example/feed", "Beta"),
+    ] {
+        app.rss_feeds.push(FeedInfo {
+            url: url.to_string(),
+            title: title.to_string(),
+            ..Default::default()
+        });
+    }
+    let hour = Duration::from_secs(3600);
+    app.current_feed_content = [("b", "zebra", 1), ("a", "apple", 30), ("b", "mango", 60)]
+        .iter()
+        .enumerate()
+        .map(|(i, (host, title, hours_ago))| FeedItem {
+            title: title.to_string(),
+            description: String::new(),
+            link: String::new(),
+            published: Some(SystemTime::now() - hour * *hours_ago),
+            id: format!("sort-test-{}", i),
+            feed_url: format!("https://{}.example/feed", host),
+            enclosure: None,
+        })
+        .collect();
+    app.update_search_filter();
+    assert_eq!(app.filtered_indices, None);
+    assert_eq!(app.view_key(), "all");
+
+    assert!(app.set_sort_by_name("oldest"));
+    assert_eq!(app.filtered_indices, Some(vec![2, 1, 0]));
+    assert!(app.set_sort_by_name("Title"));
+    assert_eq!(app.filtered_indices, Some(vec![1, 2, 0]));
+    assert!(!app.set_sort_by_name("random"));
+
+    // Grouping by feed keeps each feed together, sorted within the group
+    assert!(app.set_group_by_name("feed"));
+    assert_eq!(app.filtered_indices, Some(vec![1, 2, 0]));
+    assert_eq!(app.group_header(0).as_deref(), Some("Alpha"));
+    assert_eq!(app.group_header(1).as_deref(), Some("Beta"));
+    assert_eq!(app.group_header(2), None);
+
+    // Headers take a line each: Alpha, item, Beta, item, item
+    assert_eq!(app.items_fitting(0, 8), 2);
+    assert_eq!(app.items_fitting(0, 11), 3);
+    assert_eq!(app.item_at_line(0), None);
+    assert_eq!(app.item_at_line(3), Some(0));
+    assert_eq!(app.item_at_line(5), Some(1));
+    assert_eq!(app.item_at_line(9), Some(2));
+
+    // Day groups follow the newest first order
+    app.set_view_order(ViewOrder {
+        sort: SortMode::NewestFirst,
+        group: GroupBy::Day,
+    });
+    assert_eq!(app.filtered_indices, Some(vec![0, 1, 2]));
+    assert!(app.group_header(0).is_some());
+    assert!(app.view_orders.contains_key("all"));
+
+    // Other views keep their own order
+    app.page_mode = PageMode::Favorites;
+    assert_eq!(app.view_order(), ViewOrder::default());
+    app.page_mode = PageMode::FeedList;
+    app.set_view_order(ViewOrder::default());
+    assert!(app.view_orders.is_empty());
+    assert_eq!(app.filtered_indices, None);
+}
+
+#[test]
+fn test_edit_feed_title_and_url() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    // Article IDs are "<title slug>_<hash of the feed URL>_<timestamp>"
+    let item_id = |slug: &str, url: &str| {
+        let mut hasher = DefaultHasher::new();
+        url.hash(&mut hasher);
+        format!("{}_{:x}_1700000000000000000", slug, hasher.finish())
+    };
+    let old_url = "https://old.example/rss";
+    let new_url = "https://new.example/feed.xml";
+
+    let mut app = App::default();
+    app.page_mode = PageMode::FeedManager;
+    app.rss_feeds.push(FeedInfo {
+        url: old_url.to_string(),
+        title: "RSS".to_string(),
+        ..Default::default()
+    });
+    app.rss_feeds.push(FeedInfo {
+        url: "https://other.example/rss".to_string(),
+        title: "Other".to_string(),
+        ..Default::default()
+    });
+    app.current_feed_content = vec![FeedItem {
+        title: "Hello | RSS".to_string(),
+        description: String::new(),
+        link: String::new(),
+        published: None,
+        id: item_id("hello", old_url),
+        feed_url: old_url.to_string(),
+        enclosure: None,
+    }];
+    app.set_item_read(&item_id("hello", old_url), true);
+    app.favorites.insert(item_id("hello", old_url));
+    app.tagged_items.insert(
+        item_id("hello", old_url),
+        reedy::app::TaggedItem {
+            item: app.current_feed_content[0].clone(),
+            tags: ["later".to_string()].into_iter().collect(),
+        },
+    );
+    app.search_index
+        .add_items(app.current_feed_content.iter().map(|i| (i, "RSS")));
+    app.selected_index = Some(0);
+
+    // Renaming also updates the feed title shown with its articles
+    app.start_renaming_feed();
+    assert_eq!(app.input_mode, InputMode::RenamingFeed);
+    assert_eq!(app.input_buffer, "RSS");
+    app.input_buffer = "Old Site Blog".to_string();
+    app.rename_selected_feed();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(app.rss_feeds[0].title, "Old Site Blog");
+    assert_eq!(app.current_feed_content[0].title, "Hello | Old Site Blog");
+    assert_eq!(
+        app.tagged_items[&item_id("hello", old_url)].item.title,
+        "Hello | Old Site Blog"
+    );
+    let hits = app.search_index.search("\"old site blog\"", 10);
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].item.title, "Hello | Old Site Blog");
+    assert!(app.search_index.search("rss", 10).is_empty());
+
+    // Invalid and duplicate URLs are rejected and keep the input open
+    app.start_editing_feed_url();
+    assert_eq!(app.input_mode, InputMode::EditingFeedUrl);
+    assert_eq!(app.input_buffer, old_url);
+    app.input_buffer = "not a url".to_string();
+    app.set_selected_feed_url();
+    assert_eq!(app.input_mode, InputMode::EditingFeedUrl);
+    app.input_buffer = "https://other.example/rss".to_string();
+    app.set_selected_feed_url();
+    assert_eq!(app.input_mode, InputMode::EditingFeedUrl);
+    assert_eq!(app.rss_feeds[0].url, old_url);
+
+    // Moving the feed keeps its articles' read and favorite state
+    app.input_buffer = new_url.to_string();
+    app.set_selected_feed_url();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(app.rss_feeds[0].url, new_url);
+    let item = app.current_feed_content[0].clone();
+    assert_eq!(item.feed_url, new_url);
+    assert_eq!(item.id, item_id("hello", new_url));
+    assert!(app.is_item_read(&item));
+    assert!(app.favorites.contains(&item.id));
+    assert!(!app.favorites.contains(&item_id("hello", old_url)));
+}
+
+#[test]
+fn test_feed_manager_order_and_pins() {
+    use reedy::app::{FeedManagerRow, FeedSort};
+
+    let mut app = App::default();
+    app.page_mode = PageMode::FeedManager;
+    for (title, category) in [
+        ("Zeta", None),
+        ("Alpha", None),
+        ("Rust Blog", Some("Tech")),
+        ("Go Blog", Some("Tech")),
+        ("Comics", Some("Fun")),
+    ] {
+        app.rss_feeds.push(FeedInfo {
+            url: format!("https://{}.example/feed", title.replace(' ', "-")),
+            title: title.to_string(),
+            category: category.map(str::to_string),
+            ..Default::default()
+        });
+    }
+    let titles = |app: &App| -> Vec<String> {
+        app.feed_manager_rows()
+            .iter()
+            .map(|row| match row {
+                FeedManagerRow::Feed { index, .. } => app.rss_feeds[*index].title.clone(),
+                FeedManagerRow::Category { path, .. } => format!("[{}]", path),
+                FeedManagerRow::SavedSearch(_) => String::new(),
+            })
+            .collect()
+    };
+    assert_eq!(
+        titles(&app),
+        [
+            "Zeta",
+            "Alpha",
+            "[Fun]",
+            "Comics",
+            "[Tech]",
+            "Rust Blog",
+            "Go Blog"
+        ]
+    );
+
+    // Feeds move within their category and the selection follows them
+    app.selected_index = Some(6);
+    app.move_selected_feed(false);
+    assert_eq!(app.selected_index, Some(5));
+    app.move_selected_feed(false);
+    assert_eq!(app.selected_index, Some(5));
+    assert_eq!(
+        titles(&app),
+        [
+            "Zeta",
+            "Alpha",
+            "[Fun]",
+            "Comics",
+            "[Tech]",
+            "Go Blog",
+            "Rust Blog"
+        ]
+    );
+
+    // Categories move with everything in them
+    app.selected_index = Some(4);
+    app.move_selected_feed(false);
+    assert_eq!(app.selected_index, Some(2));
+    assert_eq!(
+        titles(&app),
+        [
+            "Zeta",
+            "Alpha",
+            "[Tech]",
+            "Go Blog",
+            "Rust Blog",
+            "[Fun]",
+            "Comics"
+        ]
+    );
+
+    // Pinned feeds come first; their category stays in place
+    app.selected_index = Some(6);
+    app.toggle_pin_selected_feed();
+    assert_eq!(app.selected_index, Some(0));
+    assert_eq!(
+        titles(&app),
+        [
+            "Comics",
+            "Zeta",
+            "Alpha",
+            "[Tech]",
+            "Go Blog",
+            "Rust Blog",
+            "[Fun]"
+        ]
+    );
+
+    // The order carries through to OPML and the grouped feed list
+    let opml = app.generate_opml().unwrap();
+    let position = |text: &str| opml.find(text).unwrap();
+    assert!(position("Zeta") < position("Alpha"));
+    assert!(position("\"Tech\"") < position("\"Fun\""));
+    assert!(position("Go Blog") < position("Rust Blog"));
+    let groups: Vec<Option<String>> = app
+        .get_feeds_by_category()
+        .into_iter()
+        .map(|(category, _)| category)
+        .collect();
+    assert_eq!(
+        groups,
+        [None, Some("Tech".to_string()), Some("Fun".to_string())]
+    );
+
+    // Sorting by name orders each category, and moving is refused until manual again
+    app.command_buffer = "sort name".to_string();
+    assert!(app.execute_command().unwrap());
+    assert_eq!(app.feed_sort, FeedSort::Name);
+    assert_eq!(
+        titles(&app),
+        [
+            "Comics",
+            "Alpha",
+            "Zeta",
+            "[Tech]",
+            "Go Blog",
+            "Rust Blog",
+            "[Fun]"
+        ]
+    );
+    app.selected_index = Some(2);
+    app.move_selected_feed(false);
+    assert!(app.error_message.is_some());
+    app.cycle_feed_sort();
+    assert_eq!(app.feed_sort, FeedSort::Manual);
+}
+
+#[test]
+fn test_paused_feeds_are_not_counted() {
+    use reedy::app::CategoryScope;
+
+    let mut app = App::default();
+    app.page_mode = PageMode::FeedManager;
+    app.config
+        .saved_searches
+        .push(reedy::app::SavedSearch::new("Everything", "", false));
+    for host in ["a", "b"] {
+        app.rss_feeds.push(FeedInfo {
+            url: format!("https://{}.example/feed", host),
+            title: host.to_string(),
+            category: Some("Tech".to_string()),
+            ..Default::default()
+        });
+    }
+    app.current_feed_content = ["a", "b", "b"]
+        .iter()
+        .enumerate()
+        .map(|(i, host)| FeedItem {
+            title: format!("Item {}", i),
+            description: String::new(),
+            link: String::new(),
+            published: None,
+            id: format!("pause-test-{}", i),
+            feed_url: format!("https://{}.example/feed", host),
+            enclosure: None,
+        })
+        .collect();
+    cache_loaded_items(&mut app);
+    assert_eq!(app.count_for_scope(&CategoryScope::All), (3, 3));
+    // Cached articles count whether or not they are loaded
+    app.current_feed_content.clear();
+    assert_eq!(app.count_for_scope(&CategoryScope::All), (3, 3));
+
+    // Row 0 is the category, row 2 is feed "b"
+    app.selected_index = Some(2);
+    app.toggle_pause_selected_feed();
+    assert!(app.is_feed_paused("https://b.example/feed"));
+    assert!(app.status_message.as_deref().unwrap().contains("z resumes"));
+    assert_eq!(app.count_for_scope(&CategoryScope::All), (1, 3));
+    let search = app.config.saved_searches[0].clone();
+    assert_eq!(app.saved_search_count(&search), (1, 1));
+    // The feed list is reloaded, while the Feed Manager keeps its selection
+    assert_eq!(app.current_feed_content.len(), 3);
+    assert_eq!(app.selected_index, Some(2));
+
+    app.toggle_pause_selected_feed();
+    assert!(!app.is_feed_paused("https://b.example/feed"));
+    assert_eq!(app.count_for_scope(&CategoryScope::All), (3, 3));
+    assert_eq!(app.saved_search_count(&search), (3, 3));
+}
+
+#[test]
+fn test_feed_manager_bulk_actions() {
+    use std::collections::HashSet;
+
+    let mut app = App::default();
+    app.page_mode = PageMode::FeedManager;
+    for (title, category) in [
+        ("One", None),
+        ("Two", None),
+        ("Three", Some("Imported")),
+        ("Four", Some("Imported")),
+    ] {
+        app.rss_feeds.push(FeedInfo {
+            url: format!("https://{}.example/feed", title.to_lowercase()),
+            title: title.to_string(),
+            category: category.map(str::to_string),
+            ..Default::default()
+        });
+    }
+    let marked = |app: &App| -> HashSet<String> {
+        app.rss_feeds
+            .iter()
+            .filter(|f| app.marked_feeds.contains(&f.url))
+            .map(|f| f.title.clone())
+            .collect()
+    };
+
+    // Rows: One, Two, [Imported], Three, Four. Marking a category marks its feeds.
+    app.selected_index = Some(2);
+    app.toggle_mark_selected_feed();
+    assert_eq!(app.selected_index, Some(3));
+    assert_eq!(marked(&app), HashSet::from(["Three".into(), "Four".into()]));
+    app.selected_index = Some(2);
+    app.toggle_mark_selected_feed();
+    assert!(!app.has_marked_feeds());
+
+    // A visual range covers the rows between its start and the selection
+    app.selected_index = Some(0);
+    app.toggle_feed_visual();
+    app.selected_index = Some(1);
+    assert_eq!(app.visual_feed_indices(), HashSet::from([0, 1]));
+    app.toggle_feed_visual();
+    assert_eq!(marked(&app), HashSet::from(["One".into(), "Two".into()]));
+
+    // Bulk actions apply to the marked feeds only
+    app.toggle_pause_selected_feed();
+    assert!(app.rss_feeds[0].paused && app.rss_feeds[1].paused);
+    assert!(!app.rss_feeds[2].paused);
+    app.start_setting_category();
+    assert_eq!(app.input_mode, InputMode::SettingCategory);
+    assert_eq!(app.input_buffer, "");
+    app.input_buffer = "Imported".to_string();
+    app.set_category();
+    assert!(app
+        .rss_feeds
+        .iter()
+        .all(|f| f.category.as_deref() == Some("Imported")));
+
+    let opml = app.generate_opml_of(Some(&app.marked_feeds)).unwrap();
+    assert!(opml.contains("One") && opml.contains("Two"));
+    assert!(!opml.contains("Three"));
+
+    app.start_deleting();
+    assert_eq!(app.input_mode, InputMode::Deleting);
+    app.delete_marked_feeds();
+    let titles: Vec<&str> = app.rss_feeds.iter().map(|f| f.title.as_str()).collect();
+    assert_eq!(titles, ["Three", "Four"]);
+    assert!(!app.has_marked_feeds());
+}
+
+#[test]
+fn test_visual_selection_of_articles() {
+    let mut app = App::default();
+    app.current_feed_content = (0..5)
+        .map(|i| FeedItem {
+            title: format!("Release {}", i),
+            description: String::new(),
+            link: String::new(),
+            published: None,
+            id: format!("visual-test-{}", i),
+            feed_url: "https://releases.example/feed".to_string(),
+            enclosure: None,
+        })
+        .collect();
+    app.selected_index = Some(1);
+
+    // A visual range from item 1 down to item 3
+    app.toggle_item_visual();
+    app.select_next();
+    app.select_next();
+    assert_eq!(app.visual_item_ids().len(), 3);
+    app.toggle_read_marked_items();
+    let read: Vec<bool> = app
+        .current_feed_content
+        .iter()
+        .map(|item| app.is_item_read(item))
+        .collect();
+    assert_eq!(read, [false, true, true, true, false]);
+    assert_eq!(app.item_visual_start, None);
+    assert_eq!(app.marked_items.len(), 3);
+
+    // Toggling adds single items; a second toggle on fully read marks makes them unread
+    app.selected_index = Some(4);
+    app.toggle_mark_selected_item();
+    app.toggle_favorite_marked_items();
+    assert_eq!(app.favorites.len(), 4);
+    app.mark_marked_items_read();
+    app.toggle_read_marked_items();
+    assert!(app
+        .current_feed_content
+        .iter()
+        .all(|item| !app.is_item_read(item)));
+
+    // Tagging from the list applies to every marked article
+    app.start_tagging();
+    app.input_buffer = "release".to_string();
+    app.confirm_tagging();
+    assert_eq!(app.items_with_tag("release").len(), 4);
+
+    app.clear_item_marks();
+    assert!(!app.has_marked_items());
+
+    // The range stays anchored to its first article when the list above it changes
+    app.selected_index = Some(2);
+    app.toggle_item_visual();
+    app.current_feed_content.remove(0);
+    app.update_search_filter();
+    app.selected_index = Some(2);
+    let mut ids: Vec<String> = app.visual_item_ids().into_iter().collect();
+    ids.sort();
+    assert_eq!(ids, ["visual-test-2", "visual-test-3"]);
+
+    // Marks on articles that are no longer listed are dropped
+    app.toggle_item_visual();
+    app.current_feed_content.truncate(1);
+    app.update_search_filter();
+    assert!(app.marked_items.is_empty());
+    assert!(!app.has_marked_items());
+}
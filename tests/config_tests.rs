@@ -0,0 +1,113 @@
+use reedy::app::{Config, MuteRule};
+use reedy::config::{
+    check_config, config_toml, default_toml, parse_config, serialize_config, ConfigFormat,
+};
+use std::path::Path;
+
+#[test]
+fn test_config_format_from_extension() {
+    assert_eq!(
+        ConfigFormat::of(Path::new("reedy/config.toml")),
+        ConfigFormat::Toml
+    );
+    assert_eq!(
+        ConfigFormat::of(Path::new("reedy/config.json")),
+        ConfigFormat::Json
+    );
+}
+
+#[test]
+fn test_default_toml_is_the_default_config() {
+    let toml = default_toml();
+    assert!(toml.contains("# HTTP request timeout in seconds"));
+    assert!(toml.contains("# Keys for quit\nquit = \"q\""));
+    assert!(toml.contains("# mute_rules = ["));
+
+    let config = parse_config(&toml, ConfigFormat::Toml).unwrap();
+    assert_eq!(
+        serde_json::to_value(&config).unwrap(),
+        serde_json::to_value(Config::default()).unwrap()
+    );
+    assert!(check_config(&toml, ConfigFormat::Toml).is_empty());
+}
+
+#[test]
+fn test_config_toml_keeps_settings() {
+    // `config init` seeds config.toml from an existing config.json this way
+    let json = r#"{"auto_refresh_mins": 5, "keybindings": {"quit": "x"}}"#;
+    let config = parse_config(json, ConfigFormat::Json).unwrap();
+    let toml = config_toml(&config);
+    assert!(toml.contains("# HTTP request timeout in seconds"));
+    assert!(toml.contains("# Keys for quit\nquit = \"x\""));
+
+    let parsed = parse_config(&toml, ConfigFormat::Toml).unwrap();
+    assert_eq!(
+        serde_json::to_value(&parsed).unwrap(),
+        serde_json::to_value(&config).unwrap()
+    );
+}
+
+#[test]
+fn test_check_config_reports_lines() {
+    let toml = "auto_refresh_mins = 5\n\
+                http_timeout_secs = 0\n\
+                \n\
+                [theme]\n\
+                primary = \"greeen\"\n\
+                \n\
+                [keybindings]\n\
+                quit = \"Ctrl+Q\"\n\
+                \n\
+                [api]\n\
+                port = 0\n";
+    let problems = check_config(toml, ConfigFormat::Toml);
+    let lines: Vec<Option<usize>> = problems.iter().map(|p| p.line).collect();
+    assert_eq!(problems.len(), 4);
+    assert!(lines.contains(&Some(2)));
+    assert!(lines.contains(&Some(5)));
+    assert!(lines.contains(&Some(8)));
+    assert!(lines.contains(&Some(11)));
+    assert!(problems
+        .iter()
+        .any(|p| p.to_string() == "line 5: theme.primary: unknown color \"greeen\""));
+
+    // Parse errors point at the offending value
+    let problems = check_config(
+        "notifications_enabled = true\ncache_duration_mins = \"x\"\n",
+        ConfigFormat::Toml,
+    );
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].line, Some(2));
+
+    let json = "{\n  \"theme\": {\n    \"text\": \"plaid\"\n  }\n}";
+    let problems = check_config(json, ConfigFormat::Json);
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].line, Some(3));
+}
+
+#[test]
+fn test_serialize_config_keeps_toml_comments() {
+    let existing = "# my settings\n\
+                    auto_refresh_mins = 5 # every five minutes\n\
+                    \n\
+                    [theme]\n\
+                    # a calmer look\n\
+                    primary = \"red\"\n";
+    let mut config = parse_config(existing, ConfigFormat::Toml).unwrap();
+    config.theme.primary = "blue".to_string();
+    config
+        .mute_rules
+        .push(MuteRule::new("title:sponsored", Default::default()));
+
+    let updated = serialize_config(&config, ConfigFormat::Toml, Some(existing)).unwrap();
+    assert!(updated.starts_with("# my settings\nauto_refresh_mins = 5 # every five minutes\n"));
+    assert!(updated.contains("# a calmer look\nprimary = \"blue\"\n"));
+    // Settings left at their defaults are not written out
+    assert!(!updated.contains("keybindings"));
+    assert!(!updated.contains("secondary"));
+
+    let reparsed = parse_config(&updated, ConfigFormat::Toml).unwrap();
+    assert_eq!(reparsed.theme.primary, "blue");
+    assert_eq!(reparsed.mute_rules.len(), 1);
+    assert_eq!(reparsed.auto_refresh_mins, 5);
+}
@@ -0,0 +1,75 @@
+use reedy::app::{App, Config, FeedItem};
+use reedy::paths;
+use std::fs::{self, File};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Writes the config file with a modification time of its own, so every write is noticed
+fn write_config(path: &Path, contents: &str, age_secs: u64) {
+    fs::write(path, contents).unwrap();
+    File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(SystemTime::now() - Duration::from_secs(age_secs))
+        .unwrap();
+}
+
+// The directories are process-wide, so everything that changes them is in one test
+#[test]
+fn test_config_file_changes_are_reloaded() {
+    let base = std::env::temp_dir().join(format!("reedy-config-watch-{}", std::process::id()));
+    let config_dir = base.join("config");
+    fs::create_dir_all(&config_dir).unwrap();
+    paths::set_config_dir(Some(config_dir.clone()));
+    let path = App::get_config_path();
+    write_config(
+        &path,
+        &serde_json::to_string_pretty(&Config::default()).unwrap(),
+        30,
+    );
+
+    let mut app = App::default();
+    app.watch_config = true;
+    app.current_feed_content = (0..3)
+        .map(|i| FeedItem {
+            title: format!("Article {}", i),
+            description: String::new(),
+            link: String::new(),
+            published: None,
+            id: format!("article-{}", i),
+            feed_url: "https://watch.example/rss".to_string(),
+            enclosure: None,
+        })
+        .collect();
+    app.selected_index = Some(2);
+    app.check_config_file();
+    assert!(app.status_message.is_none());
+
+    // An edited file replaces the config and keeps the selected article
+    let edited = Config {
+        auto_refresh_mins: 15,
+        ..Config::default()
+    };
+    write_config(&path, &serde_json::to_string_pretty(&edited).unwrap(), 20);
+    app.check_config_file();
+    assert_eq!(app.config.auto_refresh_mins, 15);
+    assert_eq!(app.status_message.as_deref(), Some("Config reloaded"));
+    assert_eq!(app.selected_index, Some(2));
+
+    // An invalid file is reported and the previous config stays in use
+    write_config(&path, "{ \"auto_refresh_mins\": ", 10);
+    app.check_config_file();
+    assert_eq!(app.config.auto_refresh_mins, 15);
+    assert!(app
+        .error_message
+        .as_deref()
+        .is_some_and(|e| e.starts_with("Config not reloaded")));
+
+    // Nothing happens until the file changes again
+    app.error_message = None;
+    app.check_config_file();
+    assert!(app.error_message.is_none());
+
+    fs::remove_dir_all(&base).unwrap();
+}
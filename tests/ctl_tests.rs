@@ -0,0 +1,128 @@
+use reedy::app::{App, FeedInfo, FeedItem};
+use reedy::ctl::{dispatch, format_reply};
+
+fn item(title: &str, id: &str) -> FeedItem {
+    FeedItem {
+        title: title.to_string(),
+        description: String::new(),
+        // Empty links keep open_selected_feed from launching a browser
+        link: String::new(),
+        published: None,
+        id: id.to_string(),
+        feed_url: String::new(),
+        enclosure: None,
+    }
+}
+
+#[tokio::test]
+async fn test_dispatch_search_filters_items() {
+    let mut app = App::default();
+    app.current_feed_content = vec![item("Rust 2.0 | Blog", "a"), item("Go news | Blog", "b")];
+
+    let reply = dispatch(&mut app, "search \"rust\"", None).await.unwrap();
+    assert_eq!(reply, "1 matching items");
+    assert_eq!(app.search_query, "rust");
+    assert_eq!(app.visible_item_count(), 1);
+}
+
+#[tokio::test]
+async fn test_dispatch_open_next_unread() {
+    let mut app = App::default();
+    app.current_feed_content = vec![
+        item("First | Blog", "ctl-1"),
+        item("Second | Blog", "ctl-2"),
+    ];
+    app.set_item_read("ctl-1", true);
+
+    let reply = dispatch(&mut app, "open-next-unread", None).await.unwrap();
+    assert_eq!(reply, "Opened Second | Blog");
+    assert_eq!(app.selected_index, Some(1));
+    assert!(app.is_item_read(&app.current_feed_content[1]));
+
+    let err = dispatch(&mut app, "open-next-unread", None)
+        .await
+        .unwrap_err();
+    assert_eq!(err, "No unread items");
+}
+
+#[tokio::test]
+async fn test_dispatch_falls_back_to_commands() {
+    let mut app = App::default();
+
+    assert!(dispatch(&mut app, "add", None).await.is_err());
+    assert!(dispatch(&mut app, "frobnicate", None).await.is_err());
+
+    dispatch(&mut app, "q", None).await.unwrap();
+    assert!(!app.running);
+}
+
+#[tokio::test]
+async fn test_dispatch_add_and_refresh_skip_the_network() {
+    use reedy::ctl::fetch_feed_title;
+
+    // Commands other than `add` need no title, and a missing URL is left to dispatch
+    assert_eq!(fetch_feed_title("refresh", 1).await, Ok(None));
+    assert_eq!(fetch_feed_title("add", 1).await, Ok(None));
+
+    // The listener fetched the title, so dispatch only subscribes (a duplicate here, which
+    // keeps the test from saving the feeds)
+    let mut app = App::default();
+    app.rss_feeds.push(FeedInfo {
+        url: "https://ctl-test.invalid/feed.xml".to_string(),
+        title: "Ctl Feed".to_string(),
+        ..Default::default()
+    });
+    let err = dispatch(
+        &mut app,
+        "add https://ctl-test.invalid/feed.xml \"Tech News\"",
+        Some("Ctl Feed"),
+    )
+    .await
+    .unwrap_err();
+    assert!(err.contains("Already subscribed"));
+    let err = dispatch(&mut app, "add https://ctl-test.invalid/other.xml", None)
+        .await
+        .unwrap_err();
+    assert_eq!(err, "Invalid RSS feed URL");
+
+    let reply = dispatch(&mut app, "refresh", None).await.unwrap();
+    assert_eq!(reply, "Refreshing 1 feeds");
+    assert!(app.refresh_requested);
+}
+
+#[test]
+fn test_format_reply() {
+    assert_eq!(format_reply(Ok("done".to_string())), "OK done");
+    assert_eq!(format_reply(Err("bad\nthing".to_string())), "ERR bad thing");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_socket_round_trip() {
+    use reedy::ctl::{send_command, start_listener};
+    use reedy::event::Event;
+    use tokio::sync::mpsc;
+
+    let path = std::env::temp_dir().join(format!("reedy-ctl-test-{}.sock", std::process::id()));
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    start_listener(&path, 1, sender).await.unwrap();
+
+    // A second listener on the same live socket is refused
+    let (other, _) = mpsc::unbounded_channel();
+    assert!(start_listener(&path, 1, other).await.is_err());
+
+    let client_path = path.clone();
+    let client =
+        tokio::task::spawn_blocking(move || send_command(&client_path, "refresh").unwrap());
+
+    match receiver.recv().await {
+        Some(Event::Control(call)) => {
+            assert_eq!(call.command, "refresh");
+            call.reply.send("OK Refreshed".to_string()).unwrap();
+        }
+        other => panic!("unexpected event: {:?}", other),
+    }
+
+    assert_eq!(client.await.unwrap(), "OK Refreshed");
+    let _ = std::fs::remove_file(&path);
+}
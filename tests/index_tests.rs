@@ -0,0 +1,191 @@
+use reedy::app::{App, FeedInfo, FeedItem};
+use reedy::index::{IndexQuery, SearchIndex};
+use std::time::{Duration, SystemTime};
+
+fn item(id: &str, title: &str, description: &str, days_old: u64) -> FeedItem {
+    FeedItem {
+        title: title.to_string(),
+        description: description.to_string(),
+        link: format!("https://blog.example/{}", id),
+        published: Some(SystemTime::now() - Duration::from_secs(days_old * 24 * 3600)),
+        id: id.to_string(),
+        feed_url: "https://blog.example/feed".to_string(),
+        enclosure: None,
+    }
+}
+
+fn sample_items() -> Vec<FeedItem> {
+    vec![
+        item("a", "Rust 2.0 released", "The async story improves", 1),
+        item("b", "Weekly links", "Links about rust and go", 2),
+        item(
+            "c",
+            "Async Python",
+            "Rust is not mentioned here, python async is",
+            300,
+        ),
+        item("d", "Gardening", "Roses in the rain", 3),
+    ]
+}
+
+fn sample_index() -> SearchIndex {
+    let items = sample_items();
+    let mut index = SearchIndex::default();
+    assert_eq!(
+        index.add_items(items.iter().map(|i| (i, "Example Blog"))),
+        4
+    );
+    // Articles already indexed are skipped
+    assert_eq!(
+        index.add_items(items.iter().map(|i| (i, "Example Blog"))),
+        0
+    );
+    index
+}
+
+fn ids(index: &SearchIndex, query: &str) -> Vec<String> {
+    index
+        .search(query, 10)
+        .into_iter()
+        .map(|hit| hit.item.id.clone())
+        .collect()
+}
+
+#[test]
+fn test_index_query_parsing() {
+    assert!(IndexQuery::parse("").is_none());
+    assert!(IndexQuery::parse("  -only -excluded ").is_none());
+    assert!(IndexQuery::parse("rust \"async story\" pyth* -go").is_some());
+}
+
+#[test]
+fn test_index_ranks_terms_phrases_and_prefixes() {
+    let index = sample_index();
+
+    // Title matches rank above description matches
+    assert_eq!(ids(&index, "rust"), vec!["a", "b", "c"]);
+    assert_eq!(ids(&index, "rust -go"), vec!["a", "c"]);
+    // Both words must match; "async" twice and in the title outweighs "rust" in the title
+    assert_eq!(ids(&index, "rust async"), vec!["c", "a"]);
+
+    // Phrases need the words next to each other, in order
+    assert_eq!(ids(&index, "\"async story\""), vec!["a"]);
+    assert!(ids(&index, "\"story async\"").is_empty());
+    // Phrases do not run from one field into the next
+    assert!(ids(&index, "\"released the\"").is_empty());
+
+    // Prefixes match any term starting with them
+    assert_eq!(ids(&index, "gard*"), vec!["d"]);
+    assert!(ids(&index, "gard").is_empty());
+
+    // The link and the feed title are indexed too
+    assert_eq!(ids(&index, "blog example d").len(), 1);
+    assert_eq!(ids(&index, "\"example blog\"").len(), 4);
+}
+
+#[test]
+fn test_index_saves_and_loads() {
+    let dir = std::env::temp_dir().join(format!("reedy-index-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let mut index = SearchIndex::load(dir.clone());
+    assert!(index.is_empty());
+    let items = sample_items();
+    index.add_items(items.iter().map(|i| (i, "Example Blog")));
+    index.save().unwrap();
+
+    let loaded = SearchIndex::load(dir.clone());
+    assert_eq!(loaded.len(), 4);
+    assert_eq!(ids(&loaded, "rust -go"), vec!["a", "c"]);
+    assert_eq!(ids(&loaded, "\"async story\""), vec!["a"]);
+
+    // A damaged term table is rebuilt from the stored articles
+    std::fs::write(dir.join("postings.bin"), b"garbage").unwrap();
+    let rebuilt = SearchIndex::load(dir.clone());
+    assert_eq!(ids(&rebuilt, "rust -go"), vec!["a", "c"]);
+
+    // Counts and deltas that do not fit the file are treated as damage, not trusted
+    let huge = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+    let mut postings = b"RDIX1\x04\x01\x04rust".to_vec();
+    postings.extend_from_slice(&huge);
+    std::fs::write(dir.join("postings.bin"), &postings).unwrap();
+    let rebuilt = SearchIndex::load(dir.clone());
+    assert_eq!(ids(&rebuilt, "rust -go"), vec!["a", "c"]);
+
+    let mut postings = b"RDIX1\x04\x01\x04rust\x01\x00\x02".to_vec();
+    postings.extend_from_slice(&[0xff, 0xff, 0xff, 0xff, 0x0f, 0xff, 0xff, 0xff, 0xff, 0x0f]);
+    std::fs::write(dir.join("postings.bin"), &postings).unwrap();
+    let rebuilt = SearchIndex::load(dir.clone());
+    assert_eq!(ids(&rebuilt, "rust -go"), vec!["a", "c"]);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_index_retitles_feed() {
+    let dir = std::env::temp_dir().join(format!("reedy-retitle-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let mut index = SearchIndex::load(dir.clone());
+    let mut items = sample_items();
+    for item in &mut items {
+        item.title = format!("{} | Example Blog", item.title);
+    }
+    let mut other = item("e", "Rust elsewhere | Other", "Not renamed", 1);
+    other.feed_url = "https://other.example/feed".to_string();
+    index.add_items(items.iter().map(|i| (i, "Example Blog")));
+    index.add_items(std::iter::once((&other, "Other")));
+
+    let renamed = index.retitle_feed("https://blog.example/feed", "Garden Notes", |item| {
+        if let Some(title) = item.title.strip_suffix(" | Example Blog") {
+            item.title = format!("{} | Garden Notes", title);
+        }
+    });
+    assert_eq!(renamed, 4);
+    assert!(ids(&index, "\"example blog\"").is_empty());
+    assert_eq!(ids(&index, "\"garden notes\"").len(), 4);
+    let mut rust = ids(&index, "rust -go");
+    rust.sort();
+    assert_eq!(rust, vec!["a", "c", "e"]);
+    let hits = index.search("roses", 10);
+    assert_eq!(hits[0].item.title, "Gardening | Garden Notes");
+
+    // The rewritten postings survive a save and load
+    index.save().unwrap();
+    let loaded = SearchIndex::load(dir.clone());
+    assert!(ids(&loaded, "\"example blog\"").is_empty());
+    assert_eq!(ids(&loaded, "\"garden notes\"").len(), 4);
+    let mut rust = ids(&loaded, "rust -go");
+    rust.sort();
+    assert_eq!(rust, vec!["a", "c", "e"]);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_find_everywhere_shows_archived_articles() {
+    let mut app = App::default();
+    app.rss_feeds.push(FeedInfo {
+        url: "https://blog.example/feed".to_string(),
+        title: "Example Blog".to_string(),
+        ..Default::default()
+    });
+    app.index_items(&sample_items());
+    // Only one article is still loaded; the rest come from the index
+    app.current_feed_content = vec![sample_items().remove(3)];
+
+    assert!(app.find_everywhere("rust"));
+    assert!(app.search_everything);
+    assert!(app.has_active_filter());
+    let found: Vec<&str> = app
+        .current_feed_content
+        .iter()
+        .map(|item| item.id.as_str())
+        .collect();
+    assert_eq!(found, vec!["a", "b", "c"]);
+    assert_eq!(app.visible_item_count(), 3);
+    assert_eq!(app.view_key(), "everything");
+
+    app.clear_search();
+    assert!(!app.search_everything);
+}
@@ -0,0 +1,104 @@
+use reedy::app::{App, CategoryScope, FeedInfo, FeedItem, InputMode};
+use reedy::palette::{fuzzy_match, PALETTE_COMMANDS};
+
+#[test]
+fn test_fuzzy_match_scores_and_positions() {
+    assert_eq!(fuzzy_match("", "Anything"), Some((0, Vec::new())));
+    assert!(fuzzy_match("xyz", "Hacker News").is_none());
+    assert!(fuzzy_match("nh", "Hacker News").is_none());
+
+    let (_, positions) = fuzzy_match("hn", "Hacker News").unwrap();
+    assert_eq!(positions, vec![0, 7]);
+
+    // Word starts and consecutive letters beat scattered letters
+    let (word_starts, _) = fuzzy_match("rb", "Rust Blog").unwrap();
+    let (scattered, _) = fuzzy_match("rb", "Three Bears").unwrap();
+    assert!(word_starts > scattered);
+    let (consecutive, _) = fuzzy_match("rust", "Rust Blog").unwrap();
+    let (spread, _) = fuzzy_match("rust", "Reading Update Stats Today").unwrap();
+    assert!(consecutive > spread);
+
+    // The best place to start is found even if the first letter occurs earlier
+    let (_, positions) = fuzzy_match("news", "a new newsletter").unwrap();
+    assert_eq!(positions, vec![6, 7, 8, 9]);
+}
+
+#[test]
+fn test_palette_commands_are_listed_once() {
+    let mut names: Vec<&str> = PALETTE_COMMANDS.iter().map(|(c, _)| c.trim()).collect();
+    let count = names.len();
+    names.sort_unstable();
+    names.dedup();
+    assert_eq!(names.len(), count);
+}
+
+#[tokio::test]
+async fn test_palette_ranks_and_jumps() {
+    let mut app = App::default();
+    for (url, title, category) in [
+        ("https://hn.example/rss", "Hacker News", Some("Tech")),
+        ("https://rust.example/feed", "Rust Blog", Some("Tech/Rust")),
+    ] {
+        app.rss_feeds.push(FeedInfo {
+            url: url.to_string(),
+            title: title.to_string(),
+            category: category.map(str::to_string),
+            ..Default::default()
+        });
+    }
+    app.current_feed_content = (0..3)
+        .map(|i| FeedItem {
+            title: format!("Rust release {}", i),
+            description: String::new(),
+            link: String::new(),
+            published: None,
+            id: format!("palette-{}", i),
+            feed_url: "https://rust.example/feed".to_string(),
+            enclosure: None,
+        })
+        .collect();
+
+    app.open_palette();
+    assert_eq!(app.input_mode, InputMode::Palette);
+    for c in "rustb".chars() {
+        app.palette_input(Some(c));
+    }
+    let matches = app.palette_matches();
+    assert_eq!(matches[0].0.label, "Rust Blog");
+    assert_eq!(matches[0].1, vec![0, 1, 2, 3, 5]);
+
+    // Categories jump to a category view
+    for _ in 0..5 {
+        app.palette_input(None);
+    }
+    for c in "/rust".chars() {
+        app.palette_input(Some(c));
+    }
+    assert_eq!(app.palette_matches()[0].0.label, "Tech/Rust");
+    app.confirm_palette().await.unwrap();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(
+        app.category_scope,
+        CategoryScope::Category("Tech/Rust".to_string())
+    );
+
+    // Articles open in the preview
+    app.open_palette();
+    for c in "release 2".chars() {
+        app.palette_input(Some(c));
+    }
+    app.confirm_palette().await.unwrap();
+    assert_eq!(app.input_mode, InputMode::Preview);
+    let index = app.get_actual_index(app.selected_index.unwrap()).unwrap();
+    assert_eq!(app.current_feed_content[index].id, "palette-2");
+
+    // Commands that take an argument wait for it in command mode
+    app.close_preview();
+    app.open_palette();
+    for c in ":find".chars() {
+        app.palette_input(Some(c));
+    }
+    app.confirm_palette().await.unwrap();
+    assert_eq!(app.input_mode, InputMode::Command);
+    assert_eq!(app.command_buffer, "find ");
+}
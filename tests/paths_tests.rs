@@ -0,0 +1,53 @@
+use reedy::app::App;
+use reedy::paths;
+
+// The directories are process-wide, so everything that changes them is in one test
+#[test]
+fn test_profiles_namespace_files() {
+    let base = std::env::temp_dir().join(format!("reedy-paths-{}", std::process::id()));
+    let config_dir = base.join("config");
+    let data_dir = base.join("data");
+    paths::set_config_dir(Some(config_dir.clone()));
+    paths::set_data_dir(Some(data_dir.clone()));
+
+    assert_eq!(paths::profile(), "default");
+    assert_eq!(App::get_save_path(), config_dir.join("feeds.json"));
+    assert_eq!(App::get_config_path(), config_dir.join("config.json"));
+    assert_eq!(App::get_opml_path(), config_dir.join("feeds.opml"));
+    assert!(App::get_log_path().starts_with(&data_dir));
+    assert!(App::get_socket_path().ends_with("reedy.sock"));
+
+    paths::set_profile(Some("work".to_string()));
+    let work = config_dir.join("profiles").join("work");
+    assert_eq!(paths::profile(), "work");
+    assert_eq!(App::get_save_path(), work.join("feeds.json"));
+    assert_eq!(App::get_opml_path(), work.join("feeds.opml"));
+    assert_eq!(
+        paths::cache_dir(),
+        data_dir.join("cache").join("profiles").join("work")
+    );
+    assert_eq!(paths::data_dir(), data_dir.join("profiles").join("work"));
+    assert!(App::get_socket_path().ends_with("reedy-work.sock"));
+    assert_eq!(paths::profiles(), vec!["default", "work"]);
+
+    // "default" goes back to the top-level directories
+    paths::set_profile(Some("default".to_string()));
+    assert_eq!(App::get_save_path(), config_dir.join("feeds.json"));
+
+    let mut app = App::default();
+    app.command_buffer = "profile w".to_string();
+    assert_eq!(app.command_suggestions(), vec!["work"]);
+    app.command_buffer = "profile work".to_string();
+    assert!(app.execute_command().unwrap());
+    assert_eq!(app.pending_profile.as_deref(), Some("work"));
+    app.pending_profile = None;
+    app.command_buffer = "profile ../etc".to_string();
+    assert!(!app.execute_command().unwrap());
+    assert!(app.pending_profile.is_none());
+
+    assert!(paths::valid_profile_name("side-project_2"));
+    assert!(!paths::valid_profile_name(""));
+    assert!(!paths::valid_profile_name("a b"));
+
+    std::fs::remove_dir_all(&base).unwrap();
+}
@@ -0,0 +1,237 @@
+use reedy::app::{App, FeedInfo, FeedItem};
+use reedy::query::Query;
+use std::time::{Duration, SystemTime};
+
+fn item(title: &str, description: &str, feed_url: &str, days_old: u64) -> FeedItem {
+    let published = SystemTime::now() - Duration::from_secs(days_old * 24 * 3600);
+    FeedItem {
+        title: title.to_string(),
+        description: description.to_string(),
+        link: String::new(),
+        published: Some(published),
+        id: title.to_string(),
+        feed_url: feed_url.to_string(),
+        enclosure: None,
+    }
+}
+
+fn sample_app() -> App {
+    let mut app = App::default();
+    app.rss_feeds = vec![
+        FeedInfo {
+            url: "https://hn.example/rss".to_string(),
+            title: "Hacker News".to_string(),
+            category: Some("Tech".to_string()),
+            ..Default::default()
+        },
+        FeedInfo {
+            url: "https://rust.example/feed".to_string(),
+            title: "Rust Blog".to_string(),
+            category: Some("Rust".to_string()),
+            ..Default::default()
+        },
+    ];
+    app.current_feed_content = vec![
+        item(
+            "Show HN: a tool | Hacker News",
+            "Sponsored post",
+            "https://hn.example/rss",
+            1,
+        ),
+        item(
+            "Rust 2.0 released | Rust Blog",
+            "Big news",
+            "https://rust.example/feed",
+            3,
+        ),
+        item(
+            "Async in depth | Rust Blog",
+            "Futures explained",
+            "https://rust.example/feed",
+            30,
+        ),
+    ];
+    app
+}
+
+/// Returns the titles (without feed suffix) of items matching the query
+fn search(app: &App, query: &str) -> Vec<String> {
+    let query = Query::parse(query).unwrap().unwrap();
+    app.current_feed_content
+        .iter()
+        .filter(|item| query.matches(item, app))
+        .map(|item| item.title.split(" | ").next().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_parse_empty_query() {
+    assert!(Query::parse("").unwrap().is_none());
+    assert!(Query::parse("   ").unwrap().is_none());
+}
+
+#[test]
+fn test_query_filters() {
+    let app = sample_app();
+
+    assert_eq!(
+        search(&app, "rust"),
+        vec!["Rust 2.0 released", "Async in depth"]
+    );
+    assert_eq!(
+        search(&app, "feed:\"hacker news\""),
+        vec!["Show HN: a tool"]
+    );
+    assert_eq!(
+        search(&app, "cat:rust"),
+        vec!["Rust 2.0 released", "Async in depth"]
+    );
+    assert_eq!(
+        search(&app, "title:/^(rust|async) /"),
+        vec!["Rust 2.0 released", "Async in depth"]
+    );
+    assert_eq!(search(&app, "title:news"), vec!["Show HN: a tool"]);
+    assert_eq!(
+        search(&app, "after:7d"),
+        vec!["Show HN: a tool", "Rust 2.0 released"]
+    );
+    assert_eq!(search(&app, "before:7d"), vec!["Async in depth"]);
+    assert_eq!(search(&app, "after:1970-01-02").len(), 3);
+    assert_eq!(search(&app, "\"big news\""), vec!["Rust 2.0 released"]);
+}
+
+#[test]
+fn test_query_boolean_operators() {
+    let app = sample_app();
+
+    assert_eq!(
+        search(&app, "-sponsored"),
+        vec!["Rust 2.0 released", "Async in depth"]
+    );
+    assert_eq!(search(&app, "cat:rust after:7d"), vec!["Rust 2.0 released"]);
+    assert_eq!(
+        search(&app, "cat:rust AND after:7d"),
+        vec!["Rust 2.0 released"]
+    );
+    assert_eq!(
+        search(&app, "async OR feed:hacker"),
+        vec!["Show HN: a tool", "Async in depth"]
+    );
+    assert_eq!(search(&app, "NOT cat:rust"), vec!["Show HN: a tool"]);
+    assert_eq!(
+        search(&app, "cat:rust (title:async OR -big)"),
+        vec!["Async in depth"]
+    );
+    // Lower-case "or" is an ordinary search word
+    assert!(search(&app, "rust or").is_empty());
+    // A '-' in front of a group negates the whole group
+    assert_eq!(
+        search(&app, "-(async OR sponsored)"),
+        vec!["Rust 2.0 released"]
+    );
+    assert_eq!(search(&app, "cat:rust -(big)"), vec!["Async in depth"]);
+}
+
+#[test]
+fn test_invalid_query_does_not_keep_stale_results() {
+    let mut app = sample_app();
+    app.start_search();
+    app.search_query = "rust".to_string();
+    app.update_search_filter();
+    assert_eq!(app.visible_item_count(), 2);
+
+    // While the query does not parse the other filters still apply to the current content
+    app.search_query = "rust (".to_string();
+    app.update_search_filter();
+    assert!(app.search_error.is_some());
+    app.current_feed_content.truncate(1);
+    app.toggle_show_muted();
+    assert_eq!(app.get_visible_items().len(), 1);
+
+    // Confirming drops the invalid query
+    app.confirm_search();
+    assert!(app.search_query.is_empty());
+    assert!(app.search_error.is_none());
+    assert!(app.error_message.is_some());
+}
+
+#[test]
+fn test_query_is_flags() {
+    let mut app = sample_app();
+    app.set_item_read("Rust 2.0 released | Rust Blog", true);
+    app.favorites
+        .insert("Async in depth | Rust Blog".to_string());
+
+    assert_eq!(search(&app, "is:read"), vec!["Rust 2.0 released"]);
+    assert_eq!(search(&app, "is:unread cat:rust"), vec!["Async in depth"]);
+    assert_eq!(search(&app, "is:fav"), vec!["Async in depth"]);
+}
+
+#[test]
+fn test_query_parse_errors() {
+    assert_eq!(
+        Query::parse("feed:\"Hacker").unwrap_err(),
+        "Unterminated quote"
+    );
+    assert!(Query::parse("(rust").unwrap_err().contains("')'"));
+    assert!(Query::parse("rust)").unwrap_err().contains("')'"));
+    assert!(Query::parse("title:/[/")
+        .unwrap_err()
+        .starts_with("Invalid regex"));
+    assert!(Query::parse("title:/abc")
+        .unwrap_err()
+        .starts_with("Unterminated regex"));
+    assert!(Query::parse("is:bogus").unwrap_err().contains("is:bogus"));
+    assert!(Query::parse("after:yesterdayish")
+        .unwrap_err()
+        .starts_with("Invalid date"));
+    assert!(Query::parse("rust OR").is_err());
+    assert!(Query::parse("NOT").is_err());
+    assert!(Query::parse("feed:").is_err());
+}
+
+#[test]
+fn test_search_error_ignores_query_and_drives_filtered_indices() {
+    let mut app = sample_app();
+
+    app.search_query = "cat:rust".to_string();
+    app.update_search_filter();
+    assert_eq!(app.filtered_indices, Some(vec![1, 2]));
+    assert_eq!(app.search_error, None);
+
+    // While a query is incomplete it is ignored rather than leaving stale results
+    app.search_query = "cat:rust (".to_string();
+    app.update_search_filter();
+    assert_eq!(app.filtered_indices, None);
+    assert!(app.search_error.is_some());
+
+    app.search_query = "cat:rust (title:async)".to_string();
+    app.update_search_filter();
+    assert_eq!(app.filtered_indices, Some(vec![2]));
+    assert_eq!(app.search_error, None);
+    assert_eq!(app.get_actual_index(0), Some(2));
+}
+
+#[test]
+fn test_query_description_and_domain() {
+    let mut app = sample_app();
+    app.current_feed_content[0].link = "https://www.example.com/show".to_string();
+    app.current_feed_content[1].link = "https://blog.rust.example/2.0".to_string();
+    app.current_feed_content[2].link = "https://rust.example/async".to_string();
+
+    assert_eq!(search(&app, "desc:sponsored"), vec!["Show HN: a tool"]);
+    assert_eq!(
+        search(&app, "desc:/^(big|futures) /"),
+        vec!["Rust 2.0 released", "Async in depth"]
+    );
+    // Subdomains match, "www." is ignored and partial host names don't match
+    assert_eq!(
+        search(&app, "domain:www.example.com"),
+        vec!["Show HN: a tool"]
+    );
+    assert_eq!(
+        search(&app, "domain:rust.example"),
+        vec!["Rust 2.0 released", "Async in depth"]
+    );
+    assert!(search(&app, "domain:ample.com").is_empty());
+}
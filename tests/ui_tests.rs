@@ -1,5 +1,5 @@
 use ratatui::style::Color;
-use reedy::ui::{parse_color, truncate_text};
+use reedy::ui::{parse_color, truncate_text, try_parse_color};
 
 #[test]
 fn test_truncate_text_no_truncation_needed() {
@@ -112,3 +112,12 @@ fn test_parse_color_fallback() {
     // Invalid hex should fall back to white
     assert_eq!(parse_color("#zzzzzz"), Color::White);
 }
+
+#[test]
+fn test_try_parse_color_rejects_unknown() {
+    assert_eq!(try_parse_color("dark_grey"), Some(Color::DarkGray));
+    assert_eq!(try_parse_color("#FF5500"), Some(Color::Rgb(255, 85, 0)));
+    assert_eq!(try_parse_color("plaid"), None);
+    assert_eq!(try_parse_color("#zzzzzz"), None);
+    assert_eq!(parse_color("plaid"), Color::White);
+}